@@ -51,6 +51,9 @@ pub fn increment(
             ValidateAttestation {
                 registry: ctx.accounts.predicate_registry.to_account_info(),
                 attester_account: ctx.accounts.attester_account.to_account_info(),
+                attester_scope_account: ctx.accounts.attester_scope_account
+                    .as_ref()
+                    .map(|account| account.to_account_info()),
                 policy_account: ctx.accounts.policy_account.to_account_info(),
                 used_uuid_account: ctx.accounts.used_uuid_account.to_account_info(),
                 signer: ctx.accounts.owner.to_account_info(),
@@ -122,16 +125,20 @@ pub struct Increment<'info> {
     /// Attester account in the predicate registry
     #[account(
         mut,
-        seeds = [b"attester", attester_account.attester.as_ref()],
+        seeds = [b"attester", predicate_registry.namespace_seed(&predicate_registry.key()).as_slice(), attester_account.attester.as_ref()],
         bump,
         seeds::program = predicate_registry_program.key()
     )]
     pub attester_account: Account<'info, AttesterAccount>,
 
+    /// Scope account of the attester's identity (only needed for scoped attesters)
+    /// CHECK: This is validated in the predicate registry program
+    pub attester_scope_account: Option<UncheckedAccount<'info>>,
+
     /// Policy account for this counter program
-    /// The policy is tied to the program ID, not individual users
+    /// The policy is tied to the program ID (within the counter's registry), not individual users
     #[account(
-        seeds = [b"policy", crate::ID.as_ref()],
+        seeds = [b"policy", predicate_registry.namespace_seed(&predicate_registry.key()).as_slice(), crate::ID.as_ref()],
         bump,
         seeds::program = predicate_registry_program.key()
     )]
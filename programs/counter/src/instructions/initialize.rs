@@ -64,9 +64,9 @@ pub struct Initialize<'info> {
     pub predicate_registry: Account<'info, PredicateRegistryAccount>,
 
     /// Policy account for this counter program
-    /// The policy is tied to the program ID, not individual users
+    /// The policy is tied to the program ID (within the chosen registry), not individual users
     #[account(
-        seeds = [b"policy", crate::ID.as_ref()],
+        seeds = [b"policy", predicate_registry.namespace_seed(&predicate_registry.key()).as_slice(), crate::ID.as_ref()],
         bump,
         seeds::program = predicate_registry_program.key()
     )]
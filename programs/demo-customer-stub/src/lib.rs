@@ -1,3 +1,7 @@
+// Suppress warnings from Anchor's internal behavior
+// These are framework-level warnings, not from our code
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
 declare_id!("5iejgxCq2vnpiwWpf4qwziVhbX2irmgMEghBrD9tmk5p");
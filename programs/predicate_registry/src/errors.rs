@@ -121,4 +121,248 @@ pub enum PredicateRegistryError {
     /// Error when client program doesn't match policy account
     #[msg("Client program mismatch: Client program does not match policy account")]
     InvalidClientProgram,
+    
+    /// Error when accepting authority with a key that was not proposed
+    #[msg("Not pending authority: The signer is not the proposed pending authority")]
+    NotPendingAuthority,
+    
+    /// Error when accepting or cancelling with no transfer in progress
+    #[msg("No pending authority transfer: There is no authority transfer in progress")]
+    NoPendingAuthorityTransfer,
+    
+    /// Error when a single-authority admin path is used while multisig mode is enabled
+    #[msg("Multisig enabled: This action must be executed through a multisig proposal")]
+    MultisigEnabled,
+    
+    /// Error when a multisig operation is attempted while multisig mode is disabled
+    #[msg("Multisig disabled: Multisig mode is not enabled for this registry")]
+    MultisigDisabled,
+    
+    /// Error when a multisig signer set or threshold is invalid
+    #[msg("Invalid multisig config: Signers must be unique and non-zero, with 1 <= threshold <= signers")]
+    InvalidMultisigConfig,
+    
+    /// Error when the multisig signer set exceeds the maximum size
+    #[msg("Too many multisig signers: Signer set exceeds the maximum allowed size")]
+    TooManyMultisigSigners,
+    
+    /// Error when the signer is not a member of the multisig signer set
+    #[msg("Not a multisig signer: The signer is not in the registry's multisig signer set")]
+    NotMultisigSigner,
+    
+    /// Error when a signer approves the same proposal twice
+    #[msg("Already approved: The signer has already approved this proposal")]
+    AlreadyApproved,
+    
+    /// Error when executing a proposal that has not reached the threshold
+    #[msg("Threshold not met: The proposal does not have enough approvals")]
+    ThresholdNotMet,
+    
+    /// Error when the accounts supplied do not match the proposal's action
+    #[msg("Proposal account mismatch: Accounts provided do not match the proposal action")]
+    ProposalAccountMismatch,
+    
+    /// Error when a direct admin path is used while the timelock is enabled
+    #[msg("Timelock enabled: This action must be queued and executed after the timelock delay")]
+    TimelockEnabled,
+    
+    /// Error when queueing an operation while the timelock is disabled
+    #[msg("Timelock disabled: The registry timelock is not enabled")]
+    TimelockDisabled,
+    
+    /// Error when a timelock delay is out of range
+    #[msg("Invalid timelock delay: Delay must be between 0 and 30 days")]
+    InvalidTimelockDelay,
+    
+    /// Error when executing an operation before its eta
+    #[msg("Timelock not expired: The operation cannot execute before its eta")]
+    TimelockNotExpired,
+    
+    /// Error when validating an attestation while the registry is paused
+    #[msg("Registry paused: Attestation validation is paused")]
+    RegistryPaused,
+    
+    /// Error when pausing a registry that is already paused
+    #[msg("Registry already paused: The registry is already paused")]
+    RegistryAlreadyPaused,
+    
+    /// Error when unpausing a registry that is not paused
+    #[msg("Registry not paused: The registry is not paused")]
+    RegistryNotPaused,
+    
+    /// Error when validating an attestation for a paused client program
+    #[msg("Policy paused: Attestation validation is paused for this program")]
+    PolicyPaused,
+    
+    /// Error when pausing a policy that is already paused
+    #[msg("Policy already paused: The policy is already paused")]
+    PolicyAlreadyPaused,
+    
+    /// Error when unpausing a policy that is not paused
+    #[msg("Policy not paused: The policy is not paused")]
+    PolicyNotPaused,
+    
+    /// Error when the clock drift buffer or maximum attestation lifetime is out of range
+    #[msg("Invalid attestation config: Drift buffer or maximum lifetime is out of range")]
+    InvalidAttestationConfig,
+    
+    /// Error when an attestation expires further in the future than the registry allows
+    #[msg("Attestation lifetime exceeded: Expiration is beyond the maximum attestation lifetime")]
+    AttestationLifetimeExceeded,
+    
+    /// Error when migrating an account that already uses the current layout
+    #[msg("Account already migrated: The account already uses the current layout")]
+    AccountAlreadyMigrated,
+    
+    /// Error when migrating an account type that has no versioned layout
+    #[msg("Unsupported account migration: The account type cannot be migrated")]
+    UnsupportedAccountMigration,
+    
+    /// Error when renouncing without confirming the registry address
+    #[msg("Renounce not confirmed: Confirmation must be the registry address")]
+    RenounceNotConfirmed,
+    
+    /// Error when acting on a registry whose authority has been renounced
+    #[msg("Authority renounced: The registry can no longer be administered")]
+    AuthorityRenounced,
+    
+    /// Error when an attester metadata field is empty or too long
+    #[msg("Invalid attester metadata: Fields must be non-empty and within their maximum length")]
+    InvalidAttesterMetadata,
+    
+    /// Error when metadata is given without its account, or the account without metadata
+    #[msg("Attester metadata mismatch: Metadata and its account must be provided together")]
+    AttesterMetadataMismatch,
+    
+    /// Error when an attester validity window is negative or inverted
+    #[msg("Invalid attester validity: Bounds must be non-negative and valid_from must not follow valid_until")]
+    InvalidAttesterValidity,
+    
+    /// Error when validating an attestation before the attester's validity window starts
+    #[msg("Attester not yet valid: The attester's validity window has not started")]
+    AttesterNotYetValid,
+    
+    /// Error when validating an attestation after the attester's validity window ends
+    #[msg("Attester validity ended: The attester's validity window has ended")]
+    AttesterValidityEnded,
+    
+    /// Error when rotating an attester key that has already been rotated
+    #[msg("Attester already rotated: This key has already been replaced")]
+    AttesterAlreadyRotated,
+    
+    /// Error when a key rotation overlap is negative or too long
+    #[msg("Invalid rotation overlap: Overlap must be between 0 and 30 days")]
+    InvalidRotationOverlap,
+    
+    /// Error when an attester scope list is too long, has duplicates or an empty prefix
+    #[msg("Invalid attester scope: Too many entries, a duplicate entry or an empty prefix")]
+    InvalidAttesterScope,
+    
+    /// Error when a scoped attester signs for a program or policy outside its scope
+    #[msg("Attester out of scope: The attester may not sign for this program or policy")]
+    AttesterOutOfScope,
+    
+    /// Error when validating a scoped attester's attestation without its scope account
+    #[msg("Attester scope required: The attester is scoped and its scope account must be provided")]
+    AttesterScopeRequired,
+    
+    /// Error when a policy's attester quorum exceeds the maximum
+    #[msg("Invalid attester quorum: Quorum must not exceed 4 attesters")]
+    InvalidAttesterQuorum,
+    
+    /// Error when a single attestation is validated for a policy requiring several attesters
+    #[msg("Attester quorum required: This policy requires a threshold validation")]
+    AttesterQuorumRequired,
+    
+    /// Error when a threshold validation carries too few or too many signatures
+    #[msg("Attester quorum not met: Signature count must be between the policy's quorum and 4")]
+    AttesterQuorumNotMet,
+    
+    /// Error when two signatures in a threshold validation share an attester identity
+    #[msg("Duplicate attester: Each signature must come from a distinct attester")]
+    DuplicateAttester,
+    
+    /// Error when an unbonding period is negative or too long
+    #[msg("Invalid bond config: Unbonding period must be between 0 and 90 days")]
+    InvalidBondConfig,
+    
+    /// Error when self-registering while the registry requires no bond
+    #[msg("Self-registration disabled: This registry does not accept bonded attesters")]
+    SelfRegistrationDisabled,
+    
+    /// Error when an admin deregisters an attester that still holds a bond
+    #[msg("Attester bonded: Bonded attesters exit through begin_unbonding")]
+    AttesterBonded,
+    
+    /// Error when beginning to unbond an attester that is already exiting
+    #[msg("Attester already unbonding: The attester has already begun exiting")]
+    AttesterAlreadyUnbonding,
+    
+    /// Error when withdrawing a bond before the unbonding period has elapsed
+    #[msg("Bond not withdrawable: The attester is not exiting or its unbonding period has not elapsed")]
+    BondNotWithdrawable,
+    
+    /// Error when an attester's bond is below the registry's minimum collateral
+    #[msg("Insufficient collateral: The attester's bond is below the registry's minimum")]
+    InsufficientCollateral,
+    
+    /// Error when slashing nothing or more than the attester's bond
+    #[msg("Invalid slash amount: Amount must be positive and at most the attester's bond")]
+    InvalidSlashAmount,
+    
+    /// Error when a withdrawn bond is not sent to the account it belongs to
+    #[msg("Bond recipient mismatch: Bonds are returned to the attester, or to the registry authority for non-Solana keys")]
+    BondRecipientMismatch,
+    
+    /// Error when validating an attestation from a suspended attester
+    #[msg("Attester suspended: The attester is suspended and cannot sign attestations")]
+    AttesterSuspended,
+    
+    /// Error when reinstating an attester that is not suspended
+    #[msg("Attester not suspended: Only suspended attesters can be reinstated")]
+    AttesterNotSuspended,
+    
+    /// Error when a revocation cutoff is not positive or would loosen an existing cutoff
+    #[msg("Invalid revocation cutoff: Cutoff must be positive and no later than an existing cutoff")]
+    InvalidRevocationCutoff,
+    
+    /// Error when an attestation's issued-at timestamp is implausible
+    #[msg("Invalid issued-at: Attestation is issued in the future, after it expires, or more than the maximum lifetime before it expires")]
+    InvalidIssuedAt,
+    
+    /// Error when an attestation was issued after its attester's revocation cutoff
+    #[msg("Attestation revoked: The attestation was issued after the attester's revocation cutoff")]
+    AttestationRevoked,
+    
+    /// Error when indexing an attester that is already in the attester index
+    #[msg("Attester already indexed: The attester is already listed in the attester index")]
+    AttesterAlreadyIndexed,
+    
+    /// Error when unindexing an attester that is not in the attester index
+    #[msg("Attester not indexed: The attester is not listed in the attester index")]
+    AttesterNotIndexed,
+    
+    /// Error when the supplied index pages do not match the attester index
+    #[msg("Attester index mismatch: The index pages supplied do not list the attester or are not the last page")]
+    AttesterIndexMismatch,
+    
+    /// Error when a rotation's new key is not a valid key of the attester's key type
+    #[msg("Invalid rotation key: Ethereum attesters must rotate to an Ethereum address left-padded to 32 bytes")]
+    InvalidRotationKey,
+    
+    /// Error when a batch is empty, too large, or repeats or zeroes a key
+    #[msg("Invalid attester batch: Batch must hold 1 to 16 distinct, non-zero attester keys")]
+    InvalidAttesterBatch,
+    
+    /// Error when a key self-registers while a scope account is left over for its identity
+    #[msg("Attester scope exists: A self-registering attester must not inherit an existing scope")]
+    AttesterScopeExists,
+    
+    /// Error when the slashing evidence was not signed by the slashed attester
+    #[msg("Slash evidence mismatch: The used UUID account was not attested or co-signed by this attester")]
+    SlashEvidenceMismatch,
+    
+    /// Error when a revoked attester's statement is signed without its issue time
+    #[msg("Issued at not signed: Attesters with a revocation cutoff must sign under the Sha256WithIssuedAt digest scheme")]
+    IssuedAtNotSigned,
 }
@@ -11,6 +11,8 @@ use anchor_lang::prelude::*;
 pub struct RegistryInitialized {
     /// The public key of the registry account
     pub registry: Pubkey,
+    /// The registry's namespace identifier
+    pub registry_id: u64,
     /// The authority of the registry
     pub authority: Pubkey,
     /// Timestamp when initialized
@@ -24,7 +26,7 @@ pub struct AttesterRegistered {
     pub registry: Pubkey,
     /// The attester that was registered
     pub attester: Pubkey,
-    /// The authority who registered the attester
+    /// The owner or attester manager who registered the attester (the proposal or operation account in multisig or timelock mode)
     pub authority: Pubkey,
     /// Timestamp when registered
     pub timestamp: i64,
@@ -37,7 +39,7 @@ pub struct AttesterDeregistered {
     pub registry: Pubkey,
     /// The attester that was deregistered
     pub attester: Pubkey,
-    /// The authority who deregistered the attester
+    /// The owner or attester manager who deregistered the attester (the proposal or operation account in multisig or timelock mode)
     pub authority: Pubkey,
     /// Timestamp when deregistered
     pub timestamp: i64,
@@ -94,6 +96,32 @@ pub struct AuthorityTransferred {
     pub timestamp: i64,
 }
 
+/// Event emitted when a new registry authority is proposed
+#[event]
+pub struct AuthorityTransferProposed {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The current authority that proposed the transfer
+    pub authority: Pubkey,
+    /// The proposed new authority (must accept to complete the transfer)
+    pub pending_authority: Pubkey,
+    /// Timestamp when proposed
+    pub timestamp: i64,
+}
+
+/// Event emitted when a pending authority transfer is cancelled
+#[event]
+pub struct AuthorityTransferCancelled {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The current authority that cancelled the transfer
+    pub authority: Pubkey,
+    /// The pending authority whose proposal was cancelled
+    pub cancelled_authority: Pubkey,
+    /// Timestamp when cancelled
+    pub timestamp: i64,
+}
+
 /// Event emitted when a policy ID is updated
 #[event]
 pub struct PolicyUpdated {
@@ -124,4 +152,563 @@ pub struct UuidMarkedUsed {
     pub timestamp: i64,
 }
 
+/// Event emitted when the multisig signer set or threshold changes
+#[event]
+pub struct MultisigConfigured {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The new multisig signer set
+    pub signers: Vec<Pubkey>,
+    /// The new approval threshold (0 = multisig disabled)
+    pub threshold: u8,
+    /// Timestamp when configured
+    pub timestamp: i64,
+}
 
+/// Event emitted when a multisig admin proposal is created
+#[event]
+pub struct ProposalCreated {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The proposal account
+    pub proposal: Pubkey,
+    /// The sequential proposal identifier
+    pub proposal_id: u64,
+    /// The multisig signer who created the proposal
+    pub proposer: Pubkey,
+    /// The proposed admin action
+    pub action: crate::state::AdminAction,
+    /// Timestamp when created
+    pub timestamp: i64,
+}
+
+/// Event emitted when a multisig signer approves a proposal
+#[event]
+pub struct ProposalApproved {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The proposal account
+    pub proposal: Pubkey,
+    /// The sequential proposal identifier
+    pub proposal_id: u64,
+    /// The signer who approved
+    pub approver: Pubkey,
+    /// Number of approvals recorded so far
+    pub approvals: u8,
+    /// Approvals required to execute
+    pub threshold: u8,
+    /// Timestamp when approved
+    pub timestamp: i64,
+}
+
+/// Event emitted when a multisig proposal is executed
+#[event]
+pub struct ProposalExecuted {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The proposal account
+    pub proposal: Pubkey,
+    /// The sequential proposal identifier
+    pub proposal_id: u64,
+    /// Who executed the proposal
+    pub executor: Pubkey,
+    /// The admin action that was applied
+    pub action: crate::state::AdminAction,
+    /// Timestamp when executed
+    pub timestamp: i64,
+}
+
+/// Event emitted when the timelock delay changes
+#[event]
+pub struct TimelockDelaySet {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The previous delay in seconds
+    pub previous_delay: i64,
+    /// The new delay in seconds (0 = timelock disabled)
+    pub new_delay: i64,
+    /// Timestamp when changed
+    pub timestamp: i64,
+}
+
+/// Event emitted when an admin action is queued behind the timelock
+/// 
+/// Emitted both for operations queued by the authority and for multisig
+/// proposals that reach their threshold while the timelock is enabled.
+#[event]
+pub struct OperationQueued {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The queued operation or proposal account
+    pub operation: Pubkey,
+    /// Who queued the action
+    pub queued_by: Pubkey,
+    /// The queued admin action
+    pub action: crate::state::AdminAction,
+    /// Earliest timestamp at which the action can execute
+    pub eta: i64,
+    /// Timestamp when queued
+    pub timestamp: i64,
+}
+
+/// Event emitted when a queued admin operation is executed
+#[event]
+pub struct OperationExecuted {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The queued operation account
+    pub operation: Pubkey,
+    /// The sequential operation identifier
+    pub operation_id: u64,
+    /// Who executed the operation
+    pub executor: Pubkey,
+    /// The admin action that was applied
+    pub action: crate::state::AdminAction,
+    /// Timestamp when executed
+    pub timestamp: i64,
+}
+
+/// Event emitted when a queued admin operation is cancelled
+#[event]
+pub struct OperationCancelled {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The queued operation account
+    pub operation: Pubkey,
+    /// The sequential operation identifier
+    pub operation_id: u64,
+    /// The authority that cancelled the operation
+    pub authority: Pubkey,
+    /// Timestamp when cancelled
+    pub timestamp: i64,
+}
+
+/// Event emitted when the registry is paused
+#[event]
+pub struct RegistryPaused {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// Who paused the registry (authority, multisig signer, guardian or pauser)
+    pub paused_by: Pubkey,
+    /// Timestamp when paused
+    pub timestamp: i64,
+}
+
+/// Event emitted when the registry is unpaused
+#[event]
+pub struct RegistryUnpaused {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The owner or pauser that unpaused the registry (the proposal or operation account in multisig or timelock mode)
+    pub authority: Pubkey,
+    /// Timestamp when unpaused
+    pub timestamp: i64,
+}
+
+/// Event emitted when the pause guardian is set or cleared
+#[event]
+pub struct GuardianSet {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The previous guardian, if any
+    pub previous_guardian: Option<Pubkey>,
+    /// The new guardian, if any
+    pub new_guardian: Option<Pubkey>,
+    /// Timestamp when set
+    pub timestamp: i64,
+}
+
+/// Event emitted when a client program's policy is paused
+#[event]
+pub struct PolicyPaused {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The client program whose validation was paused
+    pub client_program: Pubkey,
+    /// The upgrade authority that paused the policy
+    pub authority: Pubkey,
+    /// Timestamp when paused
+    pub timestamp: i64,
+}
+
+/// Event emitted when a client program's policy is unpaused
+#[event]
+pub struct PolicyUnpaused {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The client program whose validation was resumed
+    pub client_program: Pubkey,
+    /// The upgrade authority that unpaused the policy
+    pub authority: Pubkey,
+    /// Timestamp when unpaused
+    pub timestamp: i64,
+}
+
+/// Event emitted when a role is granted
+#[event]
+pub struct RoleGranted {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The granted role
+    pub role: crate::state::Role,
+    /// The key receiving the role
+    pub holder: Pubkey,
+    /// The owner that granted the role (the proposal or operation account in multisig or timelock mode)
+    pub authority: Pubkey,
+    /// Timestamp when granted
+    pub timestamp: i64,
+}
+
+/// Event emitted when a role is revoked
+#[event]
+pub struct RoleRevoked {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The revoked role
+    pub role: crate::state::Role,
+    /// The key losing the role
+    pub holder: Pubkey,
+    /// The owner that revoked the role (the proposal or operation account in multisig or timelock mode)
+    pub authority: Pubkey,
+    /// Timestamp when revoked
+    pub timestamp: i64,
+}
+
+/// Event emitted when the attestation config changes
+#[event]
+pub struct AttestationConfigSet {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The previous clock drift buffer in seconds
+    pub previous_clock_drift_buffer: i64,
+    /// The new clock drift buffer in seconds
+    pub new_clock_drift_buffer: i64,
+    /// The previous maximum attestation lifetime in seconds
+    pub previous_max_attestation_lifetime: i64,
+    /// The new maximum attestation lifetime in seconds
+    pub new_max_attestation_lifetime: i64,
+    /// Timestamp when changed
+    pub timestamp: i64,
+}
+
+/// Event emitted when an account is migrated to the current layout
+#[event]
+pub struct AccountMigrated {
+    /// The migrated account
+    pub account: Pubkey,
+    /// The layout version before migration
+    pub from_version: u8,
+    /// The layout version after migration
+    pub to_version: u8,
+    /// Who paid for the additional rent
+    pub payer: Pubkey,
+    /// Timestamp when migrated
+    pub timestamp: i64,
+}
+
+/// Event emitted when the registry authority is renounced
+#[event]
+pub struct AuthorityRenounced {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The authority that renounced ownership
+    pub previous_authority: Pubkey,
+    /// Timestamp when renounced
+    pub timestamp: i64,
+}
+
+/// Event emitted when an attester's metadata is set
+#[event]
+pub struct AttesterMetadataUpdated {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The attester described by the metadata
+    pub attester: Pubkey,
+    /// The new metadata
+    pub metadata: crate::state::AttesterMetadata,
+    /// Who set the metadata (the attester, an attester manager or the owner)
+    pub updated_by: Pubkey,
+    /// Timestamp when set
+    pub timestamp: i64,
+}
+
+/// Event emitted when an attester's validity window is set
+#[event]
+pub struct AttesterValiditySet {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The attester whose window changed
+    pub attester: Pubkey,
+    /// Earliest accepted timestamp (0 = no lower bound)
+    pub valid_from: i64,
+    /// Latest accepted timestamp (0 = no end date)
+    pub valid_until: i64,
+    /// The owner or attester manager who set the window (the proposal or operation account in multisig or timelock mode)
+    pub authority: Pubkey,
+    /// Timestamp when set
+    pub timestamp: i64,
+}
+
+/// Event emitted when an attester is rotated to a new key
+#[event]
+pub struct AttesterKeyRotated {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The attester's stable identity
+    pub identity: Pubkey,
+    /// The key being replaced
+    pub old_key: Pubkey,
+    /// The replacement key
+    pub new_key: Pubkey,
+    /// Timestamp until which the old key remains valid
+    pub old_key_valid_until: i64,
+    /// The owner or attester manager who rotated the key (the proposal or operation account in multisig or timelock mode)
+    pub authority: Pubkey,
+    /// Timestamp when rotated
+    pub timestamp: i64,
+}
+
+/// Event emitted when an attester's scope is set
+#[event]
+pub struct AttesterScopeSet {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The attester's stable identity
+    pub identity: Pubkey,
+    /// The new scope (empty = unrestricted)
+    pub scope: crate::state::AttesterScope,
+    /// The owner or attester manager who set the scope
+    pub authority: Pubkey,
+    /// Timestamp when set
+    pub timestamp: i64,
+}
+
+/// Event emitted when a client program's attester quorum is set
+#[event]
+pub struct AttesterQuorumSet {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The client program whose policy was updated
+    pub client_program: Pubkey,
+    /// Distinct attesters each validation now requires (0 or 1 = a single attestation)
+    pub quorum: u8,
+    /// The upgrade authority that set the quorum
+    pub authority: Pubkey,
+    /// Timestamp when set
+    pub timestamp: i64,
+}
+
+/// Event emitted when a statement is validated by a quorum of attesters
+#[event]
+pub struct ThresholdStatementValidated {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The message sender from the statement
+    pub msg_sender: Pubkey,
+    /// The target address from the statement
+    pub target: Pubkey,
+    /// The attesters who signed the statement
+    pub attesters: Vec<Pubkey>,
+    /// The policy's attester quorum at validation time
+    pub quorum: u8,
+    /// The message value from the statement
+    pub msg_value: u64,
+    /// The policy ID used for validation
+    pub policy_id: String,
+    /// The unique statement identifier
+    pub uuid: String,
+    /// The expiration timestamp
+    pub expiration: i64,
+    /// Timestamp when validated
+    pub timestamp: i64,
+}
+
+/// Event emitted when the bond config changes
+#[event]
+pub struct BondConfigSet {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The previous bond in lamports
+    pub previous_attester_bond: u64,
+    /// The new bond in lamports (0 = self-registration disabled)
+    pub new_attester_bond: u64,
+    /// The previous unbonding period in seconds
+    pub previous_unbonding_period: i64,
+    /// The new unbonding period in seconds
+    pub new_unbonding_period: i64,
+    /// The previous minimum collateral in lamports
+    pub previous_min_attester_collateral: u64,
+    /// The new minimum collateral in lamports (0 = none)
+    pub new_min_attester_collateral: u64,
+    /// Timestamp when changed
+    pub timestamp: i64,
+}
+
+/// Event emitted when an attester registers itself by posting a bond
+#[event]
+pub struct AttesterBonded {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The self-registered attester
+    pub attester: Pubkey,
+    /// Lamports bonded
+    pub amount: u64,
+    /// Timestamp when registered
+    pub timestamp: i64,
+}
+
+/// Event emitted when a bonded attester begins exiting
+#[event]
+pub struct AttesterUnbondingStarted {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The exiting attester
+    pub attester: Pubkey,
+    /// Lamports bonded
+    pub amount: u64,
+    /// Earliest timestamp at which the bond can be withdrawn
+    pub withdrawable_at: i64,
+    /// Who started the exit (the attester, or the registry authority forcing it out)
+    pub caller: Pubkey,
+    /// Timestamp when unbonding started
+    pub timestamp: i64,
+}
+
+/// Event emitted when an exited attester withdraws its bond
+#[event]
+pub struct AttesterBondWithdrawn {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The exited attester
+    pub attester: Pubkey,
+    /// Lamports returned (the bond, excluding rent)
+    pub amount: u64,
+    /// Who received the bond and rent
+    pub recipient: Pubkey,
+    /// Timestamp when withdrawn
+    pub timestamp: i64,
+}
+
+/// Event emitted when collateral is added to an attester's bond vault
+#[event]
+pub struct CollateralPosted {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The attester whose bond grew
+    pub attester: Pubkey,
+    /// Lamports added
+    pub amount: u64,
+    /// Lamports bonded after the deposit
+    pub total_bond: u64,
+    /// Who provided the lamports
+    pub depositor: Pubkey,
+    /// Timestamp when posted
+    pub timestamp: i64,
+}
+
+/// Event emitted when the registry authority slashes an attester's bond
+#[event]
+pub struct AttesterSlashed {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The slashed attester
+    pub attester: Pubkey,
+    /// Lamports slashed
+    pub amount: u64,
+    /// Lamports bonded after slashing
+    pub remaining_bond: u64,
+    /// The UUID of the offending attestation (formatted string)
+    pub uuid: String,
+    /// Who received the slashed lamports
+    pub recipient: Pubkey,
+    /// Whether the lamports were burned (sent to the incinerator)
+    pub burned: bool,
+    /// The registry authority that slashed
+    pub authority: Pubkey,
+    /// Timestamp when slashed
+    pub timestamp: i64,
+}
+
+/// Event emitted when an attester is suspended
+#[event]
+pub struct AttesterSuspended {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The suspended attester
+    pub attester: Pubkey,
+    /// Number of times the attester has now been suspended
+    pub suspension_count: u32,
+    /// The owner or attester manager who suspended the attester (the proposal or operation account in multisig or timelock mode)
+    pub authority: Pubkey,
+    /// Timestamp when suspended
+    pub timestamp: i64,
+}
+
+/// Event emitted when a suspended attester is reinstated
+#[event]
+pub struct AttesterReinstated {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The reinstated attester
+    pub attester: Pubkey,
+    /// The owner or attester manager who reinstated the attester (the proposal or operation account in multisig or timelock mode)
+    pub authority: Pubkey,
+    /// Timestamp when reinstated
+    pub timestamp: i64,
+}
+
+/// Event emitted when an attester's attestations are revoked after a cutoff
+#[event]
+pub struct AttesterRevoked {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The revoked attester key
+    pub attester: Pubkey,
+    /// Attestations issued after this timestamp are rejected
+    pub revoked_after: i64,
+    /// The owner or attester manager who revoked the attester (the proposal or operation account in multisig or timelock mode)
+    pub authority: Pubkey,
+    /// Timestamp when revoked
+    pub timestamp: i64,
+}
+
+/// Event emitted when an attester is listed in the attester index
+#[event]
+pub struct AttesterIndexed {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The indexed attester
+    pub attester: Pubkey,
+    /// The index page the attester was listed on
+    pub page: u32,
+    /// Timestamp when indexed
+    pub timestamp: i64,
+}
+
+/// Event emitted when an attester is removed from the attester index
+#[event]
+pub struct AttesterUnindexed {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The removed attester
+    pub attester: Pubkey,
+    /// The index page the attester was removed from
+    pub page: u32,
+    /// Timestamp when removed
+    pub timestamp: i64,
+}
+
+/// Event emitted when a client program's digest scheme is set
+#[event]
+pub struct DigestSchemeSet {
+    /// The public key of the registry account
+    pub registry: Pubkey,
+    /// The client program whose policy was updated
+    pub client_program: Pubkey,
+    /// Digest over which attesters now sign statements for the program
+    pub digest_scheme: crate::state::DigestScheme,
+    /// The upgrade authority that set the digest scheme
+    pub authority: Pubkey,
+    /// Timestamp when set
+    pub timestamp: i64,
+}
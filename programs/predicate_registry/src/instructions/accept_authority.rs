@@ -0,0 +1,38 @@
+//! Accept authority instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::AcceptAuthority;
+use crate::events::AuthorityTransferred;
+
+/// Accept a pending registry authority transfer
+/// 
+/// The second step of a two-step transfer. Must be signed by the key that was
+/// proposed via `propose_authority`.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let new_authority = ctx.accounts.new_authority.key();
+    let clock = Clock::get()?;
+
+    let previous_authority = registry.accept_authority(new_authority, &clock)?;
+
+    emit!(AuthorityTransferred {
+        registry: registry.key(),
+        previous_authority,
+        new_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Registry authority transferred from {} to {}",
+        previous_authority,
+        new_authority
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! Approve proposal instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::ApproveProposal;
+use crate::events::ProposalApproved;
+
+/// Approve a multisig admin proposal
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    let proposal = &mut ctx.accounts.proposal;
+    let approver = &ctx.accounts.approver;
+    let clock = Clock::get()?;
+
+    proposal.approve(approver.key())?;
+
+    emit!(ProposalApproved {
+        registry: registry.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        approver: approver.key(),
+        approvals: proposal.valid_approvals(registry) as u8,
+        threshold: registry.multisig_threshold,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Proposal {} approved by {} ({}/{})",
+        proposal.proposal_id,
+        approver.key(),
+        proposal.valid_approvals(registry),
+        registry.multisig_threshold
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Begin unbonding instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{BeginUnbonding, close_side_accounts, remove_from_attester_index, verify_attester_or_owner};
+use crate::events::AttesterUnbondingStarted;
+
+/// Exit as a bonded attester and start the unbonding period
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the exiting attester
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the bonded attester itself or the registry owner (single-authority
+///   mode) can start the exit; the owner does so for attesters that cannot
+///   sign Solana transactions or must be removed despite holding a bond
+/// * The attester is deregistered and removed from the attester index
+///   immediately, so its attestations are rejected throughout the unbonding
+///   period
+/// * Its metadata and scope accounts are closed, so a later self-registration
+///   of the key cannot inherit the scope and starts denied as usual
+pub fn begin_unbonding(ctx: Context<BeginUnbonding>, attester: Pubkey) -> Result<()> {
+    verify_attester_or_owner(&ctx.accounts.registry, &ctx.accounts.caller.key(), &attester)?;
+
+    let registry = &mut ctx.accounts.registry;
+    let attester_account = &mut ctx.accounts.attester_account;
+    let bond_account = &mut ctx.accounts.bond_account;
+    let caller = ctx.accounts.caller.key();
+    let clock = Clock::get()?;
+
+    let withdrawable_at = bond_account.begin_unbonding(registry.unbonding_period, &clock)?;
+    attester_account.deregister()?;
+
+    // Remove the attester from the attester index
+    remove_from_attester_index(
+        registry,
+        attester_account,
+        &mut ctx.accounts.index_page,
+        ctx.accounts.tail_page.as_deref_mut(),
+        &clock,
+    )?;
+
+    // Close the side accounts now; the attester account stays until the bond is withdrawn
+    close_side_accounts(
+        attester_account,
+        (&ctx.accounts.metadata_account, &ctx.accounts.scope_account),
+        &ctx.accounts.caller.to_account_info(),
+    )?;
+
+    // Update registry statistics
+    registry.decrement_attester_count(&clock)?;
+
+    emit!(AttesterUnbondingStarted {
+        registry: registry.key(),
+        attester,
+        amount: bond_account.amount,
+        withdrawable_at,
+        caller,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Attester {} unbonding, withdrawable at {}", attester, withdrawable_at);
+    
+    Ok(())
+}
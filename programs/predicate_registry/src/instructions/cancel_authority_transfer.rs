@@ -0,0 +1,35 @@
+//! Cancel authority transfer instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::CancelAuthorityTransfer;
+use crate::events::AuthorityTransferCancelled;
+
+/// Cancel a pending registry authority transfer
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    let cancelled_authority = registry.cancel_authority_transfer(&clock)?;
+
+    emit!(AuthorityTransferCancelled {
+        registry: registry.key(),
+        authority: authority.key(),
+        cancelled_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Registry authority transfer to {} cancelled by {}",
+        cancelled_authority,
+        authority.key()
+    );
+    
+    Ok(())
+}
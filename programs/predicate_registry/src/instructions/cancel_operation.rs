@@ -0,0 +1,32 @@
+//! Cancel operation instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::CancelOperation;
+use crate::events::OperationCancelled;
+
+/// Cancel a queued admin operation before it is executed
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn cancel_operation(ctx: Context<CancelOperation>) -> Result<()> {
+    let operation = &ctx.accounts.operation;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    // The account is closed by the `close = queued_by` constraint
+
+    emit!(OperationCancelled {
+        registry: ctx.accounts.registry.key(),
+        operation: operation.key(),
+        operation_id: operation.operation_id,
+        authority: authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Operation {} cancelled by {}", operation.operation_id, authority.key());
+    
+    Ok(())
+}
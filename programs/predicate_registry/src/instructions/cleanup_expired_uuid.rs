@@ -7,7 +7,7 @@ use crate::errors::PredicateRegistryError;
 /// Cleanup an expired UUID account to reclaim rent
 /// 
 /// This function allows anyone to close a UsedUuidAccount after the statement
-/// has expired AND the validation buffer window has passed, returning the rent
+/// has expired AND the registry's clock drift buffer has passed, returning the rent
 /// to the original signer (payer).
 /// 
 /// # Arguments
@@ -17,7 +17,7 @@ use crate::errors::PredicateRegistryError;
 /// * `Result<()>` - Ok if cleanup successful
 /// 
 /// # Security Considerations
-/// - Only allows cleanup after statement expiration + validation buffer
+/// - Only allows cleanup after statement expiration + the registry's drift buffer
 /// - Prevents replay attacks by ensuring UUID accounts cannot be cleaned up
 ///   while attestations are still valid for validation
 /// - Enforces rent return to the original payer
@@ -30,7 +30,7 @@ pub fn cleanup_expired_uuid(ctx: Context<CleanupExpiredUuid>) -> Result<()> {
     let current_timestamp = clock.unix_timestamp;
     
     require!(
-        used_uuid_account.attestation.is_expired(current_timestamp),
+        used_uuid_account.attestation.is_expired(current_timestamp, ctx.accounts.registry.clock_drift_buffer),
         PredicateRegistryError::StatementNotExpired
     );
     
@@ -0,0 +1,43 @@
+//! Configure multisig instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::ConfigureMultisig;
+use crate::events::MultisigConfigured;
+
+/// Enable multisig mode with the given signer set and threshold
+/// 
+/// After this succeeds, attester management and authority transfer can only
+/// be performed through proposals approved by `threshold` signers.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `signers` - The multisig signer set
+/// * `threshold` - Approvals required to execute a proposal
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn configure_multisig(
+    ctx: Context<ConfigureMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    registry.configure_multisig(signers.clone(), threshold, &clock)?;
+
+    emit!(MultisigConfigured {
+        registry: registry.key(),
+        signers: signers.clone(),
+        threshold,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Multisig configured with {} signers and threshold {}",
+        signers.len(),
+        threshold
+    );
+    
+    Ok(())
+}
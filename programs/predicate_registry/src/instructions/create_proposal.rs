@@ -0,0 +1,52 @@
+//! Create proposal instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::CreateProposal;
+use crate::state::AdminAction;
+use crate::events::{ProposalCreated, ProposalApproved};
+
+/// Create a multisig admin proposal
+/// 
+/// The proposer's approval is recorded automatically.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `action` - The admin action to propose
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn create_proposal(ctx: Context<CreateProposal>, action: AdminAction) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let proposal = &mut ctx.accounts.proposal;
+    let proposer = &ctx.accounts.proposer;
+    let clock = Clock::get()?;
+
+    // Reject malformed actions up front rather than at execution time
+    action.validate()?;
+
+    let proposal_id = registry.next_proposal_id()?;
+    proposal.initialize(registry.key(), proposal_id, proposer.key(), action.clone(), &clock)?;
+
+    emit!(ProposalCreated {
+        registry: registry.key(),
+        proposal: proposal.key(),
+        proposal_id,
+        proposer: proposer.key(),
+        action,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(ProposalApproved {
+        registry: registry.key(),
+        proposal: proposal.key(),
+        proposal_id,
+        approver: proposer.key(),
+        approvals: proposal.approvals.len() as u8,
+        threshold: registry.multisig_threshold,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Proposal {} created by {}", proposal_id, proposer.key());
+    
+    Ok(())
+}
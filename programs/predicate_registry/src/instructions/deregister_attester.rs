@@ -1,14 +1,18 @@
 //! Deregister attester instruction for the predicate registry program
 
 use anchor_lang::prelude::*;
-use crate::instructions::DeregisterAttester;
+use crate::instructions::{DeregisterAttester, close_attester, verify_role_or_owner};
+use crate::state::Role;
 use crate::events::AttesterDeregistered;
 
 /// Deregister an existing attester
 /// 
-/// This instruction closes the attester account, deleting it and returning
-/// the rent to the authority who originally paid for it. This allows the
-/// attester to be re-registered later if needed.
+/// This instruction closes the attester account along with its metadata and
+/// scope accounts, where they exist, returning the rent to the signer. This
+/// allows the attester to be re-registered later if needed, without
+/// inheriting the old metadata or scope. The attester is removed from the
+/// attester index, which requires its index page (and the last page, unless
+/// they coincide).
 /// 
 /// # Arguments
 /// * `ctx` - The instruction context containing accounts
@@ -16,14 +20,32 @@ use crate::events::AttesterDeregistered;
 /// 
 /// # Returns
 /// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can deregister
 pub fn deregister_attester(ctx: Context<DeregisterAttester>, attester: Pubkey) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+
     let registry = &mut ctx.accounts.registry;
-    let _attester_account = &ctx.accounts.attester_account;
+    let attester_account = &mut ctx.accounts.attester_account;
     let authority = &ctx.accounts.authority;
     let clock = Clock::get()?;
 
-    // Note: The account is automatically closed by the `close = authority` constraint
-    // in the DeregisterAttester context, which deletes the account and returns rent.
+    // Remove the attester from the attester index and close its accounts,
+    // returning the rent to the signer
+    close_attester(
+        registry,
+        attester_account,
+        (&mut ctx.accounts.index_page, ctx.accounts.tail_page.as_deref_mut()),
+        (&ctx.accounts.metadata_account, &ctx.accounts.scope_account),
+        &authority.to_account_info(),
+        &clock,
+    )?;
 
     // Update registry statistics
     registry.decrement_attester_count(&clock)?;
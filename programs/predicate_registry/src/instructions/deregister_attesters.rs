@@ -0,0 +1,166 @@
+//! Deregister attesters instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{DeregisterAttesters, close_attester, verify_role_or_owner};
+use crate::state::{AttesterAccount, AttesterIndexPage, Role};
+use crate::events::AttesterDeregistered;
+use crate::errors::PredicateRegistryError;
+
+/// Accounts passed in `remaining_accounts` for each attester: the attester,
+/// metadata and scope PDAs
+const ACCOUNTS_PER_ATTESTER: usize = 3;
+
+/// Deregister a batch of attesters
+/// 
+/// Closes one attester account per key with `close_attester`, as the
+/// single-attester paths do: the attester is removed from the attester index
+/// and its metadata and scope accounts are closed with it, returning all the
+/// rent to the signer. `remaining_accounts` holds the attester, metadata and
+/// scope PDAs for each key, in order, followed by the index pages. The batch
+/// is all-or-nothing: any invalid key or account fails the whole instruction.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attesters` - The public keys of the attesters to deregister
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can deregister
+/// * Each attester's PDAs must be the ones derived for the key at the same position;
+///   metadata and scope PDAs that were never created are passed all the same
+/// * Index pages must belong to this registry and appear once each
+/// * Bonded attesters are refused, as by `deregister_attester`
+pub fn deregister_attesters<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeregisterAttesters<'info>>,
+    attesters: Vec<Pubkey>,
+) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+    AttesterAccount::validate_batch(&attesters)?;
+    let attester_accounts_len = attesters.len() * ACCOUNTS_PER_ATTESTER;
+    require!(
+        ctx.remaining_accounts.len() > attester_accounts_len,
+        PredicateRegistryError::InvalidAccountData
+    );
+    let (attester_infos, page_infos) = ctx.remaining_accounts.split_at(attester_accounts_len);
+
+    let registry_key = ctx.accounts.registry.key();
+    let namespace = ctx.accounts.registry.namespace_seed(&registry_key);
+    let authority = ctx.accounts.authority.to_account_info();
+    let clock = Clock::get()?;
+    let mut index_pages = load_index_pages(page_infos, &registry_key)?;
+
+    for (attester, infos) in attesters.iter().zip(attester_infos.chunks_exact(ACCOUNTS_PER_ATTESTER)) {
+        let [attester_info, metadata_info, scope_info] = infos else {
+            return err!(PredicateRegistryError::InvalidAccountData);
+        };
+        let (attester_pda, _) = Pubkey::find_program_address(
+            &[b"attester", namespace.as_ref(), attester.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(attester_info.key(), attester_pda, PredicateRegistryError::InvalidAccountData);
+        require!(!attester_info.data_is_empty(), PredicateRegistryError::AttesterNotRegistered);
+
+        let mut attester_account = Account::<AttesterAccount>::try_from(attester_info)?;
+        require!(attester_account.is_listed(), PredicateRegistryError::AttesterNotRegistered);
+        require!(attester_account.bond == 0, PredicateRegistryError::AttesterBonded);
+
+        let (metadata_pda, _) = Pubkey::find_program_address(
+            &[b"attester_metadata", namespace.as_ref(), attester.as_ref()],
+            ctx.program_id,
+        );
+        let (scope_pda, _) = Pubkey::find_program_address(
+            &[b"attester_scope", namespace.as_ref(), attester_account.identity.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(metadata_info.key(), metadata_pda, PredicateRegistryError::InvalidAccountData);
+        require_keys_eq!(scope_info.key(), scope_pda, PredicateRegistryError::InvalidAccountData);
+
+        let last_page = ctx.accounts.registry.attester_index_last_page();
+        let pages = select_index_pages(last_page, &attester_account, &mut index_pages)?;
+        close_attester(
+            &mut ctx.accounts.registry,
+            &mut attester_account,
+            pages,
+            (metadata_info, scope_info),
+            &authority,
+            &clock,
+        )?;
+
+        emit!(AttesterDeregistered {
+            registry: registry_key,
+            attester: *attester,
+            authority: authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    for index_page in &index_pages {
+        index_page.exit(ctx.program_id)?;
+    }
+
+    // Update registry statistics once for the whole batch
+    ctx.accounts.registry.decrement_attester_count_by(attesters.len() as u64, &clock)?;
+
+    msg!("{} attesters deregistered by authority {}", attesters.len(), authority.key());
+
+    Ok(())
+}
+
+/// Load the attester index pages passed after the attester PDAs
+/// 
+/// Each page is loaded once, so a page passed twice is refused rather than
+/// written back twice.
+fn load_index_pages<'info>(
+    page_infos: &'info [AccountInfo<'info>],
+    registry_key: &Pubkey,
+) -> Result<Vec<Account<'info, AttesterIndexPage>>> {
+    let mut index_pages: Vec<Account<'info, AttesterIndexPage>> = Vec::with_capacity(page_infos.len());
+    for page_info in page_infos {
+        let index_page = Account::<AttesterIndexPage>::try_from(page_info)?;
+        require!(
+            index_page.registry == *registry_key
+                && index_pages.iter().all(|loaded| loaded.page != index_page.page),
+            PredicateRegistryError::AttesterIndexMismatch
+        );
+        index_pages.push(index_page);
+    }
+    Ok(index_pages)
+}
+
+/// Pick the index pages an attester's removal touches from the loaded pages
+/// 
+/// Returns the page listing the attester and the index's current last page
+/// unless they coincide, as `deregister_attester` receives them.
+fn select_index_pages<'a>(
+    last_page: u32,
+    attester_account: &AttesterAccount,
+    index_pages: &'a mut [Account<AttesterIndexPage>],
+) -> Result<(&'a mut AttesterIndexPage, Option<&'a mut AttesterIndexPage>)> {
+    require!(attester_account.indexed, PredicateRegistryError::AttesterNotIndexed);
+    let listing = index_pages
+        .iter()
+        .position(|index_page| index_page.attesters.contains(&attester_account.attester))
+        .ok_or(PredicateRegistryError::AttesterIndexMismatch)?;
+    let last = index_pages
+        .iter()
+        .position(|index_page| index_page.page == last_page)
+        .ok_or(PredicateRegistryError::AttesterIndexMismatch)?;
+
+    if listing == last {
+        return Ok((&mut index_pages[listing], None));
+    }
+    if listing < last {
+        let (head, tail) = index_pages.split_at_mut(last);
+        Ok((&mut head[listing], Some(&mut tail[0])))
+    } else {
+        let (head, tail) = index_pages.split_at_mut(listing);
+        Ok((&mut tail[0], Some(&mut head[last])))
+    }
+}
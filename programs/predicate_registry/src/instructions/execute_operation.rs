@@ -0,0 +1,65 @@
+//! Execute operation instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{ExecuteOperation, AdminActionAccounts, apply_admin_action};
+use crate::events::OperationExecuted;
+use crate::errors::PredicateRegistryError;
+
+/// Execute a queued admin operation once its timelock has elapsed
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// - The eta is fixed when the operation is queued
+/// - Nothing executes once the authority is renounced, so the frozen registry stays fixed
+/// - Attester and role PDAs are derived from the keys named in the operation action
+/// - The operation account is closed, so it cannot be executed twice
+pub fn execute_operation(ctx: Context<ExecuteOperation>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let clock = Clock::get()?;
+
+    require!(
+        accounts.operation.is_ready(clock.unix_timestamp),
+        PredicateRegistryError::TimelockNotExpired
+    );
+
+    let operation_key = accounts.operation.key();
+    let operation_id = accounts.operation.operation_id;
+    let action = accounts.operation.action.clone();
+
+    apply_admin_action(
+        &action,
+        &mut accounts.registry,
+        operation_key,
+        AdminActionAccounts {
+            new_attester_account: accounts.new_attester_account.as_mut(),
+            attester_account: accounts.attester_account.as_mut(),
+            new_role_account: accounts.new_role_account.as_mut(),
+            role_account: accounts.role_account.as_ref(),
+            index_tail_page: accounts.index_tail_page.as_mut(),
+            index_page: accounts.index_page.as_mut(),
+            index_last_page: accounts.index_last_page.as_mut(),
+            metadata_account: accounts.metadata_account.as_deref(),
+            scope_account: accounts.scope_account.as_deref(),
+        },
+        &accounts.queued_by,
+        &clock,
+    )?;
+
+    emit!(OperationExecuted {
+        registry: accounts.registry.key(),
+        operation: operation_key,
+        operation_id,
+        executor: accounts.executor.key(),
+        action,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Operation {} executed by {}", operation_id, accounts.executor.key());
+    
+    Ok(())
+}
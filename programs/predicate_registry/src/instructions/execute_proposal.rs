@@ -0,0 +1,110 @@
+//! Execute proposal instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{ExecuteProposal, AdminActionAccounts, apply_admin_action};
+use crate::events::{ProposalExecuted, OperationQueued};
+use crate::errors::PredicateRegistryError;
+
+/// Execute a multisig admin proposal that has reached its threshold
+/// 
+/// When the registry timelock is enabled, the first call after the threshold
+/// is met queues the proposal (setting its eta) instead of executing it; a
+/// second call after the eta applies the action.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// - Only approvals from keys currently in the signer set are counted
+/// - Attester and role PDAs are derived from the keys named in the proposal action
+/// - The proposal account is closed, so it cannot be executed twice
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let clock = Clock::get()?;
+
+    require!(
+        accounts.proposal.valid_approvals(&accounts.registry) >= accounts.registry.multisig_threshold as usize,
+        PredicateRegistryError::ThresholdNotMet
+    );
+
+    let proposal_key = accounts.proposal.key();
+    let proposal_id = accounts.proposal.proposal_id;
+    let action = accounts.proposal.action.clone();
+
+    if accounts.registry.is_timelock_enabled() {
+        if accounts.proposal.eta == 0 {
+            // Threshold met: start the timelock rather than executing.
+            // Attester, role, index, metadata and scope accounts are rejected here so none can be created or closed early.
+            require!(
+                accounts.new_attester_account.is_none()
+                    && accounts.attester_account.is_none()
+                    && accounts.new_role_account.is_none()
+                    && accounts.role_account.is_none()
+                    && accounts.index_tail_page.is_none()
+                    && accounts.index_page.is_none()
+                    && accounts.index_last_page.is_none()
+                    && accounts.metadata_account.is_none()
+                    && accounts.scope_account.is_none(),
+                PredicateRegistryError::ProposalAccountMismatch
+            );
+
+            let eta = accounts.registry.timelock_eta(&clock)?;
+            accounts.proposal.eta = eta;
+
+            emit!(OperationQueued {
+                registry: accounts.registry.key(),
+                operation: proposal_key,
+                queued_by: accounts.executor.key(),
+                action,
+                eta,
+                timestamp: clock.unix_timestamp,
+            });
+
+            msg!("Proposal {} queued, executable at {}", proposal_id, eta);
+            return Ok(());
+        }
+
+        require!(
+            clock.unix_timestamp >= accounts.proposal.eta,
+            PredicateRegistryError::TimelockNotExpired
+        );
+    }
+
+    apply_admin_action(
+        &action,
+        &mut accounts.registry,
+        proposal_key,
+        AdminActionAccounts {
+            new_attester_account: accounts.new_attester_account.as_mut(),
+            attester_account: accounts.attester_account.as_mut(),
+            new_role_account: accounts.new_role_account.as_mut(),
+            role_account: accounts.role_account.as_ref(),
+            index_tail_page: accounts.index_tail_page.as_mut(),
+            index_page: accounts.index_page.as_mut(),
+            index_last_page: accounts.index_last_page.as_mut(),
+            metadata_account: accounts.metadata_account.as_deref(),
+            scope_account: accounts.scope_account.as_deref(),
+        },
+        &accounts.proposer,
+        &clock,
+    )?;
+
+    // Close the proposal so it cannot be executed twice
+    accounts.proposal.close(accounts.proposer.clone())?;
+
+    emit!(ProposalExecuted {
+        registry: accounts.registry.key(),
+        proposal: proposal_key,
+        proposal_id,
+        executor: accounts.executor.key(),
+        action,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Proposal {} executed by {}", proposal_id, accounts.executor.key());
+    
+    Ok(())
+}
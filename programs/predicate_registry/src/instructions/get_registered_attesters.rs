@@ -0,0 +1,25 @@
+//! Get registered attesters instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::GetRegisteredAttesters;
+
+/// Return one page of the attester index
+/// 
+/// Pages run from 0 to `attester_index_len / ATTESTER_INDEX_PAGE_SIZE` on
+/// the registry. Entries stay listed while an attester is suspended or its
+/// rotated-out key is still registered, so callers that need the current
+/// status should check each attester account.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `page` - The index page to read
+/// 
+/// # Returns
+/// * `Result<Vec<Pubkey>>` - The attesters on the page, also set as return data
+pub fn get_registered_attesters(ctx: Context<GetRegisteredAttesters>, page: u32) -> Result<Vec<Pubkey>> {
+    let attesters = ctx.accounts.index_page.attesters.clone();
+
+    msg!("Attester index page {} lists {} attesters", page, attesters.len());
+
+    Ok(attesters)
+}
@@ -0,0 +1,35 @@
+//! Grant role instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::GrantRole;
+use crate::events::RoleGranted;
+use crate::state::Role;
+
+/// Grant a role to a key by creating its role account
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `role` - The role to grant
+/// * `holder` - The key receiving the role
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn grant_role(ctx: Context<GrantRole>, role: Role, holder: Pubkey) -> Result<()> {
+    let role_account = &mut ctx.accounts.role_account;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    role_account.initialize(role, holder, authority.key(), &clock)?;
+
+    emit!(RoleGranted {
+        registry: ctx.accounts.registry.key(),
+        role,
+        holder,
+        authority: authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Role granted to {} by authority {}", holder, authority.key());
+    
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! Index attester instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{IndexAttester, add_to_attester_index};
+
+/// List a legacy attester in the attester index
+/// 
+/// Every registration lists its attester, so this only backfills attesters
+/// registered before the index existed.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the attester to list
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the registry authority can list attesters, and only registered or
+///   suspended attesters that are not yet indexed
+pub fn index_attester(ctx: Context<IndexAttester>, attester: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+
+    add_to_attester_index(
+        &mut ctx.accounts.registry,
+        &mut ctx.accounts.attester_account,
+        &mut ctx.accounts.index_page,
+        &clock,
+    )?;
+
+    msg!("Attester {} listed in the attester index", attester);
+
+    Ok(())
+}
@@ -1,32 +1,44 @@
 //! Initialize instruction for the predicate registry program
 
 use anchor_lang::prelude::*;
-use crate::instructions::Initialize;
+use crate::instructions::{Initialize, verify_upgrade_authority};
 use crate::events::RegistryInitialized;
 
 /// Initialize a new predicate registry
 /// 
 /// # Arguments
 /// * `ctx` - The instruction context containing accounts
+/// * `registry_id` - Namespace identifier for the new registry (PDA seed)
 /// 
 /// # Returns
 /// * `Result<()>` - Success or error
-pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+/// 
+/// # Security
+/// - Verifies the signer is this program's upgrade authority via `verify_upgrade_authority()`,
+///   so only the deployer can claim registries after a fresh deploy
+pub fn initialize(ctx: Context<Initialize>, registry_id: u64) -> Result<()> {
+    // Verify the signer is the registry program's upgrade authority
+    verify_upgrade_authority(
+        &ctx.accounts.program_data,
+        &ctx.accounts.authority.key(),
+    )?;
+
     let registry = &mut ctx.accounts.registry;
     let authority = &ctx.accounts.authority;
     let clock = Clock::get()?;
 
     // Initialize the registry
-    registry.initialize(authority.key(), &clock)?;
+    registry.initialize(authority.key(), registry_id, ctx.bumps.registry, &clock)?;
 
     // Emit initialization event
     emit!(RegistryInitialized {
         registry: registry.key(),
+        registry_id,
         authority: authority.key(),
         timestamp: clock.unix_timestamp,
     });
 
-    msg!("Predicate registry initialized with authority: {}", authority.key());
+    msg!("Predicate registry {} initialized with authority: {}", registry_id, authority.key());
     
     Ok(())
 }
@@ -0,0 +1,66 @@
+//! Migrate account instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::instructions::MigrateAccount;
+use crate::events::AccountMigrated;
+use crate::migration::migrate_account_data;
+
+/// Upgrade an account with an older layout to the current layout in place
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// - Only accounts owned by this program with a supported discriminator are accepted
+/// - Existing fields are decoded with their legacy layout and written back unchanged
+/// - Already migrated accounts are rejected via `AccountAlreadyMigrated`
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    let payer = &ctx.accounts.payer;
+    let clock = Clock::get()?;
+
+    let migrated = migrate_account_data(&account.try_borrow_data()?)?;
+    let space = migrated.space.max(account.data_len());
+
+    // Top up rent for the added space
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+
+    account.realloc(space, false)?;
+    let mut data = account.try_borrow_mut_data()?;
+    data[..migrated.data.len()].copy_from_slice(&migrated.data);
+    data[migrated.data.len()..].fill(0);
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        from_version: migrated.from_version,
+        to_version: migrated.to_version,
+        payer: payer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Migrated account {} from version {} to version {}",
+        account.key(),
+        migrated.from_version,
+        migrated.to_version
+    );
+    
+    Ok(())
+}
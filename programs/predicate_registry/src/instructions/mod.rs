@@ -16,24 +16,57 @@ pub mod set_policy_id;
 pub mod update_policy_id;
 pub mod validate_attestation;
 pub mod cleanup_expired_uuid;
-pub mod transfer_authority;
-
-/// Clock drift buffer for attestation expiration validation
-/// 
-/// This constant defines the time window (in seconds) after an attestation's
-/// expiration time during which the attestation is still considered valid.
-/// This buffer accounts for clock drift between different systems.
-/// 
-/// This value must be consistent across:
-/// - `validate_attestation`: Allows validation if `current_timestamp <= expiration + CLOCK_DRIFT_BUFFER`
-/// - `cleanup_expired_uuid`: Prevents cleanup if `current_timestamp <= expiration + CLOCK_DRIFT_BUFFER`
-pub const CLOCK_DRIFT_BUFFER: i64 = 30; // 30 seconds
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod configure_multisig;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod set_timelock_delay;
+pub mod queue_operation;
+pub mod execute_operation;
+pub mod cancel_operation;
+pub mod pause;
+pub mod unpause;
+pub mod set_guardian;
+pub mod pause_policy;
+pub mod unpause_policy;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_attestation_config;
+pub mod migrate_account;
+pub mod renounce_authority;
+pub mod update_attester_metadata;
+pub mod set_attester_validity;
+pub mod rotate_attester_key;
+pub mod set_attester_scope;
+pub mod set_attester_quorum;
+pub mod validate_threshold_attestation;
+pub mod set_bond_config;
+pub mod register_with_bond;
+pub mod begin_unbonding;
+pub mod withdraw_bond;
+pub mod post_collateral;
+pub mod slash_attester;
+pub mod suspend_attester;
+pub mod reinstate_attester;
+pub mod revoke_attester_after;
+pub mod index_attester;
+pub mod get_registered_attesters;
+pub mod register_attesters;
+pub mod deregister_attesters;
+pub mod register_secp256k1_attester;
+pub mod register_secp256r1_attester;
+pub mod set_digest_scheme;
 
 /// Verify that the signer is the upgrade authority for a given program
 /// 
 /// Parses the ProgramData account to extract and validate the upgrade authority.
 /// This is the single source of truth for upgrade authority verification,
-/// used by both `set_policy_id` and `update_policy_id`.
+/// used by `set_policy_id`, `update_policy_id`, `pause_policy` and
+/// `unpause_policy` for client programs, and by `initialize` for the
+/// registry program itself.
 /// 
 /// # Arguments
 /// * `program_data` - The ProgramData account for the target program
@@ -73,146 +106,2439 @@ pub fn verify_upgrade_authority(
     let has_authority = program_data_bytes[12] == 1;
     require!(has_authority, PredicateRegistryError::Unauthorized);
     
-    // Extract upgrade authority (bytes 13-44)
-    let mut authority_bytes = [0u8; 32];
-    authority_bytes.copy_from_slice(&program_data_bytes[13..45]);
-    let upgrade_authority = Pubkey::new_from_array(authority_bytes);
+    // Extract upgrade authority (bytes 13-44)
+    let mut authority_bytes = [0u8; 32];
+    authority_bytes.copy_from_slice(&program_data_bytes[13..45]);
+    let upgrade_authority = Pubkey::new_from_array(authority_bytes);
+    
+    // Verify the signer matches the upgrade authority
+    require!(
+        upgrade_authority == *expected_authority,
+        PredicateRegistryError::Unauthorized
+    );
+    
+    Ok(())
+}
+
+/// Verify that the signer may act with the given role
+/// 
+/// The signer must hold the role (proven by their role PDA) or be the
+/// registry owner. Either way the registry must be in single-authority mode:
+/// once multisig or timelock mode is enabled, role-gated actions go through
+/// proposals or queued operations like every other admin action. Nobody may
+/// act once the authority has been renounced.
+/// 
+/// # Arguments
+/// * `registry` - The registry being administered
+/// * `signer` - The signer's public key
+/// * `role_account` - The signer's role PDA, if they claim to hold the role
+/// * `role` - The role required for the action
+/// 
+/// # Returns
+/// * `Ok(())` if the signer holds the role or is the owner
+/// * `Err` if the registry is renounced, the signer is unauthorized, or the action must use a proposal or queued operation
+pub fn verify_role_or_owner(
+    registry: &PredicateRegistry,
+    signer: &Pubkey,
+    role_account: Option<&Account<RoleAccount>>,
+    role: Role,
+) -> Result<()> {
+    // Renouncing freezes the registry, including previously granted roles
+    require!(!registry.is_renounced(), PredicateRegistryError::AuthorityRenounced);
+
+    match role_account {
+        // The PDA seeds already bind the role and holder; this is a defensive check
+        Some(role_account) => require!(
+            role_account.role == role && role_account.holder == *signer,
+            PredicateRegistryError::Unauthorized
+        ),
+        None => require!(registry.authority == *signer, PredicateRegistryError::Unauthorized),
+    }
+
+    require!(!registry.is_multisig_enabled(), PredicateRegistryError::MultisigEnabled);
+    require!(!registry.is_timelock_enabled(), PredicateRegistryError::TimelockEnabled);
+    Ok(())
+}
+
+/// Verify that the signer is the attester itself or the registry owner
+/// 
+/// Used by the bond instructions, which attesters drive themselves but the
+/// owner must also be able to drive for attesters that cannot sign Solana
+/// transactions (secp256k1 and P-256 keys) or have to be removed. The owner
+/// may only act in single-authority mode.
+/// 
+/// # Arguments
+/// * `registry` - The registry the attester belongs to
+/// * `signer` - The signer's public key
+/// * `attester` - The attester's public key
+/// 
+/// # Returns
+/// * `Ok(())` if the signer is the attester or the owner
+/// * `Err` if the signer is neither, or the owner must use a proposal or queued operation
+pub fn verify_attester_or_owner(
+    registry: &PredicateRegistry,
+    signer: &Pubkey,
+    attester: &Pubkey,
+) -> Result<()> {
+    if signer == attester {
+        return Ok(());
+    }
+    require!(!registry.is_renounced(), PredicateRegistryError::AuthorityRenounced);
+    require!(registry.authority == *signer, PredicateRegistryError::Unauthorized);
+    require!(!registry.is_multisig_enabled(), PredicateRegistryError::MultisigEnabled);
+    require!(!registry.is_timelock_enabled(), PredicateRegistryError::TimelockEnabled);
+    Ok(())
+}
+
+/// List a newly registered attester key in the attester index
+/// 
+/// Every path that creates an attester account calls this in the same
+/// instruction, so the index lists exactly the registered and suspended
+/// attesters (apart from legacy attesters awaiting `index_attester`).
+/// 
+/// # Arguments
+/// * `registry` - The registry the attester belongs to
+/// * `attester_account` - The attester account being registered
+/// * `tail_page` - The index page at `attester_index_tail_page()`
+/// * `clock` - The current clock
+pub fn add_to_attester_index(
+    registry: &mut Account<PredicateRegistry>,
+    attester_account: &mut AttesterAccount,
+    tail_page: &mut AttesterIndexPage,
+    clock: &Clock,
+) -> Result<()> {
+    let registry_key = registry.key();
+    let page = registry.index_attester(registry_key, attester_account, tail_page)?;
+
+    emit!(crate::events::AttesterIndexed {
+        registry: registry_key,
+        attester: attester_account.attester,
+        page,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Remove a deregistered attester key from the attester index
+/// 
+/// Every path that deregisters an attester calls this in the same
+/// instruction. Attesters that are not indexed are refused, so legacy
+/// attesters must be listed with `index_attester` before they can leave.
+/// 
+/// # Arguments
+/// * `registry` - The registry the attester belongs to
+/// * `attester_account` - The attester account being deregistered
+/// * `page` - The index page listing the attester
+/// * `last_page` - The index's last page, unless it is `page`
+/// * `clock` - The current clock
+pub fn remove_from_attester_index(
+    registry: &mut Account<PredicateRegistry>,
+    attester_account: &mut AttesterAccount,
+    page: &mut AttesterIndexPage,
+    last_page: Option<&mut AttesterIndexPage>,
+    clock: &Clock,
+) -> Result<()> {
+    let page = registry.unindex_attester(attester_account, page, last_page)?;
+
+    emit!(crate::events::AttesterUnindexed {
+        registry: registry.key(),
+        attester: attester_account.attester,
+        page,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Record the registration of a freshly initialized attester account
+/// 
+/// Shared by every path that registers an attester (single, batch, bonded,
+/// proposal and operation): lists the attester in the attester index, stores
+/// its metadata if any and emits the registration events. The caller creates
+/// and initializes the account for its key type and updates `total_attesters`.
+/// 
+/// # Arguments
+/// * `registry` - The registry the attester belongs to
+/// * `attester_account` - The initialized attester account
+/// * `tail_page` - The index page at `attester_index_tail_page()`
+/// * `metadata` - The metadata to store, with the attester's metadata account
+/// * `actor` - The key recorded as the registering authority in emitted events
+/// * `clock` - The current clock
+pub fn open_attester(
+    registry: &mut Account<PredicateRegistry>,
+    attester_account: &mut AttesterAccount,
+    tail_page: &mut AttesterIndexPage,
+    metadata: Option<(AttesterMetadata, &mut AttesterMetadataAccount)>,
+    actor: Pubkey,
+    clock: &Clock,
+) -> Result<()> {
+    use crate::events::{AttesterMetadataUpdated, AttesterRegistered};
+
+    add_to_attester_index(registry, attester_account, tail_page, clock)?;
+    let attester = attester_account.attester;
+
+    emit!(AttesterRegistered {
+        registry: registry.key(),
+        attester,
+        authority: actor,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if let Some((metadata, metadata_account)) = metadata {
+        metadata_account.set(attester, metadata.clone(), actor, clock)?;
+
+        emit!(AttesterMetadataUpdated {
+            registry: registry.key(),
+            attester,
+            metadata,
+            updated_by: actor,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    Ok(())
+}
+
+/// Close a deregistered attester account along with its side accounts
+/// 
+/// Shared by every path that deregisters an attester (single, batch,
+/// proposal and operation): removes the attester from the attester index and
+/// closes the attester, metadata and scope accounts, returning their rent.
+/// The metadata and scope PDAs are always passed, so none can be left behind
+/// for a later registration of the key to inherit; empty ones are skipped.
+/// The scope account is shared by every key of the identity, so it is left
+/// open when the key has been rotated away. The caller checks the attester
+/// can leave, emits `AttesterDeregistered` and updates `total_attesters`.
+/// 
+/// # Arguments
+/// * `registry` - The registry the attester belongs to
+/// * `attester_account` - The attester account to close
+/// * `index_pages` - The index page listing the attester, and the index's last page unless it is that page
+/// * `side_accounts` - The attester's metadata PDA and its identity's scope PDA (checked by the caller)
+/// * `rent_recipient` - Receives the rent of the closed accounts
+/// * `clock` - The current clock
+pub fn close_attester<'info>(
+    registry: &mut Account<'info, PredicateRegistry>,
+    attester_account: &mut Account<'info, AttesterAccount>,
+    index_pages: (&mut AttesterIndexPage, Option<&mut AttesterIndexPage>),
+    side_accounts: (&AccountInfo<'info>, &AccountInfo<'info>),
+    rent_recipient: &AccountInfo<'info>,
+    clock: &Clock,
+) -> Result<()> {
+    let (index_page, last_page) = index_pages;
+    remove_from_attester_index(registry, attester_account, index_page, last_page, clock)?;
+    close_side_accounts(attester_account, side_accounts, rent_recipient)?;
+    attester_account.close(rent_recipient.clone())
+}
+
+/// Close an exiting attester's metadata and scope accounts, where they exist
+/// 
+/// Used by `close_attester` and by `begin_unbonding`, which deregisters the
+/// attester but keeps its account until the bond is withdrawn. The scope
+/// account is left open when the key has been rotated away, as the new key
+/// shares it.
+/// 
+/// # Arguments
+/// * `attester_account` - The exiting attester
+/// * `side_accounts` - The attester's metadata PDA and its identity's scope PDA (checked by the caller)
+/// * `rent_recipient` - Receives the rent of the closed accounts
+pub fn close_side_accounts<'info>(
+    attester_account: &AttesterAccount,
+    side_accounts: (&AccountInfo<'info>, &AccountInfo<'info>),
+    rent_recipient: &AccountInfo<'info>,
+) -> Result<()> {
+    let (metadata_info, scope_info) = side_accounts;
+    close_if_exists::<AttesterMetadataAccount>(metadata_info, rent_recipient)?;
+    if attester_account.next_key.is_none() {
+        close_if_exists::<AttesterScopeAccount>(scope_info, rent_recipient)?;
+    }
+    Ok(())
+}
+
+/// Close a program account of type `T` at an already checked PDA, unless it
+/// was never created
+fn close_if_exists<'info, T: AccountDeserialize>(
+    info: &AccountInfo<'info>,
+    rent_recipient: &AccountInfo<'info>,
+) -> Result<()> {
+    if info.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(*info.owner, crate::ID, PredicateRegistryError::InvalidAccountData);
+    // Checks the discriminator, so only an account of the expected type is closed
+    T::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+    // Close as Anchor's `close` constraint does: move the rent, then hand the
+    // emptied account back to the system program
+    let recipient_lamports = rent_recipient.lamports()
+        .checked_add(info.lamports())
+        .ok_or(PredicateRegistryError::ArithmeticError)?;
+    **rent_recipient.try_borrow_mut_lamports()? = recipient_lamports;
+    **info.try_borrow_mut_lamports()? = 0;
+    info.assign(&anchor_lang::system_program::ID);
+    info.resize(0)?;
+    Ok(())
+}
+
+/// Optional accounts that an `AdminAction` may create or close
+/// 
+/// Each must be supplied exactly when the action needs it (see
+/// `apply_admin_action`).
+pub struct AdminActionAccounts<'a, 'info> {
+    /// Freshly created attester PDA (for `RegisterAttester`, `RegisterSecp256k1Attester`, `RegisterSecp256r1Attester` and `RotateAttesterKey`)
+    pub new_attester_account: Option<&'a mut Account<'info, AttesterAccount>>,
+    /// Existing attester PDA to close or update (for `DeregisterAttester`, `SetAttesterValidity`, `SuspendAttester`, `ReinstateAttester`, `RevokeAttesterAfter` and `RotateAttesterKey`)
+    pub attester_account: Option<&'a mut Account<'info, AttesterAccount>>,
+    /// Freshly created role PDA (for `GrantRole`)
+    pub new_role_account: Option<&'a mut Account<'info, RoleAccount>>,
+    /// Existing role PDA to close (for `RevokeRole`)
+    pub role_account: Option<&'a Account<'info, RoleAccount>>,
+    /// The attester index's tail page, to list the new key on (for `RegisterAttester`, `RegisterSecp256k1Attester`, `RegisterSecp256r1Attester` and `RotateAttesterKey`)
+    pub index_tail_page: Option<&'a mut Account<'info, AttesterIndexPage>>,
+    /// The index page listing the attester (for `DeregisterAttester`)
+    pub index_page: Option<&'a mut Account<'info, AttesterIndexPage>>,
+    /// The index's last page, unless it is `index_page` (for `DeregisterAttester`)
+    pub index_last_page: Option<&'a mut Account<'info, AttesterIndexPage>>,
+    /// The attester's metadata PDA, closed if it exists (for `DeregisterAttester`)
+    pub metadata_account: Option<&'a AccountInfo<'info>>,
+    /// The identity's scope PDA, closed if it exists (for `DeregisterAttester`)
+    pub scope_account: Option<&'a AccountInfo<'info>>,
+}
+
+/// Apply an administrative action on behalf of the multisig or timelock
+/// 
+/// Performs the same state changes as the corresponding single-authority
+/// instruction and emits the same events, with `actor` recorded as the
+/// authority. This is the single source of truth for executing an
+/// `AdminAction`, used by both `execute_proposal` and `execute_operation`.
+/// 
+/// # Arguments
+/// * `action` - The action to apply
+/// * `registry` - The registry being administered
+/// * `actor` - The key recorded as the acting authority in emitted events
+/// * `accounts` - Optional attester, role, attester index, metadata and scope accounts the action touches
+/// * `rent_recipient` - Receives rent from closed attester, metadata, scope and role accounts
+/// * `clock` - The current clock
+pub fn apply_admin_action<'info>(
+    action: &AdminAction,
+    registry: &mut Account<'info, PredicateRegistry>,
+    actor: Pubkey,
+    accounts: AdminActionAccounts<'_, 'info>,
+    rent_recipient: &AccountInfo<'info>,
+    clock: &Clock,
+) -> Result<()> {
+    use crate::events::*;
+
+    // Optional accounts must be supplied exactly when the action needs them,
+    // so stray accounts can never be created or closed as a side effect
+    require!(
+        accounts.new_attester_account.is_some() == action.new_attester().is_some()
+            && accounts.attester_account.is_some() == matches!(
+                action,
+                AdminAction::DeregisterAttester { .. }
+                    | AdminAction::SetAttesterValidity { .. }
+                    | AdminAction::SuspendAttester { .. }
+                    | AdminAction::ReinstateAttester { .. }
+                    | AdminAction::RevokeAttesterAfter { .. }
+                    | AdminAction::RotateAttesterKey { .. }
+            )
+            && accounts.new_role_account.is_some() == matches!(action, AdminAction::GrantRole { .. })
+            && accounts.role_account.is_some() == matches!(action, AdminAction::RevokeRole { .. })
+            && accounts.index_tail_page.is_some() == action.new_attester().is_some()
+            && accounts.index_page.is_some() == matches!(action, AdminAction::DeregisterAttester { .. })
+            && (accounts.index_last_page.is_none() || accounts.index_page.is_some())
+            && accounts.metadata_account.is_some() == matches!(action, AdminAction::DeregisterAttester { .. })
+            && accounts.scope_account.is_some() == matches!(action, AdminAction::DeregisterAttester { .. }),
+        PredicateRegistryError::ProposalAccountMismatch
+    );
+
+    match action {
+        AdminAction::RegisterAttester { attester } => {
+            let (Some(attester_account), Some(index_tail_page)) =
+                (accounts.new_attester_account, accounts.index_tail_page)
+            else {
+                return err!(PredicateRegistryError::ProposalAccountMismatch);
+            };
+            attester_account.initialize(*attester, clock)?;
+            registry.increment_attester_count(clock)?;
+            open_attester(registry, attester_account, index_tail_page, None, actor, clock)?;
+        }
+        AdminAction::RegisterSecp256k1Attester { eth_address } => {
+            let (Some(attester_account), Some(index_tail_page)) =
+                (accounts.new_attester_account, accounts.index_tail_page)
+            else {
+                return err!(PredicateRegistryError::ProposalAccountMismatch);
+            };
+            attester_account.initialize_secp256k1(eth_address, clock)?;
+            registry.increment_attester_count(clock)?;
+            open_attester(registry, attester_account, index_tail_page, None, actor, clock)?;
+        }
+        AdminAction::RegisterSecp256r1Attester { public_key } => {
+            let (Some(attester_account), Some(index_tail_page)) =
+                (accounts.new_attester_account, accounts.index_tail_page)
+            else {
+                return err!(PredicateRegistryError::ProposalAccountMismatch);
+            };
+            attester_account.initialize_secp256r1(public_key, clock)?;
+            registry.increment_attester_count(clock)?;
+            open_attester(registry, attester_account, index_tail_page, None, actor, clock)?;
+        }
+        AdminAction::DeregisterAttester { attester } => {
+            let (Some(attester_account), Some(index_page), Some(metadata_account), Some(scope_account)) = (
+                accounts.attester_account,
+                accounts.index_page,
+                accounts.metadata_account,
+                accounts.scope_account,
+            ) else {
+                return err!(PredicateRegistryError::ProposalAccountMismatch);
+            };
+            require!(
+                attester_account.is_listed(),
+                PredicateRegistryError::AttesterNotRegistered
+            );
+            require!(attester_account.bond == 0, PredicateRegistryError::AttesterBonded);
+            close_attester(
+                registry,
+                attester_account,
+                (index_page, accounts.index_last_page.map(|page| &mut **page)),
+                (metadata_account, scope_account),
+                rent_recipient,
+                clock,
+            )?;
+            registry.decrement_attester_count(clock)?;
+
+            emit!(AttesterDeregistered {
+                registry: registry.key(),
+                attester: *attester,
+                authority: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::RotateAttesterKey { attester, new_key, overlap } => {
+            let (Some(attester_account), Some(new_attester_account), Some(index_tail_page)) =
+                (accounts.attester_account, accounts.new_attester_account, accounts.index_tail_page)
+            else {
+                return err!(PredicateRegistryError::ProposalAccountMismatch);
+            };
+            let old_key_valid_until =
+                attester_account.rotate_to(new_attester_account, *new_key, *overlap, clock)?;
+            registry.increment_attester_count(clock)?;
+            add_to_attester_index(registry, new_attester_account, index_tail_page, clock)?;
+
+            emit!(AttesterKeyRotated {
+                registry: registry.key(),
+                identity: attester_account.identity,
+                old_key: *attester,
+                new_key: *new_key,
+                old_key_valid_until,
+                authority: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::SetAttesterValidity { attester, valid_from, valid_until } => {
+            let attester_account = accounts.attester_account
+                .ok_or(PredicateRegistryError::ProposalAccountMismatch)?;
+            require!(
+                attester_account.is_registered,
+                PredicateRegistryError::AttesterNotRegistered
+            );
+            attester_account.set_validity(*valid_from, *valid_until)?;
+
+            emit!(AttesterValiditySet {
+                registry: registry.key(),
+                attester: *attester,
+                valid_from: *valid_from,
+                valid_until: *valid_until,
+                authority: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::SuspendAttester { attester } => {
+            let attester_account = accounts.attester_account
+                .ok_or(PredicateRegistryError::ProposalAccountMismatch)?;
+            let suspension_count = attester_account.suspend(clock)?;
+
+            emit!(AttesterSuspended {
+                registry: registry.key(),
+                attester: *attester,
+                suspension_count,
+                authority: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::ReinstateAttester { attester } => {
+            let attester_account = accounts.attester_account
+                .ok_or(PredicateRegistryError::ProposalAccountMismatch)?;
+            attester_account.reinstate(clock)?;
+
+            emit!(AttesterReinstated {
+                registry: registry.key(),
+                attester: *attester,
+                authority: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::RevokeAttesterAfter { attester, timestamp } => {
+            let attester_account = accounts.attester_account
+                .ok_or(PredicateRegistryError::ProposalAccountMismatch)?;
+            attester_account.revoke_after(*timestamp)?;
+
+            emit!(AttesterRevoked {
+                registry: registry.key(),
+                attester: *attester,
+                revoked_after: *timestamp,
+                authority: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::ProposeAuthority { new_authority } => {
+            registry.propose_authority(*new_authority, clock)?;
+
+            emit!(AuthorityTransferProposed {
+                registry: registry.key(),
+                authority: actor,
+                pending_authority: *new_authority,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::CancelAuthorityTransfer => {
+            let cancelled_authority = registry.cancel_authority_transfer(clock)?;
+
+            emit!(AuthorityTransferCancelled {
+                registry: registry.key(),
+                authority: actor,
+                cancelled_authority,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::ConfigureMultisig { signers, threshold } => {
+            registry.configure_multisig(signers.clone(), *threshold, clock)?;
+
+            emit!(MultisigConfigured {
+                registry: registry.key(),
+                signers: signers.clone(),
+                threshold: *threshold,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::SetTimelockDelay { delay } => {
+            let previous_delay = registry.timelock_delay;
+            registry.set_timelock_delay(*delay, clock)?;
+
+            emit!(TimelockDelaySet {
+                registry: registry.key(),
+                previous_delay,
+                new_delay: *delay,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::SetAttestationConfig { clock_drift_buffer, max_attestation_lifetime } => {
+            let previous_clock_drift_buffer = registry.clock_drift_buffer;
+            let previous_max_attestation_lifetime = registry.max_attestation_lifetime;
+            registry.set_attestation_config(*clock_drift_buffer, *max_attestation_lifetime, clock)?;
+
+            emit!(AttestationConfigSet {
+                registry: registry.key(),
+                previous_clock_drift_buffer,
+                new_clock_drift_buffer: *clock_drift_buffer,
+                previous_max_attestation_lifetime,
+                new_max_attestation_lifetime: *max_attestation_lifetime,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::SetBondConfig { attester_bond, unbonding_period, min_attester_collateral } => {
+            let previous_attester_bond = registry.attester_bond;
+            let previous_unbonding_period = registry.unbonding_period;
+            let previous_min_attester_collateral = registry.min_attester_collateral;
+            registry.set_bond_config(*attester_bond, *unbonding_period, *min_attester_collateral, clock)?;
+
+            emit!(BondConfigSet {
+                registry: registry.key(),
+                previous_attester_bond,
+                new_attester_bond: *attester_bond,
+                previous_unbonding_period,
+                new_unbonding_period: *unbonding_period,
+                previous_min_attester_collateral,
+                new_min_attester_collateral: *min_attester_collateral,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::Unpause => {
+            registry.unpause(clock)?;
+
+            emit!(RegistryUnpaused {
+                registry: registry.key(),
+                authority: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::SetGuardian { guardian } => {
+            let previous_guardian = registry.set_guardian(*guardian, clock)?;
+
+            emit!(GuardianSet {
+                registry: registry.key(),
+                previous_guardian,
+                new_guardian: *guardian,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::GrantRole { role, holder } => {
+            let role_account = accounts.new_role_account
+                .ok_or(PredicateRegistryError::ProposalAccountMismatch)?;
+            role_account.initialize(*role, *holder, actor, clock)?;
+
+            emit!(RoleGranted {
+                registry: registry.key(),
+                role: *role,
+                holder: *holder,
+                authority: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::RevokeRole { role, holder } => {
+            let role_account = accounts.role_account
+                .ok_or(PredicateRegistryError::ProposalAccountMismatch)?;
+            role_account.close(rent_recipient.clone())?;
+
+            emit!(RoleRevoked {
+                registry: registry.key(),
+                role: *role,
+                holder: *holder,
+                authority: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// Re-export instruction functions
+pub use initialize::*;
+pub use register_attester::*;
+pub use deregister_attester::*;
+pub use set_policy_id::*;
+pub use update_policy_id::*;
+pub use validate_attestation::*;
+pub use cleanup_expired_uuid::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use cancel_authority_transfer::*;
+pub use configure_multisig::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use set_timelock_delay::*;
+pub use set_attestation_config::*;
+pub use migrate_account::*;
+pub use renounce_authority::*;
+pub use update_attester_metadata::*;
+pub use set_attester_validity::*;
+pub use rotate_attester_key::*;
+pub use set_attester_scope::*;
+pub use set_attester_quorum::*;
+pub use validate_threshold_attestation::*;
+pub use set_bond_config::*;
+pub use register_with_bond::*;
+pub use begin_unbonding::*;
+pub use withdraw_bond::*;
+pub use post_collateral::*;
+pub use slash_attester::*;
+pub use suspend_attester::*;
+pub use reinstate_attester::*;
+pub use revoke_attester_after::*;
+pub use index_attester::*;
+pub use get_registered_attesters::*;
+pub use register_attesters::*;
+pub use deregister_attesters::*;
+pub use register_secp256k1_attester::*;
+pub use register_secp256r1_attester::*;
+pub use set_digest_scheme::*;
+pub use queue_operation::*;
+pub use execute_operation::*;
+pub use cancel_operation::*;
+pub use pause::*;
+pub use unpause::*;
+pub use set_guardian::*;
+pub use pause_policy::*;
+pub use unpause_policy::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+
+/// Account validation context for initializing a new registry
+/// 
+/// Registries are namespaced by `registry_id`, so independent trust domains
+/// (e.g. staging, partner-specific and production) can coexist on one
+/// program ID. Only the upgrade authority of this program may initialize a
+/// registry, preventing anyone from front-running a fresh deployment and
+/// claiming a registry PDA.
+#[derive(Accounts)]
+#[instruction(registry_id: u64)]
+pub struct Initialize<'info> {
+    /// The registry account to be created
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PredicateRegistry::INIT_SPACE,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry_id).as_slice()],
+        bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The program data account for the predicate registry program
+    /// 
+    /// CHECK: PDA derived from this program's ID via BPF Loader Upgradeable.
+    /// The upgrade authority is extracted and verified in `verify_upgrade_authority()`.
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+    )]
+    pub program_data: AccountInfo<'info>,
+    
+    /// The authority who will own the registry (must be the program's upgrade authority)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for registering an attester
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct RegisterAttester<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account to be created
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AttesterAccount::INIT_SPACE,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The registry owner or an attester manager
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// The attester's metadata account (required exactly when metadata is given)
+    /// May already exist if metadata outlived a deregistration that did not close it
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AttesterMetadataAccount::INIT_SPACE,
+        seeds = [b"attester_metadata", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump
+    )]
+    pub metadata_account: Option<Account<'info, AttesterMetadataAccount>>,
+    
+    /// The attester index's tail page, to list the attester on (created if the last page is full)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AttesterIndexPage::INIT_SPACE,
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), registry.attester_index_tail_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub index_page: Account<'info, AttesterIndexPage>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for registering an Ethereum-key attester
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(eth_address: [u8; 20])]
+pub struct RegisterSecp256k1Attester<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account to be created, keyed by the padded Ethereum address
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AttesterAccount::INIT_SPACE,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), AttesterAccount::secp256k1_key(&eth_address).as_ref()],
+        bump
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The registry owner or an attester manager
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// The attester index's tail page, to list the attester on (created if the last page is full)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AttesterIndexPage::INIT_SPACE,
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), registry.attester_index_tail_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub index_page: Account<'info, AttesterIndexPage>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for registering a P-256 attester
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(public_key: [u8; 33])]
+pub struct RegisterSecp256r1Attester<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account to be created, keyed by the public key's x-coordinate
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AttesterAccount::INIT_SPACE,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), AttesterAccount::secp256r1_key(&public_key).as_ref()],
+        bump
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The registry owner or an attester manager
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// The attester index's tail page, to list the attester on (created if the last page is full)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AttesterIndexPage::INIT_SPACE,
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), registry.attester_index_tail_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub index_page: Account<'info, AttesterIndexPage>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for deregistering an attester
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct DeregisterAttester<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account to be deregistered and closed by the handler
+    /// Closing the account returns rent to the signer (owner or attester manager)
+    /// Bonded attesters cannot be deregistered, which would strand their bond;
+    /// the owner forces them out with `begin_unbonding` instead
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump,
+        constraint = attester_account.is_listed() @ PredicateRegistryError::AttesterNotRegistered,
+        constraint = attester_account.bond == 0 @ PredicateRegistryError::AttesterBonded
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The registry owner or an attester manager (receives rent refund from closed account)
+    /// Must be mutable to receive lamports from the closed account
+    /// Security: Verified in the handler by `verify_role_or_owner()`
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// The attester's metadata PDA, closed alongside it (required even if it was never created)
+    /// CHECK: PDA checked by the seeds; closed by `close_attester` only if it holds a metadata account
+    #[account(
+        mut,
+        seeds = [b"attester_metadata", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+    
+    /// The identity's scope PDA, closed alongside it (required even if it was never created;
+    /// left open if the key has been rotated away, as the new key shares it)
+    /// CHECK: PDA checked by the seeds; closed by `close_attester` only if it holds a scope account
+    #[account(
+        mut,
+        seeds = [b"attester_scope", registry.namespace_seed(&registry.key()).as_slice(), attester_account.identity.as_ref()],
+        bump
+    )]
+    pub scope_account: UncheckedAccount<'info>,
+    
+    /// The index page listing the attester
+    #[account(
+        mut,
+        constraint = index_page.registry == registry.key() @ PredicateRegistryError::AttesterIndexMismatch
+    )]
+    pub index_page: Account<'info, AttesterIndexPage>,
+    
+    /// The attester index's last page, whose last entry fills the freed slot
+    /// (omit when `index_page` is the last page)
+    #[account(
+        mut,
+        constraint = tail_page.registry == registry.key() @ PredicateRegistryError::AttesterIndexMismatch
+    )]
+    pub tail_page: Option<Account<'info, AttesterIndexPage>>,
+}
+
+/// Account validation context for registering a batch of attesters
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`. The attester PDAs to
+/// create are passed as writable `remaining_accounts`, one per key, in order.
+#[derive(Accounts)]
+pub struct RegisterAttesters<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The registry owner or an attester manager (pays for the attester accounts)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// The attester index's tail page, to list the attesters on (created if the last page is full)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AttesterIndexPage::INIT_SPACE,
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), registry.attester_index_tail_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub index_page: Account<'info, AttesterIndexPage>,
+    
+    /// The page after the tail page, for attesters that do not fit on it
+    /// (required exactly when the batch overflows the tail page)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AttesterIndexPage::INIT_SPACE,
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), (registry.attester_index_tail_page() + 1).to_le_bytes().as_ref()],
+        bump
+    )]
+    pub next_index_page: Option<Account<'info, AttesterIndexPage>>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for deregistering a batch of attesters
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`. The attester,
+/// metadata and scope PDAs to close are passed as writable
+/// `remaining_accounts`, three per key, in order, followed by every attester
+/// index page the removals touch: the pages listing the attesters and the
+/// index's last pages.
+#[derive(Accounts)]
+pub struct DeregisterAttesters<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The registry owner or an attester manager (receives rent from the closed accounts)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+}
+
+/// Account validation context for self-registering an attester with a bond
+/// 
+/// Permissionless while the registry's bond is non-zero; the attester key
+/// signs and pays for everything.
+#[derive(Accounts)]
+pub struct RegisterWithBond<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        constraint = registry.is_self_registration_enabled() @ PredicateRegistryError::SelfRegistrationDisabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account to be created
+    #[account(
+        init,
+        payer = attester,
+        space = 8 + AttesterAccount::INIT_SPACE,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.key().as_ref()],
+        bump
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The bond vault to be created and funded
+    /// May remain, empty, from an earlier registration whose bond was slashed to zero
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = 8 + AttesterBondAccount::INIT_SPACE,
+        seeds = [b"attester_bond", registry.namespace_seed(&registry.key()).as_slice(), attester.key().as_ref()],
+        bump
+    )]
+    pub bond_account: Account<'info, AttesterBondAccount>,
+    
+    /// The scope PDA of the attester's identity (its own key), which must not exist
+    /// CHECK: PDA checked by the seeds; only its emptiness is read
+    #[account(
+        seeds = [b"attester_scope", registry.namespace_seed(&registry.key()).as_slice(), attester.key().as_ref()],
+        bump,
+        constraint = scope_account.data_is_empty() @ PredicateRegistryError::AttesterScopeExists
+    )]
+    pub scope_account: UncheckedAccount<'info>,
+    
+    /// The attester index's tail page, to list the attester on (created if the last page is full)
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = 8 + AttesterIndexPage::INIT_SPACE,
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), registry.attester_index_tail_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub index_page: Account<'info, AttesterIndexPage>,
+    
+    /// The attester registering itself (pays the bond and rent)
+    #[account(mut)]
+    pub attester: Signer<'info>,
+    
+    /// System program for account creation and the bond transfer
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for a bonded attester beginning to exit
+/// 
+/// Callable by the attester itself or, to force it out, by the registry
+/// owner; see `verify_attester_or_owner`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct BeginUnbonding<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account to deregister (suspended attesters may also exit)
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump,
+        constraint = attester_account.is_listed() @ PredicateRegistryError::AttesterNotRegistered,
+        constraint = attester_account.next_key.is_none() @ PredicateRegistryError::AttesterAlreadyRotated
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The identity's bond vault
+    #[account(
+        mut,
+        seeds = [b"attester_bond", registry.namespace_seed(&registry.key()).as_slice(), attester_account.identity.as_ref()],
+        bump
+    )]
+    pub bond_account: Account<'info, AttesterBondAccount>,
+    
+    /// The attester's metadata PDA, closed as the attester exits (required even if it was never created)
+    /// CHECK: PDA checked by the seeds; closed by `close_side_accounts` only if it holds a metadata account
+    #[account(
+        mut,
+        seeds = [b"attester_metadata", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+    
+    /// The identity's scope PDA, closed as the attester exits (required even if it was never created)
+    /// CHECK: PDA checked by the seeds; closed by `close_side_accounts` only if it holds a scope account
+    #[account(
+        mut,
+        seeds = [b"attester_scope", registry.namespace_seed(&registry.key()).as_slice(), attester_account.identity.as_ref()],
+        bump
+    )]
+    pub scope_account: UncheckedAccount<'info>,
+    
+    /// The index page listing the attester
+    #[account(
+        mut,
+        constraint = index_page.registry == registry.key() @ PredicateRegistryError::AttesterIndexMismatch
+    )]
+    pub index_page: Account<'info, AttesterIndexPage>,
+    
+    /// The attester index's last page, whose last entry fills the freed slot
+    /// (omit when `index_page` is the last page)
+    #[account(
+        mut,
+        constraint = tail_page.registry == registry.key() @ PredicateRegistryError::AttesterIndexMismatch
+    )]
+    pub tail_page: Option<Account<'info, AttesterIndexPage>>,
+    
+    /// The bonded attester, or the registry owner (receives rent from the closed metadata and scope accounts)
+    /// Security: Verified in the handler by `verify_attester_or_owner()`
+    #[account(mut)]
+    pub caller: Signer<'info>,
+}
+
+/// Account validation context for withdrawing an exited attester's bond
+/// 
+/// Callable by the attester itself or by the registry owner; see
+/// `verify_attester_or_owner`. Either way the bond goes to
+/// `AttesterAccount::bond_recipient`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct WithdrawBond<'info> {
+    /// The registry account (for event emission)
+    #[account(
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The exited attester's account, closed to free the key for re-registration
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump,
+        constraint = attester_account.next_key.is_none() @ PredicateRegistryError::AttesterAlreadyRotated
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The identity's bond vault, closed to return the bond and rent
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [b"attester_bond", registry.namespace_seed(&registry.key()).as_slice(), attester_account.identity.as_ref()],
+        bump
+    )]
+    pub bond_account: Account<'info, AttesterBondAccount>,
+    
+    /// Receives the bond and rent: the attester, or the registry authority for non-Solana keys
+    /// CHECK: Bound to `AttesterAccount::bond_recipient` by the constraint
+    #[account(
+        mut,
+        constraint = recipient.key() == attester_account.bond_recipient(&registry.authority) @ PredicateRegistryError::BondRecipientMismatch
+    )]
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// The exited attester, or the registry owner
+    /// Security: Verified in the handler by `verify_attester_or_owner()`
+    pub caller: Signer<'info>,
+}
+
+/// Account validation context for posting collateral for an attester
+/// 
+/// Callable by the attester itself or by the registry owner; see
+/// `verify_attester_or_owner`. The depositor pays the collateral and, on
+/// first deposit, the vault's rent.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct PostCollateral<'info> {
+    /// The registry account (for event emission)
+    #[account(
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account whose tracked bond grows
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump,
+        constraint = attester_account.is_registered @ PredicateRegistryError::AttesterNotRegistered,
+        constraint = attester_account.next_key.is_none() @ PredicateRegistryError::AttesterAlreadyRotated
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The identity's bond vault (created on first deposit)
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = 8 + AttesterBondAccount::INIT_SPACE,
+        seeds = [b"attester_bond", registry.namespace_seed(&registry.key()).as_slice(), attester_account.identity.as_ref()],
+        bump
+    )]
+    pub bond_account: Account<'info, AttesterBondAccount>,
+    
+    /// The attester, or the registry owner
+    /// Security: Verified in the handler by `verify_attester_or_owner()`
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    
+    /// System program for account creation and the transfer
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for slashing an attester's bond
+/// 
+/// Only available in single-authority mode.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey, uuid: [u8; 16])]
+pub struct SlashAttester<'info> {
+    /// The registry account
+    #[account(
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The slashed attester's account (registered or unbonding, not rotated out)
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump,
+        constraint = attester_account.next_key.is_none() @ PredicateRegistryError::AttesterAlreadyRotated
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The identity's bond vault
+    #[account(
+        mut,
+        seeds = [b"attester_bond", registry.namespace_seed(&registry.key()).as_slice(), attester_account.identity.as_ref()],
+        bump
+    )]
+    pub bond_account: Account<'info, AttesterBondAccount>,
+    
+    /// Evidence: the used UUID account of an attestation the attester signed or co-signed
+    #[account(
+        seeds = [b"used_uuid", registry.namespace_seed(&registry.key()).as_slice(), uuid.as_ref()],
+        bump,
+        constraint = used_uuid_account.is_signed_by(&attester_account) @ PredicateRegistryError::SlashEvidenceMismatch
+    )]
+    pub used_uuid_account: Account<'info, UsedUuidAccount>,
+    
+    /// Receives the slashed lamports (pass the incinerator to burn them)
+    /// CHECK: Any writable account may receive lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// The registry authority
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for rotating an attester to a new key
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey, new_key: Pubkey)]
+pub struct RotateAttesterKey<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account of the key being replaced
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The attester account to create for the replacement key
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AttesterAccount::INIT_SPACE,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), new_key.as_ref()],
+        bump
+    )]
+    pub new_attester_account: Account<'info, AttesterAccount>,
+    
+    /// The registry owner or an attester manager
+    /// Security: Verified in the handler by `verify_role_or_owner()`
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// The attester index's tail page, to list the new key on (created if the last page is full)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AttesterIndexPage::INIT_SPACE,
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), registry.attester_index_tail_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub index_page: Account<'info, AttesterIndexPage>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for setting an attester's validity window
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct SetAttesterValidity<'info> {
+    /// The registry account
+    #[account(
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account to update
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump,
+        constraint = attester_account.is_registered @ PredicateRegistryError::AttesterNotRegistered
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The registry owner or an attester manager
+    /// Security: Verified in the handler by `verify_role_or_owner()`
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+}
+
+/// Account validation context for suspending an attester
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct SuspendAttester<'info> {
+    /// The registry account
+    #[account(
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account to suspend
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The registry owner or an attester manager
+    /// Security: Verified in the handler by `verify_role_or_owner()`
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+}
+
+/// Account validation context for reinstating a suspended attester
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct ReinstateAttester<'info> {
+    /// The registry account
+    #[account(
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account to reinstate
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The registry owner or an attester manager
+    /// Security: Verified in the handler by `verify_role_or_owner()`
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+}
+
+/// Account validation context for revoking an attester's attestations after a cutoff
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct RevokeAttesterAfter<'info> {
+    /// The registry account
+    #[account(
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account to revoke (whether registered, suspended or exiting)
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The registry owner or an attester manager
+    /// Security: Verified in the handler by `verify_role_or_owner()`
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+}
+
+/// Account validation context for setting an attester's scope
+/// 
+/// Callable by the owner (single-authority mode) or by any holder of the
+/// `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct SetAttesterScope<'info> {
+    /// The registry account
+    #[account(
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account whose scope flag is updated
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump,
+        constraint = attester_account.is_registered @ PredicateRegistryError::AttesterNotRegistered
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The scope account of the attester's identity (created on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AttesterScopeAccount::INIT_SPACE,
+        seeds = [b"attester_scope", registry.namespace_seed(&registry.key()).as_slice(), attester_account.identity.as_ref()],
+        bump
+    )]
+    pub scope_account: Account<'info, AttesterScopeAccount>,
+    
+    /// The registry owner or an attester manager (pays rent on first use)
+    /// Security: Verified in the handler by `verify_role_or_owner()`
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for updating an attester's metadata
+/// 
+/// Callable by the attester itself, by the owner (single-authority mode) or
+/// by any holder of the `AttesterManager` role; see `verify_role_or_owner`.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct UpdateAttesterMetadata<'info> {
+    /// The registry account
+    #[account(
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester account being described
+    #[account(
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump,
+        constraint = attester_account.is_registered @ PredicateRegistryError::AttesterNotRegistered
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The attester's metadata account (created on first update)
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + AttesterMetadataAccount::INIT_SPACE,
+        seeds = [b"attester_metadata", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump
+    )]
+    pub metadata_account: Account<'info, AttesterMetadataAccount>,
+    
+    /// The attester, owner or attester manager (pays rent on first update)
+    /// Security: Verified in the handler
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// The signer's `AttesterManager` role account (omit when acting as attester or owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::AttesterManager.seed().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for setting a policy ID
+/// 
+/// Policies are owned by PROGRAMS, not users. This context:
+/// - Creates a policy PDA derived from the client program's address
+/// - Verifies the signer is the program's upgrade authority
+/// - Stores both the program address and the authority
+#[derive(Accounts)]
+pub struct SetPolicyId<'info> {
+    /// The registry account (for event emission and stats tracking)
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The policy account to be created (derived from client program, not user)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PolicyAccount::INIT_SPACE,
+        seeds = [b"policy", registry.namespace_seed(&registry.key()).as_slice(), client_program.key().as_ref()],
+        bump
+    )]
+    pub policy_account: Account<'info, PolicyAccount>,
+    
+    /// The client program that this policy applies to
+    /// 
+    /// CHECK: This must be a deployed BPF Upgradeable program. Security is enforced by:
+    /// 1. `program_data` PDA is derived from this address (see seeds below)
+    /// 2. `verify_upgrade_authority()` validates the signer is this program's upgrade authority
+    /// 3. `policy_account` PDA is also derived from this address, ensuring consistency
+    pub client_program: AccountInfo<'info>,
+    
+    /// The program data account for the client program
+    /// 
+    /// CHECK: PDA derived from `client_program` via BPF Loader Upgradeable.
+    /// The upgrade authority is extracted and verified in `verify_upgrade_authority()`.
+    #[account(
+        seeds = [client_program.key().as_ref()],
+        bump,
+        seeds::program = anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+    )]
+    pub program_data: AccountInfo<'info>,
+    
+    /// The upgrade authority of the client program
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for updating a policy ID
+/// 
+/// Updates an existing policy for a PROGRAM. Only the program's upgrade
+/// authority can call this instruction.
+#[derive(Accounts)]
+pub struct UpdatePolicyId<'info> {
+    /// The registry account (for event emission)
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The policy account to be updated (derived from client program)
+    #[account(
+        mut,
+        seeds = [b"policy", registry.namespace_seed(&registry.key()).as_slice(), client_program.key().as_ref()],
+        bump,
+        constraint = policy_account.client_program == client_program.key() @ PredicateRegistryError::InvalidClientProgram
+    )]
+    pub policy_account: Account<'info, PolicyAccount>,
+    
+    /// The client program (for PDA derivation)
+    /// 
+    /// CHECK: This must be a deployed BPF Upgradeable program. Security is enforced by:
+    /// 1. `policy_account` constraint ensures this matches the stored `client_program`
+    /// 2. `program_data` PDA is derived from this address (see seeds below)
+    /// 3. `verify_upgrade_authority()` validates the signer is this program's upgrade authority
+    pub client_program: AccountInfo<'info>,
+    
+    /// The program data account for the client program
+    /// 
+    /// CHECK: PDA derived from `client_program` via BPF Loader Upgradeable.
+    /// The upgrade authority is extracted and verified in `verify_upgrade_authority()`.
+    #[account(
+        seeds = [client_program.key().as_ref()],
+        bump,
+        seeds::program = anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+    )]
+    pub program_data: AccountInfo<'info>,
+    
+    /// The upgrade authority of the client program
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for validating an attestation
+/// 
+/// The policy is derived from the target program being called, not from the
+/// transaction signer. This ensures policies are tied to programs.
+#[derive(Accounts)]
+#[instruction(
+    target: Pubkey,
+    msg_value: u64,
+    encoded_sig_and_args: Vec<u8>,
+    attestation: Attestation
+)]
+pub struct ValidateAttestation<'info> {    
+    /// The registry account (validation is rejected while paused)
+    #[account(
+        mut,
+        constraint = !registry.paused @ PredicateRegistryError::RegistryPaused,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    /// The attester account that made the attestation
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attestation.attester.as_ref()],
+        bump,
+        constraint = !attester_account.is_suspended @ PredicateRegistryError::AttesterSuspended,
+        constraint = attester_account.is_registered @ PredicateRegistryError::AttesterNotRegisteredForValidation
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The scope account of the attester's identity (required when the attester is scoped)
+    #[account(
+        seeds = [b"attester_scope", registry.namespace_seed(&registry.key()).as_slice(), attester_account.identity.as_ref()],
+        bump
+    )]
+    pub attester_scope_account: Option<Account<'info, AttesterScopeAccount>>,
+    
+    /// The policy account for the TARGET PROGRAM (not the user)
+    /// This is the key change: policy is tied to the program being called
+    #[account(
+        seeds = [b"policy", registry.namespace_seed(&registry.key()).as_slice(), target.as_ref()],
+        bump,
+        constraint = policy_account.client_program == target @ PredicateRegistryError::InvalidClientProgram,
+        constraint = !policy_account.paused @ PredicateRegistryError::PolicyPaused
+    )]
+    pub policy_account: Account<'info, PolicyAccount>,
+    
+    /// The used UUID account (replay protection)
+    /// Must be created for first use, will fail if already exists
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + UsedUuidAccount::INIT_SPACE,
+        seeds = [b"used_uuid", registry.namespace_seed(&registry.key()).as_slice(), attestation.uuid.as_ref()],
+        bump
+    )]
+    pub used_uuid_account: Account<'info, UsedUuidAccount>,
+    
+    /// The user calling the program (validated against program's policy)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+    
+    /// Instructions sysvar for signature verification
+    /// CHECK: This is the instructions sysvar account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+/// Account validation context for validating a threshold attestation
+/// 
+/// Like `ValidateAttestation`, but for a statement signed by several
+/// attesters. Their attester accounts (each followed by its scope account
+/// when scoped) are passed as remaining accounts and checked in the handler.
+#[derive(Accounts)]
+#[instruction(
+    target: Pubkey,
+    msg_value: u64,
+    encoded_sig_and_args: Vec<u8>,
+    attestation: ThresholdAttestation
+)]
+pub struct ValidateThresholdAttestation<'info> {
+    /// The registry account (validation is rejected while paused)
+    #[account(
+        constraint = !registry.paused @ PredicateRegistryError::RegistryPaused,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The policy account for the TARGET PROGRAM (not the user)
+    #[account(
+        seeds = [b"policy", registry.namespace_seed(&registry.key()).as_slice(), target.as_ref()],
+        bump,
+        constraint = policy_account.client_program == target @ PredicateRegistryError::InvalidClientProgram,
+        constraint = !policy_account.paused @ PredicateRegistryError::PolicyPaused
+    )]
+    pub policy_account: Account<'info, PolicyAccount>,
+    
+    /// The used UUID account (replay protection)
+    /// Must be created for first use, will fail if already exists
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + UsedUuidAccount::INIT_SPACE,
+        seeds = [b"used_uuid", registry.namespace_seed(&registry.key()).as_slice(), attestation.uuid.as_ref()],
+        bump
+    )]
+    pub used_uuid_account: Account<'info, UsedUuidAccount>,
+    
+    /// The user calling the program (validated against program's policy)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+    
+    /// Instructions sysvar for signature verification
+    /// CHECK: This is the instructions sysvar account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+/// Account validation context for proposing a new authority
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    /// The registry account whose authority will be transferred
+    #[account(
+        mut,
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The current authority
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for renouncing the registry authority
+/// 
+/// Only available in single-authority mode, so renouncing also leaves the
+/// registry without multisig signers or a timelock that could act for it.
+#[derive(Accounts)]
+pub struct RenounceAuthority<'info> {
+    /// The registry account whose authority is being renounced
+    #[account(
+        mut,
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The current authority
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for accepting a pending authority transfer
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// The registry account whose authority is being transferred
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump,
+        constraint = registry.pending_authority.is_some() @ PredicateRegistryError::NoPendingAuthorityTransfer,
+        constraint = registry.pending_authority == Some(new_authority.key()) @ PredicateRegistryError::NotPendingAuthority
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The proposed authority (must sign to prove key ownership)
+    pub new_authority: Signer<'info>,
+}
+
+/// Account validation context for cancelling a pending authority transfer
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    /// The registry account with the pending transfer
+    #[account(
+        mut,
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The current authority
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for configuring the multisig signer set
+/// 
+/// Enables multisig mode from single-authority mode. Once enabled, the signer
+/// set can only be changed through a `ConfigureMultisig` proposal.
+#[derive(Accounts)]
+pub struct ConfigureMultisig<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The registry authority
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for creating a multisig admin proposal
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    /// The registry account (allocates the proposal ID)
+    #[account(
+        mut,
+        constraint = registry.is_multisig_enabled() @ PredicateRegistryError::MultisigDisabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The proposal account to be created
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + AdminProposal::INIT_SPACE,
+        seeds = [b"admin_proposal", registry.namespace_seed(&registry.key()).as_slice(), registry.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+    
+    /// The multisig signer creating the proposal (pays for the proposal account)
+    #[account(
+        mut,
+        constraint = registry.is_multisig_signer(&proposer.key()) @ PredicateRegistryError::NotMultisigSigner
+    )]
+    pub proposer: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for approving a multisig admin proposal
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    /// The registry account
+    #[account(
+        constraint = registry.is_multisig_enabled() @ PredicateRegistryError::MultisigDisabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The proposal being approved
+    #[account(
+        mut,
+        has_one = registry @ PredicateRegistryError::InvalidAccountData,
+        seeds = [b"admin_proposal", registry.namespace_seed(&registry.key()).as_slice(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+    
+    /// The multisig signer approving the proposal
+    #[account(
+        constraint = registry.is_multisig_signer(&approver.key()) @ PredicateRegistryError::NotMultisigSigner
+    )]
+    pub approver: Signer<'info>,
+}
+
+/// Account validation context for executing a multisig admin proposal
+/// 
+/// Permissionless once the threshold is met (and, when the timelock is
+/// enabled, once the proposal's eta has passed). The proposal is closed and
+/// its rent returned to the proposer. Attester accounts are only required for
+/// attester actions and must be the PDA of the attester named in the action.
+/// Nothing can be executed once the authority has been renounced.
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        constraint = !registry.is_renounced() @ PredicateRegistryError::AuthorityRenounced,
+        constraint = registry.is_multisig_enabled() @ PredicateRegistryError::MultisigDisabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The proposal being executed (closed by the handler once applied)
+    #[account(
+        mut,
+        has_one = registry @ PredicateRegistryError::InvalidAccountData,
+        has_one = proposer @ PredicateRegistryError::InvalidAccountData,
+        seeds = [b"admin_proposal", registry.namespace_seed(&registry.key()).as_slice(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+    
+    /// The proposal creator (receives rent from the closed proposal and attester accounts)
+    /// CHECK: Verified by the `has_one = proposer` constraint on `proposal`
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+    
+    /// Whoever executes the proposal (pays for any account creation)
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    /// The attester account to create, for `RegisterAttester`, `RegisterSecp256k1Attester`, `RegisterSecp256r1Attester` and `RotateAttesterKey` proposals
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + AttesterAccount::INIT_SPACE,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), proposal.action.new_attester().unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub new_attester_account: Option<Account<'info, AttesterAccount>>,
+    
+    /// The attester account to close or update, for `DeregisterAttester`, `SetAttesterValidity`, `SuspendAttester`, `ReinstateAttester`, `RevokeAttesterAfter` and `RotateAttesterKey` proposals
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), proposal.action.attester().unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub attester_account: Option<Account<'info, AttesterAccount>>,
+    
+    /// The role account to create, for `GrantRole` proposals
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + RoleAccount::INIT_SPACE,
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), proposal.action.role_seed().as_ref(), proposal.action.role_holder().as_ref()],
+        bump
+    )]
+    pub new_role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// The role account to close, for `RevokeRole` proposals
+    #[account(
+        mut,
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), proposal.action.role_seed().as_ref(), proposal.action.role_holder().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// The attester index's tail page, to list the new key on, for `RegisterAttester`, `RegisterSecp256k1Attester`, `RegisterSecp256r1Attester` and `RotateAttesterKey` proposals
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + AttesterIndexPage::INIT_SPACE,
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), registry.attester_index_tail_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub index_tail_page: Option<Account<'info, AttesterIndexPage>>,
+    
+    /// The index page listing the attester, for `DeregisterAttester` proposals
+    #[account(
+        mut,
+        constraint = index_page.registry == registry.key() @ PredicateRegistryError::AttesterIndexMismatch
+    )]
+    pub index_page: Option<Account<'info, AttesterIndexPage>>,
+    
+    /// The attester index's last page, for `DeregisterAttester` proposals (omit when `index_page` is the last page)
+    #[account(
+        mut,
+        constraint = index_last_page.registry == registry.key() @ PredicateRegistryError::AttesterIndexMismatch
+    )]
+    pub index_last_page: Option<Account<'info, AttesterIndexPage>>,
+    
+    /// The attester's metadata PDA, for `DeregisterAttester` proposals (required even if it was never created)
+    /// CHECK: PDA checked by the seeds; closed by `close_attester` only if it holds a metadata account
+    #[account(
+        mut,
+        seeds = [b"attester_metadata", registry.namespace_seed(&registry.key()).as_slice(), proposal.action.attester().unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    
+    /// The identity's scope PDA, for `DeregisterAttester` proposals (required even if it was never created)
+    /// CHECK: PDA checked by the seeds; closed by `close_attester` only if it holds a scope account
+    #[account(
+        mut,
+        seeds = [b"attester_scope", registry.namespace_seed(&registry.key()).as_slice(), attester_account.as_ref().map(|account| account.identity).unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub scope_account: Option<UncheckedAccount<'info>>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for setting the timelock delay directly
+/// 
+/// Only available while the timelock is disabled; once enabled, the delay is
+/// changed through a queued `SetTimelockDelay` operation.
+#[derive(Accounts)]
+pub struct SetTimelockDelay<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The registry authority
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for setting the attestation config directly
+/// 
+/// Only available in single-authority mode; otherwise the config is changed
+/// through a `SetAttestationConfig` proposal or queued operation.
+#[derive(Accounts)]
+pub struct SetAttestationConfig<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The registry authority
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for setting the bond config directly
+/// 
+/// Callable by the owner or by any holder of the `FeeManager` role, in
+/// single-authority mode only; see `verify_role_or_owner`. Otherwise the
+/// config is changed through a `SetBondConfig` proposal or queued operation.
+#[derive(Accounts)]
+pub struct SetBondConfig<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The registry owner or a fee manager
+    pub authority: Signer<'info>,
+    
+    /// The signer's `FeeManager` role account (omit when acting as owner)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::FeeManager.seed().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+}
+
+/// Account validation context for queueing a timelocked admin operation
+#[derive(Accounts)]
+pub struct QueueOperation<'info> {
+    /// The registry account (allocates the operation ID)
+    #[account(
+        mut,
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = registry.is_timelock_enabled() @ PredicateRegistryError::TimelockDisabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The queued operation account to be created
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + QueuedOperation::INIT_SPACE,
+        seeds = [b"queued_operation", registry.namespace_seed(&registry.key()).as_slice(), registry.operation_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub operation: Account<'info, QueuedOperation>,
+    
+    /// The registry authority (pays for the operation account)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for executing a timelocked admin operation
+/// 
+/// Permissionless once the operation's eta has passed. Operations cannot be
+/// executed while multisig mode is enabled, so operations queued before the
+/// switch cannot bypass the multisig, nor once the authority has been
+/// renounced, so operations queued before renouncing cannot change the
+/// frozen registry.
+#[derive(Accounts)]
+pub struct ExecuteOperation<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        constraint = !registry.is_renounced() @ PredicateRegistryError::AuthorityRenounced,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The operation being executed (closed after execution)
+    #[account(
+        mut,
+        close = queued_by,
+        has_one = registry @ PredicateRegistryError::InvalidAccountData,
+        has_one = queued_by @ PredicateRegistryError::InvalidAccountData,
+        seeds = [b"queued_operation", registry.namespace_seed(&registry.key()).as_slice(), operation.operation_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub operation: Account<'info, QueuedOperation>,
+    
+    /// The authority that queued the operation (receives rent from closed accounts)
+    /// CHECK: Verified by the `has_one = queued_by` constraint on `operation`
+    #[account(mut)]
+    pub queued_by: AccountInfo<'info>,
+    
+    /// Whoever executes the operation (pays for any account creation)
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    /// The attester account to create, for `RegisterAttester`, `RegisterSecp256k1Attester`, `RegisterSecp256r1Attester` and `RotateAttesterKey` operations
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + AttesterAccount::INIT_SPACE,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), operation.action.new_attester().unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub new_attester_account: Option<Account<'info, AttesterAccount>>,
+    
+    /// The attester account to close or update, for `DeregisterAttester`, `SetAttesterValidity`, `SuspendAttester`, `ReinstateAttester`, `RevokeAttesterAfter` and `RotateAttesterKey` operations
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), operation.action.attester().unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub attester_account: Option<Account<'info, AttesterAccount>>,
+    
+    /// The role account to create, for `GrantRole` operations
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + RoleAccount::INIT_SPACE,
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), operation.action.role_seed().as_ref(), operation.action.role_holder().as_ref()],
+        bump
+    )]
+    pub new_role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// The role account to close, for `RevokeRole` operations
+    #[account(
+        mut,
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), operation.action.role_seed().as_ref(), operation.action.role_holder().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+    
+    /// The attester index's tail page, to list the new key on, for `RegisterAttester`, `RegisterSecp256k1Attester`, `RegisterSecp256r1Attester` and `RotateAttesterKey` operations
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + AttesterIndexPage::INIT_SPACE,
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), registry.attester_index_tail_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub index_tail_page: Option<Account<'info, AttesterIndexPage>>,
     
-    // Verify the signer matches the upgrade authority
-    require!(
-        upgrade_authority == *expected_authority,
-        PredicateRegistryError::Unauthorized
-    );
+    /// The index page listing the attester, for `DeregisterAttester` operations
+    #[account(
+        mut,
+        constraint = index_page.registry == registry.key() @ PredicateRegistryError::AttesterIndexMismatch
+    )]
+    pub index_page: Option<Account<'info, AttesterIndexPage>>,
     
-    Ok(())
-}
-
-// Re-export instruction functions
-pub use initialize::*;
-pub use register_attester::*;
-pub use deregister_attester::*;
-pub use set_policy_id::*;
-pub use update_policy_id::*;
-pub use validate_attestation::*;
-pub use cleanup_expired_uuid::*;
-pub use transfer_authority::*;
-
-/// Account validation context for initializing a new registry
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    /// The registry account to be created
+    /// The attester index's last page, for `DeregisterAttester` operations (omit when `index_page` is the last page)
     #[account(
-        init,
-        payer = authority,
-        space = 8 + PredicateRegistry::INIT_SPACE,
-        seeds = [b"predicate_registry"],
+        mut,
+        constraint = index_last_page.registry == registry.key() @ PredicateRegistryError::AttesterIndexMismatch
+    )]
+    pub index_last_page: Option<Account<'info, AttesterIndexPage>>,
+    
+    /// The attester's metadata PDA, for `DeregisterAttester` operations (required even if it was never created)
+    /// CHECK: PDA checked by the seeds; closed by `close_attester` only if it holds a metadata account
+    #[account(
+        mut,
+        seeds = [b"attester_metadata", registry.namespace_seed(&registry.key()).as_slice(), operation.action.attester().unwrap_or_default().as_ref()],
         bump
     )]
-    pub registry: Account<'info, PredicateRegistry>,
+    pub metadata_account: Option<UncheckedAccount<'info>>,
     
-    /// The authority who will own the registry
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    /// The identity's scope PDA, for `DeregisterAttester` operations (required even if it was never created)
+    /// CHECK: PDA checked by the seeds; closed by `close_attester` only if it holds a scope account
+    #[account(
+        mut,
+        seeds = [b"attester_scope", registry.namespace_seed(&registry.key()).as_slice(), attester_account.as_ref().map(|account| account.identity).unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub scope_account: Option<UncheckedAccount<'info>>,
     
     /// System program for account creation
     pub system_program: Program<'info, System>,
 }
 
-/// Account validation context for registering an attester
+/// Account validation context for cancelling a timelocked admin operation
 #[derive(Accounts)]
-#[instruction(attester: Pubkey)]
-pub struct RegisterAttester<'info> {
+pub struct CancelOperation<'info> {
     /// The registry account
     #[account(
-        mut,
         has_one = authority @ PredicateRegistryError::Unauthorized,
-        seeds = [b"predicate_registry"],
-        bump
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
     )]
     pub registry: Account<'info, PredicateRegistry>,
     
-    /// The attester account to be created
+    /// The operation being cancelled (closed, rent returned to `queued_by`)
     #[account(
-        init,
-        payer = authority,
-        space = 8 + AttesterAccount::INIT_SPACE,
-        seeds = [b"attester", attester.as_ref()],
+        mut,
+        close = queued_by,
+        has_one = registry @ PredicateRegistryError::InvalidAccountData,
+        has_one = queued_by @ PredicateRegistryError::InvalidAccountData,
+        seeds = [b"queued_operation", registry.namespace_seed(&registry.key()).as_slice(), operation.operation_id.to_le_bytes().as_ref()],
         bump
     )]
-    pub attester_account: Account<'info, AttesterAccount>,
+    pub operation: Account<'info, QueuedOperation>,
     
-    /// The registry authority
+    /// The authority that queued the operation
+    /// CHECK: Verified by the `has_one = queued_by` constraint on `operation`
     #[account(mut)]
+    pub queued_by: AccountInfo<'info>,
+    
+    /// The registry authority
     pub authority: Signer<'info>,
+}
+
+/// Account validation context for pausing the registry
+/// 
+/// Callable by the guardian, by a holder of the `Pauser` role, by any
+/// multisig signer in multisig mode, or by the authority otherwise. Never
+/// timelocked, so a compromised attester backend can be contained immediately.
+/// Like the guardian, pausers keep this power in multisig and timelock mode,
+/// which is safe because only the owner can unpause.
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        constraint = !registry.is_renounced() @ PredicateRegistryError::AuthorityRenounced,
+        constraint = registry.can_pause(&signer.key()) || role_account.is_some() @ PredicateRegistryError::Unauthorized,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
     
-    /// System program for account creation
-    pub system_program: Program<'info, System>,
+    /// The authority, multisig signer, guardian or pauser pausing the registry
+    pub signer: Signer<'info>,
+    
+    /// The signer's `Pauser` role account (omit when pausing as authority, multisig signer or guardian)
+    #[account(
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), Role::Pauser.seed().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
 }
 
-/// Account validation context for deregistering an attester
+/// Account validation context for unpausing the registry directly
+/// 
+/// Callable by the owner in single-authority mode only. Neither the guardian
+/// nor holders of the `Pauser` role can unpause. In multisig or timelock
+/// mode, the owner unpauses through an `Unpause` proposal or queued operation.
 #[derive(Accounts)]
-#[instruction(attester: Pubkey)]
-pub struct DeregisterAttester<'info> {
+pub struct Unpause<'info> {
     /// The registry account
     #[account(
         mut,
         has_one = authority @ PredicateRegistryError::Unauthorized,
-        seeds = [b"predicate_registry"],
-        bump
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
     )]
     pub registry: Account<'info, PredicateRegistry>,
     
-    /// The attester account to be deregistered and closed
-    /// Closing the account returns rent to the current registry authority
-    /// Note: If authority was transferred, the current authority receives the rent
+    /// The registry owner
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for setting the pause guardian directly
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    /// The registry account
     #[account(
         mut,
-        close = authority,
-        seeds = [b"attester", attester.as_ref()],
-        bump,
-        constraint = attester_account.is_registered @ PredicateRegistryError::AttesterNotRegistered
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
     )]
-    pub attester_account: Account<'info, AttesterAccount>,
+    pub registry: Account<'info, PredicateRegistry>,
     
-    /// The registry authority (receives rent refund from closed account)
-    /// Must be mutable to receive lamports from the closed account
-    /// Security: Protected by `has_one` constraint ensuring only current authority can call this
-    #[account(mut)]
+    /// The registry authority
     pub authority: Signer<'info>,
 }
 
-/// Account validation context for setting a policy ID
+/// Account validation context for pausing or unpausing a policy
 /// 
-/// Policies are owned by PROGRAMS, not users. This context:
-/// - Creates a policy PDA derived from the client program's address
-/// - Verifies the signer is the program's upgrade authority
-/// - Stores both the program address and the authority
+/// Used by both `pause_policy` and `unpause_policy`. Only the client
+/// program's upgrade authority can toggle its policy.
 #[derive(Accounts)]
-pub struct SetPolicyId<'info> {
-    /// The registry account (for event emission and stats tracking)
+pub struct SetPolicyPaused<'info> {
+    /// The registry account (for event emission)
     #[account(
-        mut,
-        seeds = [b"predicate_registry"],
-        bump
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
     )]
     pub registry: Account<'info, PredicateRegistry>,
     
-    /// The policy account to be created (derived from client program, not user)
+    /// The policy account to be paused or unpaused (derived from client program)
     #[account(
-        init,
-        payer = authority,
-        space = 8 + PolicyAccount::INIT_SPACE,
-        seeds = [b"policy", client_program.key().as_ref()],
-        bump
+        mut,
+        seeds = [b"policy", registry.namespace_seed(&registry.key()).as_slice(), client_program.key().as_ref()],
+        bump,
+        constraint = policy_account.client_program == client_program.key() @ PredicateRegistryError::InvalidClientProgram
     )]
     pub policy_account: Account<'info, PolicyAccount>,
     
-    /// The client program that this policy applies to
+    /// The client program (for PDA derivation)
     /// 
     /// CHECK: This must be a deployed BPF Upgradeable program. Security is enforced by:
-    /// 1. `program_data` PDA is derived from this address (see seeds below)
-    /// 2. `verify_upgrade_authority()` validates the signer is this program's upgrade authority
-    /// 3. `policy_account` PDA is also derived from this address, ensuring consistency
+    /// 1. `policy_account` constraint ensures this matches the stored `client_program`
+    /// 2. `program_data` PDA is derived from this address (see seeds below)
+    /// 3. `verify_upgrade_authority()` validates the signer is this program's upgrade authority
     pub client_program: AccountInfo<'info>,
     
     /// The program data account for the client program
@@ -227,31 +2553,25 @@ pub struct SetPolicyId<'info> {
     pub program_data: AccountInfo<'info>,
     
     /// The upgrade authority of the client program
-    #[account(mut)]
     pub authority: Signer<'info>,
-    
-    /// System program for account creation
-    pub system_program: Program<'info, System>,
 }
 
-/// Account validation context for updating a policy ID
+/// Account validation context for setting a policy's attester quorum
 /// 
-/// Updates an existing policy for a PROGRAM. Only the program's upgrade
-/// authority can call this instruction.
+/// Only the client program's upgrade authority can set its quorum.
 #[derive(Accounts)]
-pub struct UpdatePolicyId<'info> {
+pub struct SetAttesterQuorum<'info> {
     /// The registry account (for event emission)
     #[account(
-        mut,
-        seeds = [b"predicate_registry"],
-        bump
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
     )]
     pub registry: Account<'info, PredicateRegistry>,
     
-    /// The policy account to be updated (derived from client program)
+    /// The policy account to update (derived from client program)
     #[account(
         mut,
-        seeds = [b"policy", client_program.key().as_ref()],
+        seeds = [b"policy", registry.namespace_seed(&registry.key()).as_slice(), client_program.key().as_ref()],
         bump,
         constraint = policy_account.client_program == client_program.key() @ PredicateRegistryError::InvalidClientProgram
     )]
@@ -277,95 +2597,128 @@ pub struct UpdatePolicyId<'info> {
     pub program_data: AccountInfo<'info>,
     
     /// The upgrade authority of the client program
-    #[account(mut)]
     pub authority: Signer<'info>,
 }
 
-/// Account validation context for validating an attestation
+/// Account validation context for setting a policy's digest scheme
 /// 
-/// The policy is derived from the target program being called, not from the
-/// transaction signer. This ensures policies are tied to programs.
+/// Only the client program's upgrade authority can set its digest scheme.
 #[derive(Accounts)]
-#[instruction(
-    target: Pubkey,
-    msg_value: u64,
-    encoded_sig_and_args: Vec<u8>,
-    attestation: Attestation
-)]
-pub struct ValidateAttestation<'info> {    
-    /// The registry account
+pub struct SetDigestScheme<'info> {
+    /// The registry account (for event emission)
     #[account(
-        mut,
-        seeds = [b"predicate_registry"],
-        bump
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
     )]
     pub registry: Account<'info, PredicateRegistry>,
-    /// The attester account that made the attestation
+    
+    /// The policy account to update (derived from client program)
     #[account(
         mut,
-        seeds = [b"attester", attestation.attester.as_ref()],
+        seeds = [b"policy", registry.namespace_seed(&registry.key()).as_slice(), client_program.key().as_ref()],
         bump,
-        constraint = attester_account.is_registered @ PredicateRegistryError::AttesterNotRegisteredForValidation
+        constraint = policy_account.client_program == client_program.key() @ PredicateRegistryError::InvalidClientProgram
     )]
-    pub attester_account: Account<'info, AttesterAccount>,
+    pub policy_account: Account<'info, PolicyAccount>,
     
-    /// The policy account for the TARGET PROGRAM (not the user)
-    /// This is the key change: policy is tied to the program being called
+    /// The client program (for PDA derivation)
+    /// 
+    /// CHECK: This must be a deployed BPF Upgradeable program. Security is enforced by:
+    /// 1. `policy_account` constraint ensures this matches the stored `client_program`
+    /// 2. `program_data` PDA is derived from this address (see seeds below)
+    /// 3. `verify_upgrade_authority()` validates the signer is this program's upgrade authority
+    pub client_program: AccountInfo<'info>,
+    
+    /// The program data account for the client program
+    /// 
+    /// CHECK: PDA derived from `client_program` via BPF Loader Upgradeable.
+    /// The upgrade authority is extracted and verified in `verify_upgrade_authority()`.
     #[account(
-        seeds = [b"policy", target.as_ref()],
+        seeds = [client_program.key().as_ref()],
         bump,
-        constraint = policy_account.client_program == target @ PredicateRegistryError::InvalidClientProgram
+        seeds::program = anchor_lang::solana_program::bpf_loader_upgradeable::ID,
     )]
-    pub policy_account: Account<'info, PolicyAccount>,
+    pub program_data: AccountInfo<'info>,
     
-    /// The used UUID account (replay protection)
-    /// Must be created for first use, will fail if already exists
+    /// The upgrade authority of the client program
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for granting a role directly
+#[derive(Accounts)]
+#[instruction(role: Role, holder: Pubkey)]
+pub struct GrantRole<'info> {
+    /// The registry account
+    #[account(
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The role account to be created (fails if the role is already granted)
     #[account(
         init,
-        payer = signer,
-        space = 8 + UsedUuidAccount::INIT_SPACE,
-        seeds = [b"used_uuid", attestation.uuid.as_ref()],
+        payer = authority,
+        space = 8 + RoleAccount::INIT_SPACE,
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), role.seed().as_ref(), holder.as_ref()],
         bump
     )]
-    pub used_uuid_account: Account<'info, UsedUuidAccount>,
+    pub role_account: Account<'info, RoleAccount>,
     
-    /// The user calling the program (validated against program's policy)
+    /// The registry owner
     #[account(mut)]
-    pub signer: Signer<'info>,
+    pub authority: Signer<'info>,
     
     /// System program for account creation
     pub system_program: Program<'info, System>,
-    
-    /// Instructions sysvar for signature verification
-    /// CHECK: This is the instructions sysvar account
-    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
-    pub instructions_sysvar: AccountInfo<'info>,
 }
 
-/// Account validation context for transferring authority
+/// Account validation context for revoking a role directly
 #[derive(Accounts)]
-pub struct TransferAuthority<'info> {
-    /// The registry account whose authority will be transferred
+#[instruction(role: Role, holder: Pubkey)]
+pub struct RevokeRole<'info> {
+    /// The registry account
     #[account(
-        mut,
         has_one = authority @ PredicateRegistryError::Unauthorized,
-        seeds = [b"predicate_registry"],
-        bump
+        constraint = !registry.is_multisig_enabled() @ PredicateRegistryError::MultisigEnabled,
+        constraint = !registry.is_timelock_enabled() @ PredicateRegistryError::TimelockEnabled,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
     )]
     pub registry: Account<'info, PredicateRegistry>,
     
-    /// The current authority
+    /// The role account to be closed (rent returned to the owner)
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"role", registry.namespace_seed(&registry.key()).as_slice(), role.seed().as_ref(), holder.as_ref()],
+        bump
+    )]
+    pub role_account: Account<'info, RoleAccount>,
+    
+    /// The registry owner
+    #[account(mut)]
     pub authority: Signer<'info>,
 }
 
 /// Account validation context for cleaning up expired UUIDs
 #[derive(Accounts)]
 pub struct CleanupExpiredUuid<'info> {
+    /// The registry the UUID was consumed under
+    #[account(
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
     /// The used UUID account to be cleaned up (closed)
     #[account(
         mut,
         close = signer_recipient,
-        seeds = [b"used_uuid", &used_uuid_account.attestation.uuid],
+        seeds = [b"used_uuid", registry.namespace_seed(&registry.key()).as_slice(), &used_uuid_account.attestation.uuid],
         bump,
         // Enforce rent refund goes to the original payer
         // This prevents unauthorized rent theft
@@ -380,15 +2733,67 @@ pub struct CleanupExpiredUuid<'info> {
     pub signer_recipient: AccountInfo<'info>,
 }
 
-/// Account validation context for getting registered attestors (view function)
+/// Account validation context for reading a page of the attester index (view function)
 #[derive(Accounts)]
-pub struct GetRegisteredAttestors<'info> {
+#[instruction(page: u32)]
+pub struct GetRegisteredAttesters<'info> {
     /// The registry account
     #[account(
-        seeds = [b"predicate_registry"],
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The requested attester index page
+    #[account(
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), page.to_le_bytes().as_ref()],
         bump
     )]
+    pub index_page: Account<'info, AttesterIndexPage>,
+}
+
+/// Account validation context for listing a legacy attester in the attester index
+/// 
+/// Only the registry authority can backfill the index, in any mode: listing
+/// an attester that is already registered grants nothing, so it needs no
+/// proposal or queued operation.
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct IndexAttester<'info> {
+    /// The registry account
+    #[account(
+        mut,
+        has_one = authority @ PredicateRegistryError::Unauthorized,
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
+    )]
     pub registry: Account<'info, PredicateRegistry>,
+    
+    /// The attester's account (registered or suspended)
+    #[account(
+        mut,
+        seeds = [b"attester", registry.namespace_seed(&registry.key()).as_slice(), attester.as_ref()],
+        bump,
+        constraint = attester_account.is_listed() @ PredicateRegistryError::AttesterNotRegistered
+    )]
+    pub attester_account: Account<'info, AttesterAccount>,
+    
+    /// The attester index's tail page, created if the last page is full
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AttesterIndexPage::INIT_SPACE,
+        seeds = [b"attester_index", registry.namespace_seed(&registry.key()).as_slice(), registry.attester_index_tail_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub index_page: Account<'info, AttesterIndexPage>,
+    
+    /// The registry authority (pays for a new index page, if one is needed)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
 }
 
 /// Account validation context for getting a policy (view function)
@@ -397,15 +2802,37 @@ pub struct GetRegisteredAttestors<'info> {
 pub struct GetPolicy<'info> {
     /// The registry account
     #[account(
-        seeds = [b"predicate_registry"],
-        bump
+        seeds = [b"predicate_registry", PredicateRegistry::id_seed(registry.registry_id).as_slice()],
+        bump = registry.bump
     )]
     pub registry: Account<'info, PredicateRegistry>,
     
     /// The policy account for the client
     #[account(
-        seeds = [b"policy", client.as_ref()],
+        seeds = [b"policy", registry.namespace_seed(&registry.key()).as_slice(), client.as_ref()],
         bump
     )]
     pub policy_account: Account<'info, PolicyAccount>,
 }
+
+/// Account validation context for migrating an account to the current layout
+/// 
+/// Permissionless: migration only fills in defaults for the fields the
+/// legacy layout lacks, so it cannot change any existing field.
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// The registry, attester, policy or used UUID account to migrate
+    /// CHECK: Owner is checked here; the discriminator and layout are checked by `migrate_account_data`
+    #[account(
+        mut,
+        owner = crate::ID @ PredicateRegistryError::InvalidAccountData
+    )]
+    pub account: UncheckedAccount<'info>,
+    
+    /// Pays for the rent of the added space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program for the rent transfer
+    pub system_program: Program<'info, System>,
+}
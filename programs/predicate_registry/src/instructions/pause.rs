@@ -0,0 +1,32 @@
+//! Pause instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::Pause;
+use crate::events::RegistryPaused;
+
+/// Halt attestation validation across the registry
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the guardian, a pauser, a multisig signer (multisig mode) or the authority can pause
+pub fn pause(ctx: Context<Pause>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    registry.pause(&clock)?;
+
+    emit!(RegistryPaused {
+        registry: registry.key(),
+        paused_by: ctx.accounts.signer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Registry paused by {}", ctx.accounts.signer.key());
+    
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Post collateral instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::instructions::{PostCollateral, verify_attester_or_owner};
+use crate::events::CollateralPosted;
+
+/// Add collateral to a registered attester's bond vault
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the attester
+/// * `amount` - Lamports to add
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the attester itself or the registry owner (single-authority mode)
+///   can post collateral, so no third party can bond an attester and block
+///   its deregistration
+/// * Collateral is returned by `withdraw_bond` to the attester, or to the
+///   registry authority for keys that cannot sign Solana transactions
+/// * Collateral cannot be added once the attester has begun unbonding
+pub fn post_collateral(ctx: Context<PostCollateral>, attester: Pubkey, amount: u64) -> Result<()> {
+    verify_attester_or_owner(&ctx.accounts.registry, &ctx.accounts.depositor.key(), &attester)?;
+
+    let attester_account = &mut ctx.accounts.attester_account;
+    let bond_account = &mut ctx.accounts.bond_account;
+    let depositor = &ctx.accounts.depositor;
+    let clock = Clock::get()?;
+
+    // The vault is created on first deposit by an admin-registered attester
+    if bond_account.identity == Pubkey::default() {
+        bond_account.initialize(attester_account.identity, 0, &clock)?;
+    }
+    let total_bond = bond_account.deposit(amount)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: depositor.to_account_info(),
+                to: bond_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    attester_account.bond = total_bond;
+
+    emit!(CollateralPosted {
+        registry: ctx.accounts.registry.key(),
+        attester,
+        amount,
+        total_bond,
+        depositor: depositor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("{} lamports of collateral posted for attester {} by {}", amount, attester, depositor.key());
+    
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! Propose authority instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::ProposeAuthority;
+use crate::events::AuthorityTransferProposed;
+
+/// Propose a new registry authority
+/// 
+/// This is the first step of a two-step transfer. The registry authority is
+/// not changed until the proposed key signs `accept_authority`, so a mistyped
+/// key can simply be replaced or cancelled.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `new_authority` - The public key of the proposed new authority
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    // Record the pending authority (validates against the zero address)
+    registry.propose_authority(new_authority, &clock)?;
+
+    emit!(AuthorityTransferProposed {
+        registry: registry.key(),
+        authority: authority.key(),
+        pending_authority: new_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Registry authority transfer proposed from {} to {}",
+        authority.key(),
+        new_authority
+    );
+    
+    Ok(())
+}
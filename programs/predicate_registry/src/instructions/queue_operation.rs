@@ -0,0 +1,44 @@
+//! Queue operation instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::QueueOperation;
+use crate::state::AdminAction;
+use crate::events::OperationQueued;
+
+/// Queue an admin action behind the registry timelock
+/// 
+/// The operation becomes executable by anyone once `timelock_delay` seconds
+/// have passed, giving integrators advance notice of trust changes.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `action` - The admin action to queue
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn queue_operation(ctx: Context<QueueOperation>, action: AdminAction) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let operation = &mut ctx.accounts.operation;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    // Reject malformed actions up front rather than at execution time
+    action.validate()?;
+
+    let operation_id = registry.next_operation_id()?;
+    let eta = registry.timelock_eta(&clock)?;
+    operation.initialize(registry.key(), operation_id, authority.key(), action.clone(), eta, &clock)?;
+
+    emit!(OperationQueued {
+        registry: registry.key(),
+        operation: operation.key(),
+        queued_by: authority.key(),
+        action,
+        eta,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Operation {} queued, executable at {}", operation_id, eta);
+    
+    Ok(())
+}
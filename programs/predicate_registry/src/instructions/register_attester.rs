@@ -1,18 +1,40 @@
 //! Register attester instruction for the predicate registry program
 
 use anchor_lang::prelude::*;
-use crate::instructions::RegisterAttester;
-use crate::events::AttesterRegistered;
+use crate::instructions::{RegisterAttester, open_attester, verify_role_or_owner};
+use crate::state::{AttesterMetadata, Role};
+use crate::errors::PredicateRegistryError;
 
 /// Register a new attester
 /// 
+/// The attester is listed on the attester index's tail page.
+/// 
 /// # Arguments
 /// * `ctx` - The instruction context containing accounts
 /// * `attester` - The public key of the attester to register
+/// * `metadata` - Optional descriptive metadata, stored in the attester's metadata account
 /// 
 /// # Returns
 /// * `Result<()>` - Success or error
-pub fn register_attester(ctx: Context<RegisterAttester>, attester: Pubkey) -> Result<()> {
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can register
+pub fn register_attester(
+    ctx: Context<RegisterAttester>,
+    attester: Pubkey,
+    metadata: Option<AttesterMetadata>,
+) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+    require!(
+        metadata.is_some() == ctx.accounts.metadata_account.is_some(),
+        PredicateRegistryError::AttesterMetadataMismatch
+    );
+
     let registry = &mut ctx.accounts.registry;
     let attester_account = &mut ctx.accounts.attester_account;
     let authority = &ctx.accounts.authority;
@@ -24,13 +46,15 @@ pub fn register_attester(ctx: Context<RegisterAttester>, attester: Pubkey) -> Re
     // Update registry statistics
     registry.increment_attester_count(&clock)?;
 
-    // Emit attester registered event
-    emit!(AttesterRegistered {
-        registry: registry.key(),
-        attester,
-        authority: authority.key(),
-        timestamp: clock.unix_timestamp,
-    });
+    // List the attester in the attester index and store its metadata
+    open_attester(
+        registry,
+        attester_account,
+        &mut ctx.accounts.index_page,
+        metadata.zip(ctx.accounts.metadata_account.as_deref_mut()),
+        authority.key(),
+        &clock,
+    )?;
 
     msg!("Attester {} registered by authority {}", attester, authority.key());
     
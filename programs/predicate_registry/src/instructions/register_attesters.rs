@@ -0,0 +1,152 @@
+//! Register attesters instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use crate::instructions::{RegisterAttesters, open_attester, verify_role_or_owner};
+use crate::state::{AttesterAccount, Role, ATTESTER_INDEX_PAGE_SIZE};
+use crate::errors::PredicateRegistryError;
+
+/// Register a batch of attesters
+/// 
+/// Creates one attester account per key from the matching writable PDA in
+/// `remaining_accounts` and records it with `open_attester`, as the
+/// single-attester paths do, moving on to the next index page once the tail
+/// page fills up. The batch is all-or-nothing: any invalid key or account
+/// fails the whole instruction.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attesters` - The public keys of the attesters to register
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can register
+/// * Each remaining account must be the attester PDA for the key at the same position
+/// * The next index page must be supplied exactly when the batch needs it
+pub fn register_attesters<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RegisterAttesters<'info>>,
+    attesters: Vec<Pubkey>,
+) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+    AttesterAccount::validate_batch(&attesters)?;
+    require!(
+        ctx.remaining_accounts.len() == attesters.len(),
+        PredicateRegistryError::InvalidAccountData
+    );
+
+    // A batch spills onto the next index page only if the tail page fills up
+    let tail_page = ctx.accounts.registry.attester_index_tail_page();
+    let tail_page_len = (ctx.accounts.registry.attester_index_len % ATTESTER_INDEX_PAGE_SIZE as u64) as usize;
+    require!(
+        ctx.accounts.next_index_page.is_some() == (tail_page_len + attesters.len() > ATTESTER_INDEX_PAGE_SIZE),
+        PredicateRegistryError::AttesterIndexMismatch
+    );
+
+    let registry_key = ctx.accounts.registry.key();
+    let namespace = ctx.accounts.registry.namespace_seed(&registry_key);
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let clock = Clock::get()?;
+
+    for (attester, attester_info) in attesters.iter().zip(ctx.remaining_accounts) {
+        let (attester_pda, bump) = Pubkey::find_program_address(
+            &[b"attester", namespace.as_ref(), attester.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(attester_info.key(), attester_pda, PredicateRegistryError::InvalidAccountData);
+        require!(attester_info.data_is_empty(), PredicateRegistryError::AttesterAlreadyRegistered);
+
+        let signer_seeds: &[&[u8]] = &[b"attester", namespace.as_ref(), attester.as_ref(), &[bump]];
+        create_attester_account(attester_info, &authority, &system_program, ctx.program_id, signer_seeds)?;
+
+        let mut attester_account = Account::<AttesterAccount>::try_from_unchecked(attester_info)?;
+        attester_account.initialize(*attester, &clock)?;
+
+        let index_page = match ctx.accounts.next_index_page.as_mut() {
+            Some(next_index_page) if ctx.accounts.registry.attester_index_tail_page() != tail_page => next_index_page,
+            _ => &mut ctx.accounts.index_page,
+        };
+        open_attester(&mut ctx.accounts.registry, &mut attester_account, index_page, None, authority.key(), &clock)?;
+        attester_account.exit(ctx.program_id)?;
+    }
+
+    // Update registry statistics once for the whole batch
+    ctx.accounts.registry.increment_attester_count_by(attesters.len() as u64, &clock)?;
+
+    msg!("{} attesters registered by authority {}", attesters.len(), authority.key());
+
+    Ok(())
+}
+
+/// Create a rent-exempt attester account at its PDA, owned by this program
+/// 
+/// Mirrors Anchor's `init`: an address that already holds lamports is topped
+/// up, allocated and assigned instead, so pre-funding it cannot block
+/// registration.
+fn create_attester_account<'info>(
+    attester_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let space = 8 + AttesterAccount::INIT_SPACE;
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = attester_info.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: attester_info.clone(),
+                },
+                &[signer_seeds],
+            ),
+            required_lamports,
+            space as u64,
+            program_id,
+        );
+    }
+
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: attester_info.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: attester_info.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: attester_info.clone(),
+            },
+            &[signer_seeds],
+        ),
+        program_id,
+    )
+}
@@ -0,0 +1,50 @@
+//! Register secp256k1 attester instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{RegisterSecp256k1Attester, open_attester, verify_role_or_owner};
+use crate::state::Role;
+
+/// Register a new attester holding an Ethereum (secp256k1) key
+/// 
+/// The attester is keyed by its address left-padded to 32 bytes, which is
+/// the `attester` used in attestations, events and the attester index. It is
+/// listed on the attester index's tail page.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `eth_address` - The attester's 20-byte Ethereum address
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can register
+pub fn register_secp256k1_attester(
+    ctx: Context<RegisterSecp256k1Attester>,
+    eth_address: [u8; 20],
+) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+
+    let registry = &mut ctx.accounts.registry;
+    let attester_account = &mut ctx.accounts.attester_account;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    attester_account.initialize_secp256k1(&eth_address, &clock)?;
+    let attester = attester_account.attester;
+
+    // Update registry statistics
+    registry.increment_attester_count(&clock)?;
+
+    // List the attester in the attester index
+    open_attester(registry, attester_account, &mut ctx.accounts.index_page, None, authority.key(), &clock)?;
+
+    msg!("Ethereum attester {} registered by authority {}", attester, authority.key());
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Register secp256r1 attester instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{RegisterSecp256r1Attester, open_attester, verify_role_or_owner};
+use crate::state::Role;
+
+/// Register a new attester holding a P-256 (secp256r1) key
+/// 
+/// The attester is keyed by the x-coordinate of its public key, which is
+/// the `attester` used in attestations, events and the attester index. It is
+/// listed on the attester index's tail page.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `public_key` - The attester's 33-byte SEC1 compressed public key
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can register
+pub fn register_secp256r1_attester(
+    ctx: Context<RegisterSecp256r1Attester>,
+    public_key: [u8; 33],
+) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+
+    let registry = &mut ctx.accounts.registry;
+    let attester_account = &mut ctx.accounts.attester_account;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    attester_account.initialize_secp256r1(&public_key, &clock)?;
+    let attester = attester_account.attester;
+
+    // Update registry statistics
+    registry.increment_attester_count(&clock)?;
+
+    // List the attester in the attester index
+    open_attester(registry, attester_account, &mut ctx.accounts.index_page, None, authority.key(), &clock)?;
+
+    msg!("P-256 attester {} registered by authority {}", attester, authority.key());
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! Register with bond instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::instructions::{RegisterWithBond, open_attester};
+use crate::events::AttesterBonded;
+use crate::errors::PredicateRegistryError;
+
+/// Register the signer as an attester by posting the registry's bond
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Permissionless while the registry's bond is non-zero; the attester key
+///   itself must sign and pays the bond plus rent for its accounts, including
+///   a new attester index page when the last one is full
+/// * The bond is held in the attester's bond vault PDA until withdrawn or slashed
+/// * The attester starts scoped with no scope, so none of its attestations
+///   are accepted until an admin grants it one with `set_attester_scope`;
+///   a scope account left over for the key is refused rather than inherited
+pub fn register_with_bond(ctx: Context<RegisterWithBond>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let attester_account = &mut ctx.accounts.attester_account;
+    let bond_account = &mut ctx.accounts.bond_account;
+    let attester = ctx.accounts.attester.key();
+    let clock = Clock::get()?;
+
+    // A vault left behind by an earlier registration must have been emptied
+    require!(bond_account.amount == 0, PredicateRegistryError::AttesterBonded);
+
+    // Move the bond into the vault, on top of its rent exemption
+    let amount = registry.attester_bond;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.attester.to_account_info(),
+                to: bond_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    bond_account.initialize(attester, amount, &clock)?;
+
+    // Initialize the attester account, denying it everything until an admin scopes it
+    attester_account.initialize(attester, &clock)?;
+    attester_account.bond = amount;
+    attester_account.scoped = true;
+
+    // Update registry statistics
+    registry.increment_attester_count(&clock)?;
+
+    // List the attester in the attester index
+    open_attester(registry, attester_account, &mut ctx.accounts.index_page, None, attester, &clock)?;
+
+    emit!(AttesterBonded {
+        registry: registry.key(),
+        attester,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Attester {} registered with a bond of {} lamports", attester, amount);
+    
+    Ok(())
+}
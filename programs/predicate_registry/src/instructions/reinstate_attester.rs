@@ -0,0 +1,44 @@
+//! Reinstate attester instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{ReinstateAttester, verify_role_or_owner};
+use crate::state::Role;
+use crate::events::AttesterReinstated;
+
+/// Reinstate a suspended attester
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the attester to reinstate
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can reinstate
+/// * The attester's validity window, scope and bond are left unchanged
+pub fn reinstate_attester(ctx: Context<ReinstateAttester>, attester: Pubkey) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+
+    let attester_account = &mut ctx.accounts.attester_account;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    attester_account.reinstate(&clock)?;
+
+    emit!(AttesterReinstated {
+        registry: ctx.accounts.registry.key(),
+        attester,
+        authority: authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Attester {} reinstated by {}", attester, authority.key());
+    
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! Renounce authority instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::RenounceAuthority;
+use crate::events::AuthorityRenounced;
+use crate::errors::PredicateRegistryError;
+
+/// Permanently renounce the registry authority
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `confirmation` - Must equal the registry address
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// - Irreversible: the authority becomes `RENOUNCED_AUTHORITY`, which no one can sign for
+/// - Leftover proposals and queued operations can no longer be executed
+/// - Requiring the registry address as confirmation guards against accidental calls
+///   and against renouncing the wrong registry
+pub fn renounce_authority(ctx: Context<RenounceAuthority>, confirmation: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    require!(
+        confirmation == registry.key(),
+        PredicateRegistryError::RenounceNotConfirmed
+    );
+
+    let previous_authority = registry.renounce_authority(&clock)?;
+
+    emit!(AuthorityRenounced {
+        registry: registry.key(),
+        previous_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Registry authority renounced by {}", previous_authority);
+    
+    Ok(())
+}
@@ -0,0 +1,59 @@
+//! Revoke attester after instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{RevokeAttesterAfter, verify_role_or_owner};
+use crate::state::Role;
+use crate::events::AttesterRevoked;
+
+/// Reject an attester key's attestations issued after a cutoff
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the compromised attester
+/// * `timestamp` - Attestations issued after this time are rejected
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can revoke
+/// * Applies to this key only; other keys of the same identity are unaffected
+/// * A cutoff can be tightened but never lifted
+/// * Once a cutoff is set, validation only accepts the key's attestations
+///   under a digest that signs `issued_at`, so the issue time cannot be
+///   rewritten to before the cutoff
+pub fn revoke_attester_after(
+    ctx: Context<RevokeAttesterAfter>,
+    attester: Pubkey,
+    timestamp: i64,
+) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+
+    let attester_account = &mut ctx.accounts.attester_account;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    attester_account.revoke_after(timestamp)?;
+
+    emit!(AttesterRevoked {
+        registry: ctx.accounts.registry.key(),
+        attester,
+        revoked_after: timestamp,
+        authority: authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Attester {} attestations issued after {} revoked by {}",
+        attester,
+        timestamp,
+        authority.key()
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! Revoke role instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::RevokeRole;
+use crate::events::RoleRevoked;
+use crate::state::Role;
+
+/// Revoke a role from a key
+/// 
+/// The role account is closed by the `close = authority` constraint in the
+/// RevokeRole context, returning rent to the owner.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `role` - The role to revoke
+/// * `holder` - The key losing the role
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn revoke_role(ctx: Context<RevokeRole>, role: Role, holder: Pubkey) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    emit!(RoleRevoked {
+        registry: ctx.accounts.registry.key(),
+        role,
+        holder,
+        authority: authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Role revoked from {} by authority {}", holder, authority.key());
+    
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Rotate attester key instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{RotateAttesterKey, add_to_attester_index, verify_role_or_owner};
+use crate::state::Role;
+use crate::events::AttesterKeyRotated;
+
+/// Rotate an attester to a new key under the same identity
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The key being replaced
+/// * `new_key` - The replacement key, of the same key type as `attester`
+/// * `overlap` - Seconds from now during which both keys are accepted
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can rotate;
+///   the attester's own key cannot, so a leaked key cannot extend itself
+/// * The old key stays registered, and listed in the attester index, but
+///   stops validating after the overlap; the new key is listed alongside it
+/// * The bond moves to the new key, so the old key can no longer unbond,
+///   withdraw or be slashed on the identity's behalf
+pub fn rotate_attester_key(
+    ctx: Context<RotateAttesterKey>,
+    attester: Pubkey,
+    new_key: Pubkey,
+    overlap: i64,
+) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+
+    let registry = &mut ctx.accounts.registry;
+    let attester_account = &mut ctx.accounts.attester_account;
+    let new_attester_account = &mut ctx.accounts.new_attester_account;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    let old_key_valid_until = attester_account.rotate_to(new_attester_account, new_key, overlap, &clock)?;
+    registry.increment_attester_count(&clock)?;
+    add_to_attester_index(registry, new_attester_account, &mut ctx.accounts.index_page, &clock)?;
+
+    emit!(AttesterKeyRotated {
+        registry: registry.key(),
+        identity: attester_account.identity,
+        old_key: attester,
+        new_key,
+        old_key_valid_until,
+        authority: authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Attester {} rotated to {}; old key valid until {}",
+        attester,
+        new_key,
+        old_key_valid_until
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! Set attestation config instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::SetAttestationConfig;
+use crate::events::AttestationConfigSet;
+
+/// Set the clock drift buffer and maximum attestation lifetime
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `clock_drift_buffer` - Seconds past expiration an attestation is still accepted
+/// * `max_attestation_lifetime` - Maximum seconds between validation and expiration
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// Both values are shared by `validate_attestation` and `cleanup_expired_uuid`,
+/// so a used UUID account can never be closed while its attestation would
+/// still pass validation.
+pub fn set_attestation_config(
+    ctx: Context<SetAttestationConfig>,
+    clock_drift_buffer: i64,
+    max_attestation_lifetime: i64,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    let previous_clock_drift_buffer = registry.clock_drift_buffer;
+    let previous_max_attestation_lifetime = registry.max_attestation_lifetime;
+    registry.set_attestation_config(clock_drift_buffer, max_attestation_lifetime, &clock)?;
+
+    emit!(AttestationConfigSet {
+        registry: registry.key(),
+        previous_clock_drift_buffer,
+        new_clock_drift_buffer: clock_drift_buffer,
+        previous_max_attestation_lifetime,
+        new_max_attestation_lifetime: max_attestation_lifetime,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Attestation config set: drift buffer {} seconds, max lifetime {} seconds",
+        clock_drift_buffer,
+        max_attestation_lifetime
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,43 @@
+//! Set attester quorum instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{SetAttesterQuorum, verify_upgrade_authority};
+use crate::events::AttesterQuorumSet;
+
+/// Set how many distinct attesters must sign each validation for a client program
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `quorum` - Required attesters (0 or 1 = a single attestation)
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// - Verifies the signer is the program's upgrade authority via `verify_upgrade_authority()`
+/// - With a quorum above 1, `validate_attestation` is rejected for this program
+pub fn set_attester_quorum(ctx: Context<SetAttesterQuorum>, quorum: u8) -> Result<()> {
+    // Verify the signer is the program's upgrade authority
+    verify_upgrade_authority(
+        &ctx.accounts.program_data,
+        &ctx.accounts.authority.key(),
+    )?;
+
+    let policy_account = &mut ctx.accounts.policy_account;
+    let clock = Clock::get()?;
+
+    let client_program = ctx.accounts.client_program.key();
+    policy_account.set_attester_quorum(quorum, &clock)?;
+
+    emit!(AttesterQuorumSet {
+        registry: ctx.accounts.registry.key(),
+        client_program,
+        quorum,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Attester quorum for program {} set to {}", client_program, quorum);
+    
+    Ok(())
+}
@@ -0,0 +1,61 @@
+//! Set attester scope instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{SetAttesterScope, verify_role_or_owner};
+use crate::state::{AttesterScope, Role};
+use crate::events::AttesterScopeSet;
+
+/// Set the client programs and policies a registered attester may sign for
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the attester
+/// * `scope` - The new scope (empty = unrestricted)
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can set the scope
+/// * The scope is shared by every key of the attester's identity, but only
+///   enforced for keys whose `scoped` flag is set: this key and keys rotated
+///   from it later. A previous key still within its rotation overlap must be
+///   passed separately to be restricted as well.
+pub fn set_attester_scope(
+    ctx: Context<SetAttesterScope>,
+    attester: Pubkey,
+    scope: AttesterScope,
+) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+
+    let attester_account = &mut ctx.accounts.attester_account;
+    let scope_account = &mut ctx.accounts.scope_account;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    let identity = attester_account.identity;
+    scope_account.set(identity, scope.clone(), authority.key(), &clock)?;
+    attester_account.scoped = !scope.is_empty();
+
+    emit!(AttesterScopeSet {
+        registry: ctx.accounts.registry.key(),
+        identity,
+        scope,
+        authority: authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Scope for attester {} (identity {}) set by {}",
+        attester,
+        identity,
+        authority.key()
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,58 @@
+//! Set attester validity instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{SetAttesterValidity, verify_role_or_owner};
+use crate::state::Role;
+use crate::events::AttesterValiditySet;
+
+/// Set a registered attester's validity window
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the attester
+/// * `valid_from` - Earliest accepted timestamp (0 = no lower bound)
+/// * `valid_until` - Latest accepted timestamp (0 = no end date)
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can set the window
+pub fn set_attester_validity(
+    ctx: Context<SetAttesterValidity>,
+    attester: Pubkey,
+    valid_from: i64,
+    valid_until: i64,
+) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+
+    let attester_account = &mut ctx.accounts.attester_account;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    attester_account.set_validity(valid_from, valid_until)?;
+
+    emit!(AttesterValiditySet {
+        registry: ctx.accounts.registry.key(),
+        attester,
+        valid_from,
+        valid_until,
+        authority: authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Attester {} valid from {} until {} (set by {})",
+        attester,
+        valid_from,
+        valid_until,
+        authority.key()
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! Set bond config instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{SetBondConfig, verify_role_or_owner};
+use crate::state::Role;
+use crate::events::BondConfigSet;
+
+/// Set the self-registration bond, unbonding period and minimum collateral
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester_bond` - Lamports a self-registering attester must bond (0 = disabled)
+/// * `unbonding_period` - Seconds between exiting and withdrawing a bond
+/// * `min_attester_collateral` - Lamports every attester must keep bonded to be accepted (0 = none)
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or a fee manager can set the config
+/// * Bond and unbonding changes apply to new registrations and exits only:
+///   existing bonds keep their amount, and attesters already exiting keep their
+///   withdrawal time
+/// * The minimum collateral applies to every validation from now on, so
+///   attesters below it are rejected until they post more
+pub fn set_bond_config(
+    ctx: Context<SetBondConfig>,
+    attester_bond: u64,
+    unbonding_period: i64,
+    min_attester_collateral: u64,
+) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::FeeManager,
+    )?;
+
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    let previous_attester_bond = registry.attester_bond;
+    let previous_unbonding_period = registry.unbonding_period;
+    let previous_min_attester_collateral = registry.min_attester_collateral;
+    registry.set_bond_config(attester_bond, unbonding_period, min_attester_collateral, &clock)?;
+
+    emit!(BondConfigSet {
+        registry: registry.key(),
+        previous_attester_bond,
+        new_attester_bond: attester_bond,
+        previous_unbonding_period,
+        new_unbonding_period: unbonding_period,
+        previous_min_attester_collateral,
+        new_min_attester_collateral: min_attester_collateral,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Bond config set: {} lamports, unbonding period {} seconds, minimum collateral {} lamports",
+        attester_bond,
+        unbonding_period,
+        min_attester_collateral
+    );
+    
+    Ok(())
+}
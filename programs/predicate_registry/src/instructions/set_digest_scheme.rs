@@ -0,0 +1,44 @@
+//! Set digest scheme instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{SetDigestScheme, verify_upgrade_authority};
+use crate::state::DigestScheme;
+use crate::events::DigestSchemeSet;
+
+/// Set the digest over which attesters sign statements for a client program
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `digest_scheme` - SHA-256 (Solana layout) or Keccak-256 (EVM ABI encoding)
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// - Verifies the signer is the program's upgrade authority via `verify_upgrade_authority()`
+/// - Attestations signed under the previous scheme stop validating for this program
+pub fn set_digest_scheme(ctx: Context<SetDigestScheme>, digest_scheme: DigestScheme) -> Result<()> {
+    // Verify the signer is the program's upgrade authority
+    verify_upgrade_authority(
+        &ctx.accounts.program_data,
+        &ctx.accounts.authority.key(),
+    )?;
+
+    let policy_account = &mut ctx.accounts.policy_account;
+    let clock = Clock::get()?;
+
+    let client_program = ctx.accounts.client_program.key();
+    policy_account.set_digest_scheme(digest_scheme, &clock)?;
+
+    emit!(DigestSchemeSet {
+        registry: ctx.accounts.registry.key(),
+        client_program,
+        digest_scheme,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Digest scheme for program {} updated", client_program);
+    
+    Ok(())
+}
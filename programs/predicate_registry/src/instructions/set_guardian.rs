@@ -0,0 +1,31 @@
+//! Set guardian instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::SetGuardian;
+use crate::events::GuardianSet;
+
+/// Set or clear the key allowed to pause the registry
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `guardian` - The new guardian, or `None` to remove it
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Option<Pubkey>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    let previous_guardian = registry.set_guardian(guardian, &clock)?;
+
+    emit!(GuardianSet {
+        registry: registry.key(),
+        previous_guardian,
+        new_guardian: guardian,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Guardian set to {:?}", guardian);
+    
+    Ok(())
+}
@@ -51,7 +51,7 @@ pub fn set_policy_id(
 
     emit!(PolicySet {
         registry: registry.key(),
-        client_program: client_program,
+        client_program,
         authority: ctx.accounts.authority.key(),
         policy_id: policy_id.clone(),
         timestamp: clock.unix_timestamp,
@@ -0,0 +1,32 @@
+//! Set timelock delay instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::SetTimelockDelay;
+use crate::events::TimelockDelaySet;
+
+/// Enable the registry timelock by setting its delay
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `delay` - Delay in seconds before queued operations can execute
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn set_timelock_delay(ctx: Context<SetTimelockDelay>, delay: i64) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    let previous_delay = registry.timelock_delay;
+    registry.set_timelock_delay(delay, &clock)?;
+
+    emit!(TimelockDelaySet {
+        registry: registry.key(),
+        previous_delay,
+        new_delay: delay,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Timelock delay set to {} seconds", delay);
+    
+    Ok(())
+}
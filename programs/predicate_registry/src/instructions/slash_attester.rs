@@ -0,0 +1,75 @@
+//! Slash attester instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::incinerator;
+use crate::instructions::SlashAttester;
+use crate::events::AttesterSlashed;
+use crate::errors::PredicateRegistryError;
+
+/// Slash part of an attester's bond for an attestation it signed
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the attester to slash
+/// * `_uuid` - The UUID of the offending attestation (derives the used UUID account used as evidence)
+/// * `amount` - Lamports to slash
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the registry authority (single-authority mode) can slash. Slashing
+///   is a governance decision: a used UUID only shows that an attestation was
+///   accepted, not that it was bad
+/// * The evidence must be a used UUID account in this registry that the
+///   attester signed or co-signed, so it must be slashed before the account
+///   is cleaned up
+/// * Only bonded lamports can be slashed; the vault's rent is untouched
+pub fn slash_attester(
+    ctx: Context<SlashAttester>,
+    attester: Pubkey,
+    _uuid: [u8; 16],
+    amount: u64,
+) -> Result<()> {
+    let attester_account = &mut ctx.accounts.attester_account;
+    let bond_account = &mut ctx.accounts.bond_account;
+    let recipient = &ctx.accounts.recipient;
+    let clock = Clock::get()?;
+
+    require_keys_neq!(
+        recipient.key(),
+        bond_account.key(),
+        PredicateRegistryError::InvalidAccountData
+    );
+
+    let remaining_bond = bond_account.slash(amount)?;
+    attester_account.bond = remaining_bond;
+
+    // The vault is owned by this program, so lamports can be moved directly
+    **bond_account.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let burned = recipient.key() == incinerator::ID;
+    let uuid = ctx.accounts.used_uuid_account.attestation.format_uuid();
+    emit!(AttesterSlashed {
+        registry: ctx.accounts.registry.key(),
+        attester,
+        amount,
+        remaining_bond,
+        uuid: uuid.clone(),
+        recipient: recipient.key(),
+        burned,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Attester {} slashed {} lamports for attestation {} ({})",
+        attester,
+        amount,
+        uuid,
+        if burned { "burned" } else { "redistributed" }
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Suspend attester instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{SuspendAttester, verify_role_or_owner};
+use crate::state::Role;
+use crate::events::AttesterSuspended;
+
+/// Suspend a registered attester without closing its account
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the attester to suspend
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) or an attester manager can suspend
+/// * Suspended attesters' attestations are rejected until they are reinstated
+pub fn suspend_attester(ctx: Context<SuspendAttester>, attester: Pubkey) -> Result<()> {
+    verify_role_or_owner(
+        &ctx.accounts.registry,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_account.as_ref(),
+        Role::AttesterManager,
+    )?;
+
+    let attester_account = &mut ctx.accounts.attester_account;
+    let authority = &ctx.accounts.authority;
+    let clock = Clock::get()?;
+
+    let suspension_count = attester_account.suspend(&clock)?;
+
+    emit!(AttesterSuspended {
+        registry: ctx.accounts.registry.key(),
+        attester,
+        suspension_count,
+        authority: authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Attester {} suspended (suspension #{}) by {}",
+        attester,
+        suspension_count,
+        authority.key()
+    );
+    
+    Ok(())
+}
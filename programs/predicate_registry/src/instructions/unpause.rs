@@ -0,0 +1,32 @@
+//! Unpause instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::Unpause;
+use crate::events::RegistryUnpaused;
+
+/// Resume attestation validation across the registry
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the owner (single-authority mode) can unpause; neither the guardian nor a pauser can
+pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    registry.unpause(&clock)?;
+
+    emit!(RegistryUnpaused {
+        registry: registry.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Registry unpaused by {}", ctx.accounts.authority.key());
+    
+    Ok(())
+}
@@ -0,0 +1,40 @@
+//! Unpause policy instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{SetPolicyPaused, verify_upgrade_authority};
+use crate::events::PolicyUnpaused;
+
+/// Resume attestation validation for a client program
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// - Verifies the signer is the program's upgrade authority via `verify_upgrade_authority()`
+pub fn unpause_policy(ctx: Context<SetPolicyPaused>) -> Result<()> {
+    // Verify the signer is the program's upgrade authority
+    verify_upgrade_authority(
+        &ctx.accounts.program_data,
+        &ctx.accounts.authority.key(),
+    )?;
+
+    let policy_account = &mut ctx.accounts.policy_account;
+    let clock = Clock::get()?;
+
+    let client_program = ctx.accounts.client_program.key();
+    policy_account.unpause(&clock)?;
+
+    emit!(PolicyUnpaused {
+        registry: ctx.accounts.registry.key(),
+        client_program,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Policy unpaused for program {}", client_program);
+    
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! Update attester metadata instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{UpdateAttesterMetadata, verify_role_or_owner};
+use crate::state::{AttesterMetadata, Role};
+use crate::events::AttesterMetadataUpdated;
+
+/// Set a registered attester's metadata, replacing any previous values
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the attester being described
+/// * `metadata` - The new metadata (unset fields are cleared)
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the attester itself, the owner (single-authority mode) or an attester manager can update
+/// * Metadata is informational and never affects attestation validation
+pub fn update_attester_metadata(
+    ctx: Context<UpdateAttesterMetadata>,
+    attester: Pubkey,
+    metadata: AttesterMetadata,
+) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    if signer != attester {
+        verify_role_or_owner(
+            &ctx.accounts.registry,
+            &signer,
+            ctx.accounts.role_account.as_ref(),
+            Role::AttesterManager,
+        )?;
+    }
+
+    let metadata_account = &mut ctx.accounts.metadata_account;
+    let clock = Clock::get()?;
+
+    metadata_account.set(attester, metadata.clone(), signer, &clock)?;
+
+    emit!(AttesterMetadataUpdated {
+        registry: ctx.accounts.registry.key(),
+        attester,
+        metadata,
+        updated_by: signer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Metadata for attester {} updated by {}", attester, signer);
+    
+    Ok(())
+}
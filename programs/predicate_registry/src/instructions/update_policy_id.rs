@@ -42,7 +42,7 @@ pub fn update_policy_id(
 
     emit!(PolicyUpdated {
         registry: registry.key(),
-        client_program: client_program,
+        client_program,
         authority: ctx.accounts.authority.key(),
         previous_policy_id,
         new_policy_id: policy_id.clone(),
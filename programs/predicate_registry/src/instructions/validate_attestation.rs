@@ -2,14 +2,30 @@
 
 use anchor_lang::prelude::*;
 use crate::instructions::ValidateAttestation;
-use crate::state::{Statement, Attestation};
+use crate::state::{Statement, Attestation, AttesterAccount, AttesterKeyType, AttesterScopeAccount, DigestScheme, PredicateRegistry};
 use crate::events::{StatementValidated, UuidMarkedUsed};
 use crate::errors::PredicateRegistryError;
 use anchor_lang::solana_program::{
     ed25519_program,
+    instruction::Instruction,
+    secp256k1_program,
     sysvar::instructions::{self, load_current_index_checked, load_instruction_at_checked},
 };
 
+/// The secp256r1 (P-256) signature verification program
+/// 
+/// Not re-exported by `solana_program`, so its well-known id is declared here.
+mod secp256r1_program {
+    anchor_lang::solana_program::declare_id!("Secp256r1SigVerify1111111111111111111111111");
+}
+
+/// Signature precompiles whose instructions may precede a validation
+const SIGNATURE_PROGRAMS: [Pubkey; 3] = [
+    ed25519_program::ID,
+    secp256k1_program::ID,
+    secp256r1_program::ID,
+];
+
 /// Validate an attestation for a transaction
 /// 
 /// This function constructs a Statement internally from validated sources,
@@ -21,7 +37,7 @@ use anchor_lang::solana_program::{
 /// * `target` - The program being called
 /// * `msg_value` - The value being transferred (typically 0 on Solana)
 /// * `encoded_sig_and_args` - The encoded function signature and arguments
-/// * `attestation` - The attestation containing uuid, expiration, signature, and attester
+/// * `attestation` - The attestation containing uuid, expiration, issued-at time, signature, and attester
 /// 
 /// # Returns
 /// * `Result<bool>` - True if validation succeeds
@@ -53,6 +69,7 @@ pub fn validate_attestation(
         encoded_sig_and_args,
         policy_id: policy_account.policy_id.clone(),
         expiration: attestation.expiration,
+        issued_at: attestation.issued_at,
     };
     
     // Get current timestamp with error handling
@@ -74,36 +91,61 @@ pub fn validate_attestation(
 
     // === BUSINESS LOGIC VALIDATION ===
 
-    // Check if attestation has expired (with buffer for clock drift)
+    // Check if attestation has expired (with the registry's buffer for clock drift)
     require!(
-        attestation.is_valid_at(current_timestamp),
+        attestation.is_valid_at(current_timestamp, registry.clock_drift_buffer),
         PredicateRegistryError::StatementExpired
     );
 
+    // Reject far-future expirations so a leaked attestation cannot live forever
+    require!(
+        !attestation.exceeds_lifetime(current_timestamp, registry.max_attestation_lifetime),
+        PredicateRegistryError::AttestationLifetimeExceeded
+    );
+
+    // Reject issue times in the future or too long before expiration
+    require!(
+        attestation.has_valid_issued_at(
+            current_timestamp,
+            registry.clock_drift_buffer,
+            registry.max_attestation_lifetime
+        ),
+        PredicateRegistryError::InvalidIssuedAt
+    );
+
+    // Policies with a quorum must be validated by several attesters
+    require!(
+        !policy_account.requires_quorum(),
+        PredicateRegistryError::AttesterQuorumRequired
+    );
+
     // Verify that the attester in the attestation matches the registered attester
     require!(
         attestation.attester == attester_account.attester,
         PredicateRegistryError::WrongAttester
     );
 
-    // Verify that the attester is registered and active
-    require!(
-        attester_account.is_registered,
-        PredicateRegistryError::AttesterNotRegisteredForValidation
-    );
+    // Verify that the attester is registered, within its validity window and in scope
+    verify_attester_eligibility(
+        registry,
+        attester_account,
+        ctx.accounts.attester_scope_account.as_deref(),
+        &statement,
+        policy_account.digest_scheme,
+        current_timestamp,
+    )?;
 
 
     // === SIGNATURE VERIFICATION ===
     
-    // Hash the statement for signature verification
-    let message_hash = statement.hash_statement_safe();
+    // Hash the statement for signature verification, with the policy's digest scheme
+    let message_hash = statement.digest(policy_account.digest_scheme);
     
-    // Verify Ed25519 signature using Solana's native verification
-    // This implementation checks that the ed25519 verification instruction was included
+    // Verify the signature using Solana's native verification for the attester's key type
+    // This implementation checks that the signature verification instruction was included
     // in the same transaction as this instruction
-    verify_ed25519_signature(
-        &attestation.signature,
-        &attestation.attester.to_bytes(),
+    verify_attester_signatures(
+        &[(attester_account.key_type, attestation.attester, attestation.signature)],
         &message_hash,
         &ctx.accounts.instructions_sysvar,
     )?;
@@ -117,6 +159,7 @@ pub fn validate_attestation(
     used_uuid_account.attestation = attestation.clone();
     used_uuid_account.used_at = current_timestamp;
     used_uuid_account.signer = signer.key();
+    used_uuid_account.co_signers = Vec::new();
 
     // Emit UUID marked as used event
     emit!(UuidMarkedUsed {
@@ -149,23 +192,191 @@ pub fn validate_attestation(
     Ok(true)
 }
 
-/// Verify Ed25519 signature using defense-in-depth approach
+/// Verify that an attester may sign the given statement
+/// 
+/// Shared by single and threshold validation. The attester must be
+/// registered, hold the registry's minimum collateral and be within its
+/// validity window, the statement must not be issued after the attester's
+/// revocation cutoff (and, once a cutoff is set, its issue time must be
+/// signed), and a scoped attester's scope account must be supplied and permit
+/// the statement's target and policy.
+/// 
+/// # Arguments
+/// * `registry` - The registry the attester belongs to
+/// * `attester_account` - The attester's account
+/// * `scope_account` - The scope account of the attester's identity, if supplied
+/// * `statement` - The statement being validated
+/// * `digest_scheme` - The policy's digest scheme, which decides whether `issued_at` is signed
+/// * `current_timestamp` - The current unix timestamp
+/// 
+/// # Returns
+/// * `Result<()>` - Ok if the attester may sign, error otherwise
+pub(crate) fn verify_attester_eligibility(
+    registry: &PredicateRegistry,
+    attester_account: &AttesterAccount,
+    scope_account: Option<&AttesterScopeAccount>,
+    statement: &Statement,
+    digest_scheme: DigestScheme,
+    current_timestamp: i64,
+) -> Result<()> {
+    // Verify that the attester is registered and active
+    require!(
+        !attester_account.is_suspended,
+        PredicateRegistryError::AttesterSuspended
+    );
+    require!(
+        attester_account.is_registered,
+        PredicateRegistryError::AttesterNotRegisteredForValidation
+    );
+
+    // Verify that the attester has enough at stake
+    require!(
+        attester_account.bond >= registry.min_attester_collateral,
+        PredicateRegistryError::InsufficientCollateral
+    );
+
+    // Verify that the attester is within its validity window
+    require!(
+        attester_account.has_started_at(current_timestamp),
+        PredicateRegistryError::AttesterNotYetValid
+    );
+    require!(
+        !attester_account.has_ended_at(current_timestamp),
+        PredicateRegistryError::AttesterValidityEnded
+    );
+
+    // Verify that the statement was issued before any revocation cutoff; an
+    // unsigned issue time could simply be rewritten to before it
+    if attester_account.revoked_after != 0 {
+        require!(
+            digest_scheme.signs_issued_at(),
+            PredicateRegistryError::IssuedAtNotSigned
+        );
+    }
+    require!(
+        !attester_account.is_revoked_for(statement.issued_at),
+        PredicateRegistryError::AttestationRevoked
+    );
+
+    // Verify that a scoped attester may sign for this program and policy
+    if attester_account.scoped {
+        let scope_account = scope_account.ok_or(PredicateRegistryError::AttesterScopeRequired)?;
+        require!(
+            scope_account.scope.permits(&statement.target, &statement.policy_id),
+            PredicateRegistryError::AttesterOutOfScope
+        );
+    }
+    Ok(())
+}
+
+/// Verify attester signatures against the precompile for each key type
+/// 
+/// Ed25519 attesters are checked against the Ed25519 program instruction,
+/// secp256k1 attesters against the secp256k1 program instruction and
+/// secp256r1 attesters against the secp256r1 program instruction, so
+/// attesters of different kinds can sign the same statement. Each
+/// instruction must carry exactly the entries for its key type, in order.
+/// 
+/// # Arguments
+/// * `signatures` - The key type, attester key and 64-byte signature of each attester
+/// * `message` - The message that was signed (32-byte hash)
+/// * `instructions_sysvar` - The instructions sysvar account
+/// 
+/// # Returns
+/// * `Result<()>` - Ok if all validation passes, error otherwise
+pub(crate) fn verify_attester_signatures(
+    signatures: &[(AttesterKeyType, Pubkey, [u8; 64])],
+    message: &[u8; 32],
+    instructions_sysvar: &AccountInfo,
+) -> Result<()> {
+    let ed25519: Vec<([u8; 64], [u8; 32])> = signatures
+        .iter()
+        .filter(|(key_type, _, _)| *key_type == AttesterKeyType::Ed25519)
+        .map(|(_, attester, signature)| (*signature, attester.to_bytes()))
+        .collect();
+    let secp256k1: Vec<([u8; 64], [u8; 20])> = signatures
+        .iter()
+        .filter(|(key_type, _, _)| *key_type == AttesterKeyType::Secp256k1)
+        .map(|(_, attester, signature)| (*signature, AttesterAccount::eth_address(attester)))
+        .collect();
+    let secp256r1: Vec<([u8; 64], [u8; 32])> = signatures
+        .iter()
+        .filter(|(key_type, _, _)| *key_type == AttesterKeyType::Secp256r1)
+        .map(|(_, attester, signature)| (*signature, attester.to_bytes()))
+        .collect();
+
+    if !ed25519.is_empty() {
+        verify_ed25519_signatures(&ed25519, message, instructions_sysvar)?;
+    }
+    if !secp256k1.is_empty() {
+        verify_secp256k1_signatures(&secp256k1, message, instructions_sysvar)?;
+    }
+    if !secp256r1.is_empty() {
+        verify_secp256r1_signatures(&secp256r1, message, instructions_sysvar)?;
+    }
+    Ok(())
+}
+
+/// Load the signature instruction run by `program_id` for the current instruction
+/// 
+/// Signature instructions must immediately precede the validating
+/// instruction, in any order; the search stops at the first instruction
+/// that is not a signature precompile, so signatures cannot be sourced from
+/// unrelated parts of the transaction.
+/// 
+/// # Returns
+/// * `Result<(usize, Instruction)>` - The signature instruction's index and the instruction
+fn load_signature_instruction(
+    program_id: &Pubkey,
+    instructions_sysvar: &AccountInfo,
+) -> Result<(usize, Instruction)> {
+    // Verify this is the instructions sysvar account
+    require!(
+        instructions_sysvar.key == &instructions::ID,
+        PredicateRegistryError::InvalidAccountData
+    );
+
+    // Load the current instruction index
+    let current_index = load_current_index_checked(instructions_sysvar)
+        .map_err(|_| PredicateRegistryError::InvalidAccountData)? as usize;
+
+    for index in (0..current_index).rev() {
+        let instruction = load_instruction_at_checked(index, instructions_sysvar)
+            .map_err(|_| PredicateRegistryError::InvalidSignature)?;
+        if instruction.program_id == *program_id {
+            // Verify the instruction has no accounts (stateless check)
+            require!(
+                instruction.accounts.is_empty(),
+                PredicateRegistryError::InvalidSignature
+            );
+            return Ok((index, instruction));
+        }
+        if !SIGNATURE_PROGRAMS.contains(&instruction.program_id) {
+            break;
+        }
+    }
+    err!(PredicateRegistryError::InvalidSignature)
+}
+
+/// Verify Ed25519 signatures using defense-in-depth approach
 /// 
 /// This function validates that an Ed25519 signature verification instruction
 /// was properly included in the same transaction using multiple security layers.
+/// The instruction must carry exactly one entry per expected signature, in
+/// the same order, each over `message`.
 /// 
 /// # Security Layers
-/// 1. Position check - Ed25519 must be immediately before this instruction
+/// 1. Position check - Ed25519 must be among the signature instructions immediately before this instruction
 /// 2. Program ID check - Must be Ed25519Program
 /// 3. Stateless check - Ed25519 instruction has no accounts
-/// 4. Instruction index validation - Data is self-contained (0xFFFF)
-/// 5. Offset validation - Offsets don't overlap with header
-/// 6. Message size validation - Exactly 32 bytes
-/// 7. Data comparison - Signature, pubkey, and message match expected values
+/// 4. Signature count check - One entry per expected signature
+/// 5. Instruction index validation - Data is self-contained (0xFFFF)
+/// 6. Offset validation - Offsets don't overlap with header
+/// 7. Message size validation - Exactly 32 bytes
+/// 8. Data comparison - Signature, pubkey, and message match expected values
 /// 
 /// # Arguments
-/// * `signature` - The 64-byte Ed25519 signature
-/// * `pubkey` - The 32-byte public key
+/// * `expected` - The 64-byte signature and 32-byte public key of each entry
 /// * `message` - The message that was signed (32-byte hash)
 /// * `instructions_sysvar` - The instructions sysvar account
 /// 
@@ -175,50 +386,20 @@ pub fn validate_attestation(
 /// # Security Notes
 /// - Multiple independent layers prevent various attack vectors
 /// - Instruction index validation prevents cross-instruction data sourcing
-/// - The Ed25519Program has already verified the cryptographic signature
-fn verify_ed25519_signature(
-    signature: &[u8; 64],
-    pubkey: &[u8; 32],
+/// - The Ed25519Program has already verified the cryptographic signatures
+pub(crate) fn verify_ed25519_signatures(
+    expected: &[([u8; 64], [u8; 32])],
     message: &[u8; 32],
     instructions_sysvar: &AccountInfo,
 ) -> Result<()> {    
-    const HEADER_LEN: usize = 16;
+    const COUNT_LEN: usize = 2;
+    const OFFSETS_LEN: usize = 14;
     const SIG_LEN: usize = 64;
     const PUBKEY_LEN: usize = 32;
     const INSTRUCTION_INDEX_CURRENT: usize = u16::MAX as usize;
 
-    // Verify this is the instructions sysvar account
-    require!(
-        instructions_sysvar.key == &instructions::ID,
-        PredicateRegistryError::InvalidAccountData
-    );
-
-    // Load the current instruction index
-    let current_index = load_current_index_checked(instructions_sysvar)
-        .map_err(|_| PredicateRegistryError::InvalidAccountData)?;
-
-    // Check if there's a previous instruction (ed25519 verification should come before this one)
-    if current_index == 0 {
-        return Err(PredicateRegistryError::InvalidSignature.into());
-    }
-
-    // Load the previous instruction
-    let ed25519_ix = load_instruction_at_checked(
-        (current_index - 1) as usize,
-        instructions_sysvar,
-    ).map_err(|_| PredicateRegistryError::InvalidSignature)?;
-
-    // Verify it's an ed25519 verification instruction
-    require!(
-        ed25519_ix.program_id == ed25519_program::ID,
-        PredicateRegistryError::InvalidSignature
-    );
-
-    // Verify the instruction has no accounts (stateless check)
-    require!(
-        ed25519_ix.accounts.is_empty(),
-        PredicateRegistryError::InvalidSignature
-    );
+    // Load the ed25519 verification instruction preceding this one
+    let (_, ed25519_ix) = load_signature_instruction(&ed25519_program::ID, instructions_sysvar)?;
 
     // Verify the instruction data format
     let ix_data = &ed25519_ix.data;
@@ -228,99 +409,406 @@ fn verify_ed25519_signature(
     // Format:
     // [0]   u8: num_signatures
     // [1]   u8: padding
-    // [2..4] u16: signature_offset
-    // [4..6] u16: signature_instruction_index
-    // [6..8] u16: public_key_offset
-    // [8..10] u16: public_key_instruction_index
-    // [10..12] u16: message_data_offset
-    // [12..14] u16: message_data_size
-    // [14..16] u16: message_instruction_index
-    // [16..] signature, pubkey, message
+    // Then, for each signature, 14 bytes of offsets:
+    // [+0..+2]   u16: signature_offset
+    // [+2..+4]   u16: signature_instruction_index
+    // [+4..+6]   u16: public_key_offset
+    // [+6..+8]   u16: public_key_instruction_index
+    // [+8..+10]  u16: message_data_offset
+    // [+10..+12] u16: message_data_size
+    // [+12..+14] u16: message_instruction_index
+    // [..] signatures, pubkeys, messages
 
     require!(
-        ix_data.len() >= HEADER_LEN,
+        ix_data.len() >= COUNT_LEN,
         PredicateRegistryError::InvalidSignature
     );
 
-    // Only support single signature for now
-    let num_signatures = ix_data[0];
+    // Require exactly one entry per expected signature
+    let num_signatures = ix_data[0] as usize;
     require!(
-        num_signatures == 1,
+        !expected.is_empty() && num_signatures == expected.len(),
         PredicateRegistryError::InvalidSignature
     );
 
-    // Parse offsets and instruction indices (all little-endian u16)
-    let sig_offset = u16::from_le_bytes([ix_data[2], ix_data[3]]) as usize;
-    let sig_ix_idx = u16::from_le_bytes([ix_data[4], ix_data[5]]) as usize;
-    let pubkey_offset = u16::from_le_bytes([ix_data[6], ix_data[7]]) as usize;
-    let pubkey_ix_idx = u16::from_le_bytes([ix_data[8], ix_data[9]]) as usize;
-    let msg_offset = u16::from_le_bytes([ix_data[10], ix_data[11]]) as usize;
-    let msg_size = u16::from_le_bytes([ix_data[12], ix_data[13]]) as usize;
-    let msg_ix_idx = u16::from_le_bytes([ix_data[14], ix_data[15]]) as usize;
-
-    // Verify all instruction indices point to current instruction
-    // The Ed25519 program uses u16::MAX (0xFFFF) as a sentinel value for "current instruction"
-    // This prevents reading signature, public key, or message from other instructions
+    let header_len = COUNT_LEN + OFFSETS_LEN * num_signatures;
     require!(
-        sig_ix_idx == INSTRUCTION_INDEX_CURRENT
-            && pubkey_ix_idx == INSTRUCTION_INDEX_CURRENT
-            && msg_ix_idx == INSTRUCTION_INDEX_CURRENT,
+        ix_data.len() >= header_len,
         PredicateRegistryError::InvalidSignature
     );
 
-    // Verify all offsets point beyond the header (into the data region)
-    require!(
-        sig_offset >= HEADER_LEN 
-            && pubkey_offset >= HEADER_LEN 
-            && msg_offset >= HEADER_LEN,
-        PredicateRegistryError::InvalidSignature
-    );
+    for (i, (signature, pubkey)) in expected.iter().enumerate() {
+        let base = COUNT_LEN + OFFSETS_LEN * i;
+        let read_u16 = |at: usize| u16::from_le_bytes([ix_data[base + at], ix_data[base + at + 1]]) as usize;
+
+        // Parse offsets and instruction indices (all little-endian u16)
+        let sig_offset = read_u16(0);
+        let sig_ix_idx = read_u16(2);
+        let pubkey_offset = read_u16(4);
+        let pubkey_ix_idx = read_u16(6);
+        let msg_offset = read_u16(8);
+        let msg_size = read_u16(10);
+        let msg_ix_idx = read_u16(12);
+
+        // Verify all instruction indices point to current instruction
+        // The Ed25519 program uses u16::MAX (0xFFFF) as a sentinel value for "current instruction"
+        // This prevents reading signature, public key, or message from other instructions
+        require!(
+            sig_ix_idx == INSTRUCTION_INDEX_CURRENT
+                && pubkey_ix_idx == INSTRUCTION_INDEX_CURRENT
+                && msg_ix_idx == INSTRUCTION_INDEX_CURRENT,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify all offsets point beyond the header (into the data region)
+        require!(
+            sig_offset >= header_len 
+                && pubkey_offset >= header_len 
+                && msg_offset >= header_len,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Bounds checks for signature, pubkey, and message slices
+        require!(
+            ix_data.len() >= sig_offset + SIG_LEN,
+            PredicateRegistryError::InvalidSignature
+        );
+        require!(
+            ix_data.len() >= pubkey_offset + PUBKEY_LEN,
+            PredicateRegistryError::InvalidSignature
+        );
+        require!(
+            ix_data.len() >= msg_offset + msg_size,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify message size matches our expected hash size (32 bytes)
+        require!(
+            msg_size == 32,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Extract the signature, public key, and message from the instruction data
+        let sig_slice = &ix_data[sig_offset..sig_offset + SIG_LEN];
+        let pubkey_slice = &ix_data[pubkey_offset..pubkey_offset + PUBKEY_LEN];
+        let msg_slice = &ix_data[msg_offset..msg_offset + msg_size];
+
+        // Verify that the signature matches what we expect
+        require!(
+            sig_slice == signature,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify that the public key matches what we expect
+        require!(
+            pubkey_slice == pubkey,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify that the message matches what we expect
+        require!(
+            msg_slice == message,
+            PredicateRegistryError::InvalidSignature
+        );
+    }
+
+    // If we reach here, the signature verification instruction was properly included
+    // and matches our expected parameters. The Ed25519Program has already verified
+    // the cryptographic signatures (or the transaction would have failed).
+    Ok(())
+}
+
+/// Verify secp256k1 (Ethereum) signatures using the same layered checks as Ed25519
+/// 
+/// The secp256k1 program recovers the signer of `keccak256(message)` from each
+/// signature and fails the transaction unless it matches the entry's
+/// Ethereum address, so the attester signs the keccak256 of the statement
+/// hash, without an Ethereum message prefix. The instruction must carry
+/// exactly one entry per expected signature, in the same order.
+/// 
+/// # Security Layers
+/// 1. Position check - Secp256k1 must be among the signature instructions immediately before this instruction
+/// 2. Program ID check - Must be the secp256k1 program
+/// 3. Stateless check - Secp256k1 instruction has no accounts
+/// 4. Signature count check - One entry per expected signature
+/// 5. Instruction index validation - Data is read from the secp256k1 instruction itself
+/// 6. Offset validation - Offsets don't overlap with header
+/// 7. Message size validation - Exactly 32 bytes
+/// 8. Data comparison - Signature, Ethereum address, and message match expected values
+/// 
+/// # Arguments
+/// * `expected` - The 64-byte signature (without recovery id) and 20-byte Ethereum address of each entry
+/// * `message` - The message that was signed (32-byte hash)
+/// * `instructions_sysvar` - The instructions sysvar account
+/// 
+/// # Returns
+/// * `Result<()>` - Ok if all validation passes, error otherwise
+pub(crate) fn verify_secp256k1_signatures(
+    expected: &[([u8; 64], [u8; 20])],
+    message: &[u8; 32],
+    instructions_sysvar: &AccountInfo,
+) -> Result<()> {
+    const COUNT_LEN: usize = 1;
+    const OFFSETS_LEN: usize = 11;
+    const SIG_LEN: usize = 64;
+    const RECOVERY_ID_LEN: usize = 1;
+    const ETH_ADDRESS_LEN: usize = 20;
+
+    // Load the secp256k1 verification instruction preceding this one
+    let (secp256k1_index, secp256k1_ix) =
+        load_signature_instruction(&secp256k1_program::ID, instructions_sysvar)?;
+    let ix_data = &secp256k1_ix.data;
+
+    // Parse Secp256k1 instruction format according to Solana's specification
+    // Reference: https://docs.solana.com/developing/runtime-facilities/programs#secp256k1-program
+    // Format:
+    // [0]   u8: num_signatures
+    // Then, for each signature, 11 bytes of offsets:
+    // [+0..+2]   u16: signature_offset (64-byte signature followed by a recovery id)
+    // [+2]       u8:  signature_instruction_index
+    // [+3..+5]   u16: eth_address_offset
+    // [+5]       u8:  eth_address_instruction_index
+    // [+6..+8]   u16: message_data_offset
+    // [+8..+10]  u16: message_data_size
+    // [+10]      u8:  message_instruction_index
+    // [..] signatures, eth addresses, messages
 
-    // Bounds checks for signature, pubkey, and message slices
-    require!(
-        ix_data.len() >= sig_offset + SIG_LEN,
-        PredicateRegistryError::InvalidSignature
-    );
     require!(
-        ix_data.len() >= pubkey_offset + PUBKEY_LEN,
+        ix_data.len() >= COUNT_LEN,
         PredicateRegistryError::InvalidSignature
     );
+
+    // Require exactly one entry per expected signature
+    let num_signatures = ix_data[0] as usize;
     require!(
-        ix_data.len() >= msg_offset + msg_size,
+        !expected.is_empty() && num_signatures == expected.len(),
         PredicateRegistryError::InvalidSignature
     );
 
-    // Verify message size matches our expected hash size (32 bytes)
+    let header_len = COUNT_LEN + OFFSETS_LEN * num_signatures;
     require!(
-        msg_size == 32,
+        ix_data.len() >= header_len,
         PredicateRegistryError::InvalidSignature
     );
 
-    // Extract the signature, public key, and message from the instruction data
-    let sig_slice = &ix_data[sig_offset..sig_offset + SIG_LEN];
-    let pubkey_slice = &ix_data[pubkey_offset..pubkey_offset + PUBKEY_LEN];
-    let msg_slice = &ix_data[msg_offset..msg_offset + msg_size];
+    for (i, (signature, eth_address)) in expected.iter().enumerate() {
+        let base = COUNT_LEN + OFFSETS_LEN * i;
+        let read_u16 = |at: usize| u16::from_le_bytes([ix_data[base + at], ix_data[base + at + 1]]) as usize;
+        let read_u8 = |at: usize| ix_data[base + at] as usize;
+
+        // Parse offsets (little-endian u16) and instruction indices (u8)
+        let sig_offset = read_u16(0);
+        let sig_ix_idx = read_u8(2);
+        let eth_address_offset = read_u16(3);
+        let eth_address_ix_idx = read_u8(5);
+        let msg_offset = read_u16(6);
+        let msg_size = read_u16(8);
+        let msg_ix_idx = read_u8(10);
+
+        // Verify all instruction indices point to the secp256k1 instruction itself
+        // The secp256k1 program has no "current instruction" sentinel, so the
+        // indices are absolute positions in the transaction
+        require!(
+            sig_ix_idx == secp256k1_index
+                && eth_address_ix_idx == secp256k1_index
+                && msg_ix_idx == secp256k1_index,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify all offsets point beyond the header (into the data region)
+        require!(
+            sig_offset >= header_len
+                && eth_address_offset >= header_len
+                && msg_offset >= header_len,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Bounds checks for signature (with recovery id), address, and message slices
+        require!(
+            ix_data.len() >= sig_offset + SIG_LEN + RECOVERY_ID_LEN,
+            PredicateRegistryError::InvalidSignature
+        );
+        require!(
+            ix_data.len() >= eth_address_offset + ETH_ADDRESS_LEN,
+            PredicateRegistryError::InvalidSignature
+        );
+        require!(
+            ix_data.len() >= msg_offset + msg_size,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify message size matches our expected hash size (32 bytes)
+        require!(
+            msg_size == 32,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify that the signature, address and message match what we expect
+        require!(
+            &ix_data[sig_offset..sig_offset + SIG_LEN] == signature,
+            PredicateRegistryError::InvalidSignature
+        );
+        require!(
+            &ix_data[eth_address_offset..eth_address_offset + ETH_ADDRESS_LEN] == eth_address,
+            PredicateRegistryError::InvalidSignature
+        );
+        require!(
+            &ix_data[msg_offset..msg_offset + msg_size] == message,
+            PredicateRegistryError::InvalidSignature
+        );
+    }
+
+    // The secp256k1 program has already recovered each signer and checked it
+    // against the address (or the transaction would have failed)
+    Ok(())
+}
+
+/// Verify secp256r1 (P-256) signatures using the same layered checks as Ed25519
+/// 
+/// The secp256r1 program verifies each ECDSA signature over the SHA-256 of
+/// the entry's message and fails the transaction unless it is valid and in
+/// low-S form, so the attester signs the statement hash with ECDSA-SHA256,
+/// as HSMs and cloud KMS do by default. Entries carry the 33-byte compressed
+/// public key, matched against the attester's x-coordinate key. The
+/// instruction must carry exactly one entry per expected signature, in the
+/// same order.
+/// 
+/// # Security Layers
+/// 1. Position check - Secp256r1 must be among the signature instructions immediately before this instruction
+/// 2. Program ID check - Must be the secp256r1 program
+/// 3. Stateless check - Secp256r1 instruction has no accounts
+/// 4. Signature count check - One entry per expected signature
+/// 5. Instruction index validation - Data is self-contained (0xFFFF)
+/// 6. Offset validation - Offsets don't overlap with header
+/// 7. Message size validation - Exactly 32 bytes
+/// 8. Data comparison - Signature, public key, and message match expected values
+/// 
+/// # Arguments
+/// * `expected` - The 64-byte signature and 32-byte x-coordinate key of each entry
+/// * `message` - The message that was signed (32-byte hash)
+/// * `instructions_sysvar` - The instructions sysvar account
+/// 
+/// # Returns
+/// * `Result<()>` - Ok if all validation passes, error otherwise
+pub(crate) fn verify_secp256r1_signatures(
+    expected: &[([u8; 64], [u8; 32])],
+    message: &[u8; 32],
+    instructions_sysvar: &AccountInfo,
+) -> Result<()> {
+    const COUNT_LEN: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    const SIG_LEN: usize = 64;
+    const PUBKEY_LEN: usize = 33;
+    const INSTRUCTION_INDEX_CURRENT: usize = u16::MAX as usize;
+
+    // Load the secp256r1 verification instruction preceding this one
+    let (_, secp256r1_ix) = load_signature_instruction(&secp256r1_program::ID, instructions_sysvar)?;
+    let ix_data = &secp256r1_ix.data;
+
+    // Parse Secp256r1 instruction format (SIMD-0075), which mirrors Ed25519's
+    // Format:
+    // [0]   u8: num_signatures
+    // [1]   u8: padding
+    // Then, for each signature, 14 bytes of offsets:
+    // [+0..+2]   u16: signature_offset
+    // [+2..+4]   u16: signature_instruction_index
+    // [+4..+6]   u16: public_key_offset (33-byte compressed key)
+    // [+6..+8]   u16: public_key_instruction_index
+    // [+8..+10]  u16: message_data_offset
+    // [+10..+12] u16: message_data_size
+    // [+12..+14] u16: message_instruction_index
+    // [..] signatures, pubkeys, messages
 
-    // Verify that the signature matches what we expect
     require!(
-        sig_slice == signature,
+        ix_data.len() >= COUNT_LEN,
         PredicateRegistryError::InvalidSignature
     );
 
-    // Verify that the public key matches what we expect
+    // Require exactly one entry per expected signature
+    let num_signatures = ix_data[0] as usize;
     require!(
-        pubkey_slice == pubkey,
+        !expected.is_empty() && num_signatures == expected.len(),
         PredicateRegistryError::InvalidSignature
     );
 
-    // Verify that the message matches what we expect
+    let header_len = COUNT_LEN + OFFSETS_LEN * num_signatures;
     require!(
-        msg_slice == message,
+        ix_data.len() >= header_len,
         PredicateRegistryError::InvalidSignature
     );
 
-    // If we reach here, the signature verification instruction was properly included
-    // and matches our expected parameters. The Ed25519Program has already verified
-    // the cryptographic signature (or the transaction would have failed).
+    for (i, (signature, key)) in expected.iter().enumerate() {
+        let base = COUNT_LEN + OFFSETS_LEN * i;
+        let read_u16 = |at: usize| u16::from_le_bytes([ix_data[base + at], ix_data[base + at + 1]]) as usize;
+
+        // Parse offsets and instruction indices (all little-endian u16)
+        let sig_offset = read_u16(0);
+        let sig_ix_idx = read_u16(2);
+        let pubkey_offset = read_u16(4);
+        let pubkey_ix_idx = read_u16(6);
+        let msg_offset = read_u16(8);
+        let msg_size = read_u16(10);
+        let msg_ix_idx = read_u16(12);
+
+        // Verify all instruction indices point to the current instruction
+        // (u16::MAX), so no data is read from other instructions
+        require!(
+            sig_ix_idx == INSTRUCTION_INDEX_CURRENT
+                && pubkey_ix_idx == INSTRUCTION_INDEX_CURRENT
+                && msg_ix_idx == INSTRUCTION_INDEX_CURRENT,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify all offsets point beyond the header (into the data region)
+        require!(
+            sig_offset >= header_len
+                && pubkey_offset >= header_len
+                && msg_offset >= header_len,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Bounds checks for signature, pubkey, and message slices
+        require!(
+            ix_data.len() >= sig_offset + SIG_LEN,
+            PredicateRegistryError::InvalidSignature
+        );
+        require!(
+            ix_data.len() >= pubkey_offset + PUBKEY_LEN,
+            PredicateRegistryError::InvalidSignature
+        );
+        require!(
+            ix_data.len() >= msg_offset + msg_size,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify message size matches our expected hash size (32 bytes)
+        require!(
+            msg_size == 32,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify that the signature matches what we expect
+        require!(
+            &ix_data[sig_offset..sig_offset + SIG_LEN] == signature,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify that the compressed public key has the attester's
+        // x-coordinate, with either parity prefix
+        let pubkey_slice = &ix_data[pubkey_offset..pubkey_offset + PUBKEY_LEN];
+        require!(
+            matches!(pubkey_slice[0], 0x02 | 0x03) && &pubkey_slice[1..] == key,
+            PredicateRegistryError::InvalidSignature
+        );
+
+        // Verify that the message matches what we expect
+        require!(
+            &ix_data[msg_offset..msg_offset + msg_size] == message,
+            PredicateRegistryError::InvalidSignature
+        );
+    }
+
+    // The secp256r1 program has already verified each signature against its
+    // public key (or the transaction would have failed)
     Ok(())
 }
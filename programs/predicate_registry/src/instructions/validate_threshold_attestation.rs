@@ -0,0 +1,194 @@
+//! Validate threshold attestation instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{
+    ValidateThresholdAttestation, verify_attester_eligibility, verify_attester_signatures,
+};
+use crate::state::{
+    Statement, Attestation, AttesterAccount, AttesterKeyType, AttesterScopeAccount, ThresholdAttestation,
+    MAX_ATTESTER_QUORUM,
+};
+use crate::events::{ThresholdStatementValidated, UuidMarkedUsed};
+use crate::errors::PredicateRegistryError;
+
+/// Validate a statement signed by a quorum of distinct attesters
+/// 
+/// The Statement is constructed exactly as in `validate_attestation`; every
+/// attester signs the same statement hash, and all signatures are checked
+/// against a single multi-signature Ed25519 instruction.
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `target` - The program being called
+/// * `msg_value` - The value being transferred (typically 0 on Solana)
+/// * `encoded_sig_and_args` - The encoded function signature and arguments
+/// * `attestation` - The shared uuid, expiration and issued-at time, and each attester's signature
+/// 
+/// # Returns
+/// * `Result<bool>` - True if validation succeeds
+/// 
+/// # Security
+/// - Remaining accounts hold each signer's attester PDA, in signature order,
+///   immediately followed by its scope PDA when the attester is scoped
+/// - PDAs are re-derived here, as remaining accounts are not checked by Anchor
+/// - Attesters are counted by identity, so two keys of a rotated attester
+///   cannot satisfy the quorum together
+pub fn validate_threshold_attestation<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ValidateThresholdAttestation<'info>>,
+    target: Pubkey,
+    msg_value: u64,
+    encoded_sig_and_args: Vec<u8>,
+    attestation: ThresholdAttestation,
+) -> Result<bool> {
+    let ThresholdAttestation { uuid, expiration, issued_at, signatures } = attestation;
+    let registry = &ctx.accounts.registry;
+    let policy_account = &ctx.accounts.policy_account;
+    let signer = &ctx.accounts.signer;
+
+    let statement = Statement {
+        uuid,
+        msg_sender: signer.key(),
+        target,
+        msg_value,
+        encoded_sig_and_args,
+        policy_id: policy_account.policy_id.clone(),
+        expiration,
+        issued_at,
+    };
+
+    // Get current timestamp with error handling
+    let clock = Clock::get().map_err(|_| PredicateRegistryError::ClockError)?;
+    let current_timestamp = clock.unix_timestamp;
+
+    // === INPUT VALIDATION ===
+
+    // A policy without a quorum still needs at least one signature
+    let quorum = policy_account.attester_quorum.max(1);
+    require!(
+        signatures.len() >= quorum as usize && signatures.len() <= MAX_ATTESTER_QUORUM as usize,
+        PredicateRegistryError::AttesterQuorumNotMet
+    );
+
+    // The first signature is recorded as the statement's attestation, the rest as co-signers
+    let attestation = Attestation {
+        uuid,
+        attester: signatures[0].attester,
+        signature: signatures[0].signature,
+        expiration,
+        issued_at,
+    };
+
+    // === BUSINESS LOGIC VALIDATION ===
+
+    // Check if the statement has expired (with the registry's buffer for clock drift)
+    require!(
+        attestation.is_valid_at(current_timestamp, registry.clock_drift_buffer),
+        PredicateRegistryError::StatementExpired
+    );
+
+    // Reject far-future expirations so a leaked attestation cannot live forever
+    require!(
+        !attestation.exceeds_lifetime(current_timestamp, registry.max_attestation_lifetime),
+        PredicateRegistryError::AttestationLifetimeExceeded
+    );
+
+    // Reject issue times in the future or too long before expiration
+    require!(
+        attestation.has_valid_issued_at(
+            current_timestamp,
+            registry.clock_drift_buffer,
+            registry.max_attestation_lifetime
+        ),
+        PredicateRegistryError::InvalidIssuedAt
+    );
+
+    // Verify each signer is an eligible attester with a distinct identity
+    let registry_key = registry.key();
+    let namespace = registry.namespace_seed(&registry_key);
+    let mut remaining = ctx.remaining_accounts.iter();
+    let mut identities: Vec<Pubkey> = Vec::with_capacity(signatures.len());
+    let mut signers: Vec<(AttesterKeyType, Pubkey, [u8; 64])> = Vec::with_capacity(signatures.len());
+    for signature in &signatures {
+        let attester_info = remaining.next().ok_or(PredicateRegistryError::InvalidAccountData)?;
+        let (attester_pda, _) = Pubkey::find_program_address(
+            &[b"attester", namespace.as_ref(), signature.attester.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(attester_info.key(), attester_pda, PredicateRegistryError::WrongAttester);
+        let attester_account = Account::<AttesterAccount>::try_from(attester_info)?;
+
+        let scope_account = if attester_account.scoped {
+            let scope_info = remaining.next().ok_or(PredicateRegistryError::AttesterScopeRequired)?;
+            let (scope_pda, _) = Pubkey::find_program_address(
+                &[b"attester_scope", namespace.as_ref(), attester_account.identity.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(scope_info.key(), scope_pda, PredicateRegistryError::AttesterScopeRequired);
+            Some(Account::<AttesterScopeAccount>::try_from(scope_info)?)
+        } else {
+            None
+        };
+
+        verify_attester_eligibility(
+            registry,
+            &attester_account,
+            scope_account.as_deref(),
+            &statement,
+            policy_account.digest_scheme,
+            current_timestamp,
+        )?;
+
+        require!(
+            !identities.contains(&attester_account.identity),
+            PredicateRegistryError::DuplicateAttester
+        );
+        identities.push(attester_account.identity);
+        signers.push((attester_account.key_type, signature.attester, signature.signature));
+    }
+    require!(remaining.next().is_none(), PredicateRegistryError::InvalidAccountData);
+
+    // === SIGNATURE VERIFICATION ===
+
+    // Every attester signs the same statement hash, under the policy's digest scheme
+    let message_hash = statement.digest(policy_account.digest_scheme);
+    verify_attester_signatures(&signers, &message_hash, &ctx.accounts.instructions_sysvar)?;
+
+    // === REPLAY PROTECTION: Mark statement as used ===
+    // The `init` constraint on used_uuid_account fails if the UUID was already used
+    let used_uuid_account = &mut ctx.accounts.used_uuid_account;
+    used_uuid_account.attestation = attestation;
+    used_uuid_account.used_at = current_timestamp;
+    used_uuid_account.signer = signer.key();
+    used_uuid_account.co_signers = signatures[1..].iter().map(|signature| signature.attester).collect();
+
+    // Emit UUID marked as used event
+    emit!(UuidMarkedUsed {
+        uuid: statement.format_uuid(),
+        signer: signer.key(),
+        expires_at: expiration,
+        timestamp: current_timestamp,
+    });
+
+    let attesters: Vec<Pubkey> = signatures.iter().map(|signature| signature.attester).collect();
+    emit!(ThresholdStatementValidated {
+        registry: registry_key,
+        msg_sender: statement.msg_sender,
+        target: statement.target,
+        attesters,
+        quorum,
+        msg_value: statement.msg_value,
+        policy_id: statement.policy_id.clone(),
+        uuid: statement.format_uuid(),
+        expiration: statement.expiration,
+        timestamp: current_timestamp,
+    });
+
+    msg!(
+        "Statement {} validated by {} attesters for client {}",
+        statement.format_uuid(),
+        signatures.len(),
+        statement.msg_sender
+    );
+
+    Ok(true)
+}
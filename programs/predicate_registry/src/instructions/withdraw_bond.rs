@@ -0,0 +1,51 @@
+//! Withdraw bond instruction for the predicate registry program
+
+use anchor_lang::prelude::*;
+use crate::instructions::{WithdrawBond, verify_attester_or_owner};
+use crate::events::AttesterBondWithdrawn;
+use crate::errors::PredicateRegistryError;
+
+/// Withdraw an exited attester's bond once its unbonding period has elapsed
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing accounts
+/// * `attester` - The public key of the exited attester
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Security
+/// * Only the exited attester itself or the registry owner (single-authority
+///   mode) can withdraw, and neither chooses where the bond goes
+/// * The bond vault and attester accounts are closed by their
+///   `close = recipient` constraints, returning the bond and all rent to
+///   `AttesterAccount::bond_recipient`; the key may then register again
+pub fn withdraw_bond(ctx: Context<WithdrawBond>, attester: Pubkey) -> Result<()> {
+    verify_attester_or_owner(&ctx.accounts.registry, &ctx.accounts.caller.key(), &attester)?;
+
+    let bond_account = &ctx.accounts.bond_account;
+    let recipient = ctx.accounts.recipient.key();
+    let clock = Clock::get()?;
+
+    require!(
+        bond_account.is_withdrawable_at(clock.unix_timestamp),
+        PredicateRegistryError::BondNotWithdrawable
+    );
+
+    emit!(AttesterBondWithdrawn {
+        registry: ctx.accounts.registry.key(),
+        attester,
+        amount: bond_account.amount,
+        recipient,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Bond of {} lamports for attester {} withdrawn to {}",
+        bond_account.amount,
+        attester,
+        recipient
+    );
+    
+    Ok(())
+}
@@ -5,15 +5,31 @@
 //! and validate statements with cryptographic attestations.
 //!
 //! ## Features
-//! - Attester registration and management
+//! - Multiple independent registries per deployment
+//! - Attester registration and management, singly or in batches, with optional descriptive metadata
+//! - Ethereum-key (secp256k1) and P-256 (secp256r1) attesters alongside Solana Ed25519 attesters
+//! - Optional per-policy Keccak-256 statement digest, compatible with the EVM contracts
+//! - On-chain paged attester index, enumerable via return data
+//! - Optional permissionless attester registration backed by a lamport bond
+//! - Attester collateral requirements with slashing backed by used UUID evidence
+//! - Attester validity windows and key rotation under a stable identity
+//! - Attester suspension and reinstatement without closing the account
+//! - Compromise revocation rejecting attestations issued after a cutoff
+//! - Attester scopes restricting which client programs and policies an attester may sign for
 //! - Client policy management
-//! - Statement validation with cryptographic attestations
-//! - Authority management and transfer
+//! - Statement validation with cryptographic attestations, optionally by an M-of-N attester quorum
+//! - Two-step authority transfer and optional renouncement
+//! - Optional M-of-N multisig administration via proposals
+//! - Optional timelock on admin actions
+//! - Emergency pause of attestation validation, globally or per client program
+//! - Role-based access control for attester managers, pausers and fee managers
+//! - Versioned account layouts with in-place migration
 //!
 //! ## Security
 //! - All operations require proper authorization
 //! - Signature verification ensures attestation authenticity
 //! - Expiration timestamps prevent stale attestations
+//! - Configurable clock drift buffer and maximum attestation lifetime
 
 // Suppress warnings from Anchor's internal behavior
 // These are framework-level warnings, not from our code
@@ -28,6 +44,7 @@ pub mod instructions;
 pub mod state;
 pub mod errors;
 pub mod events;
+pub mod migration;
 
 // Re-export for easier access
 pub use instructions::*;
@@ -45,47 +62,377 @@ pub mod predicate_registry {
 
     /// Initialize a new predicate registry
     /// 
-    /// Creates the main registry account with the specified authority.
-    /// Only needs to be called once per deployment.
+    /// Creates a registry account with the specified authority, namespaced
+    /// by `registry_id`. Attesters, policies, used UUIDs and admin accounts
+    /// are all scoped under the registry key, so several independent
+    /// registries can share one deployment. Registry 0 and its accounts keep
+    /// the original un-namespaced addresses, so a registry created before
+    /// namespacing is registry 0 once migrated. Only the registry program's
+    /// upgrade authority can create registries.
     /// 
     /// # Arguments
     /// * `ctx` - The instruction context containing accounts
+    /// * `registry_id` - Namespace identifier for the new registry
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error
     /// 
     /// # Events
     /// * `RegistryInitialized` - Emitted when registry is successfully initialized
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        instructions::initialize(ctx)
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If signer is not the program's upgrade authority
+    /// * `InvalidProgramData` - If program data account is invalid
+    pub fn initialize(ctx: Context<Initialize>, registry_id: u64) -> Result<()> {
+        instructions::initialize(ctx, registry_id)
     }
 
     /// Register a new attester
     /// 
-    /// Allows the registry authority to register a new attester who can
-    /// provide attestations for statement validation.
+    /// Allows the registry owner or any holder of the `AttesterManager` role
+    /// to register a new attester who can provide attestations for statement
+    /// validation. The attester starts unscoped; restrict it with
+    /// `set_attester_scope`. In multisig or timelock mode, registration goes
+    /// through a proposal or queued operation instead.
     /// 
     /// # Arguments
     /// * `ctx` - The instruction context containing accounts
     /// * `attester` - The public key of the attester to register
+    /// * `metadata` - Optional name, URL, contact and description for the attester
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error
     /// 
     /// # Events
     /// * `AttesterRegistered` - Emitted when attester is successfully registered
+    /// * `AttesterMetadataUpdated` - Emitted when metadata is given
+    /// * `AttesterIndexed` - Emitted when the attester is listed in the attester index
     /// 
     /// # Errors
     /// * `AttesterAlreadyRegistered` - If attester is already registered
-    /// * `Unauthorized` - If caller is not the registry authority
-    pub fn register_attester(ctx: Context<RegisterAttester>, attester: Pubkey) -> Result<()> {
-        instructions::register_attester(ctx, attester)
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    /// * `AttesterMetadataMismatch` - If metadata and its account are not given together
+    /// * `InvalidAttesterMetadata` - If a metadata field is empty or too long
+    pub fn register_attester(
+        ctx: Context<RegisterAttester>,
+        attester: Pubkey,
+        metadata: Option<AttesterMetadata>,
+    ) -> Result<()> {
+        instructions::register_attester(ctx, attester, metadata)
+    }
+
+    /// Register a new attester holding an Ethereum (secp256k1) key
+    /// 
+    /// Lets existing EVM attester keys sign Solana statements. The attester
+    /// is identified by its 20-byte address left-padded with zeros to 32
+    /// bytes; attestations name that padded key as `attester` and carry the
+    /// 64-byte signature without its recovery id. Validation relies on a
+    /// secp256k1 program instruction, which recovers the signer of the
+    /// keccak256 of the statement hash. In multisig or timelock mode, the
+    /// owner uses a `RegisterSecp256k1Attester` proposal or queued operation
+    /// instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `eth_address` - The attester's 20-byte Ethereum address
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterRegistered` - Emitted with the padded key when the attester is registered
+    /// * `AttesterIndexed` - Emitted when the attester is listed in the attester index
+    /// 
+    /// # Errors
+    /// * `InvalidAuthority` - If the address is zero
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    pub fn register_secp256k1_attester(
+        ctx: Context<RegisterSecp256k1Attester>,
+        eth_address: [u8; 20],
+    ) -> Result<()> {
+        instructions::register_secp256k1_attester(ctx, eth_address)
+    }
+
+    /// Register a new attester holding a P-256 (secp256r1) key
+    /// 
+    /// For attester keys held in HSMs or cloud KMS that only issue P-256
+    /// keys. The attester is identified by the 32-byte x-coordinate of its
+    /// public key; attestations name that as `attester` and carry the 64-byte
+    /// `r || s` signature in low-S form. Validation relies on a secp256r1
+    /// program instruction, which verifies an ECDSA signature over the
+    /// SHA-256 of the statement hash. Registration only checks that the
+    /// x-coordinate is a field element; the secp256r1 program is the only
+    /// check that the point is on the curve, and an off-curve key fails
+    /// every verification. In multisig or timelock mode, the owner uses a
+    /// `RegisterSecp256r1Attester` proposal or queued operation instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `public_key` - The attester's 33-byte SEC1 compressed public key
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterRegistered` - Emitted with the x-coordinate key when the attester is registered
+    /// * `AttesterIndexed` - Emitted when the attester is listed in the attester index
+    /// 
+    /// # Errors
+    /// * `InvalidAuthority` - If the public key is not compressed or its x-coordinate is zero or not below the field prime
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    pub fn register_secp256r1_attester(
+        ctx: Context<RegisterSecp256r1Attester>,
+        public_key: [u8; 33],
+    ) -> Result<()> {
+        instructions::register_secp256r1_attester(ctx, public_key)
+    }
+
+    /// Rotate an attester to a new key
+    /// 
+    /// Registers `new_key` under the same stable identity as `attester`,
+    /// keeping the original `registered_at` and validity window, and links the two
+    /// accounts. The old key remains accepted for `overlap` seconds (or until
+    /// its existing end date, if sooner) so in-flight attestations still
+    /// validate. The attester's bond moves to the new key; its vault is keyed
+    /// by identity and stays put. Both keys are listed in the attester index
+    /// until the old one is deregistered. The new key keeps the attester's key
+    /// type, so Ethereum and P-256 attesters rotate to another Ethereum address
+    /// or P-256 key, given as their attester key. In multisig or timelock mode,
+    /// the owner uses a `RotateAttesterKey` proposal or queued operation instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The key being replaced
+    /// * `new_key` - The replacement key, in the attester's key format (`secp256k1_key` or `secp256r1_key` for Ethereum and P-256 attesters)
+    /// * `overlap` - Seconds during which both keys are accepted (max 30 days)
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterKeyRotated` - Emitted when the key is rotated
+    /// * `AttesterIndexed` - Emitted when the new key is listed in the attester index
+    /// 
+    /// # Errors
+    /// * `AttesterNotRegistered` - If the old key is not registered
+    /// * `AttesterAlreadyRotated` - If the old key has already been rotated
+    /// * `InvalidRotationKey` - If an Ethereum attester's new key is not a padded Ethereum address
+    /// * `InvalidRotationOverlap` - If the overlap is out of range
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    pub fn rotate_attester_key(
+        ctx: Context<RotateAttesterKey>,
+        attester: Pubkey,
+        new_key: Pubkey,
+        overlap: i64,
+    ) -> Result<()> {
+        instructions::rotate_attester_key(ctx, attester, new_key, overlap)
+    }
+
+    /// Set a registered attester's validity window
+    /// 
+    /// `validate_attestation` rejects the attester's attestations before
+    /// `valid_from` and after `valid_until`, so planned key rotations and end
+    /// dates take effect without an admin transaction at that moment. Either
+    /// bound may be 0 for no limit. In multisig or timelock mode, the owner
+    /// uses a `SetAttesterValidity` proposal or queued operation instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the attester
+    /// * `valid_from` - Earliest accepted timestamp (0 = no lower bound)
+    /// * `valid_until` - Latest accepted timestamp (0 = no end date)
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterValiditySet` - Emitted when the window is set
+    /// 
+    /// # Errors
+    /// * `AttesterNotRegistered` - If the attester is not registered
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    /// * `InvalidAttesterValidity` - If a bound is negative or the window is inverted
+    pub fn set_attester_validity(
+        ctx: Context<SetAttesterValidity>,
+        attester: Pubkey,
+        valid_from: i64,
+        valid_until: i64,
+    ) -> Result<()> {
+        instructions::set_attester_validity(ctx, attester, valid_from, valid_until)
+    }
+
+    /// Suspend a registered attester without closing its account
+    /// 
+    /// `validate_attestation` rejects a suspended attester's attestations,
+    /// but its account, history, scope and bond are kept so it can be
+    /// reinstated with `reinstate_attester`. Suspended attesters still count
+    /// towards the registry's attester total. In multisig or timelock mode,
+    /// the owner uses a `SuspendAttester` proposal or queued operation instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the attester to suspend
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterSuspended` - Emitted when the attester is suspended
+    /// 
+    /// # Errors
+    /// * `AttesterNotRegistered` - If the attester is not registered (or already suspended)
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    pub fn suspend_attester(ctx: Context<SuspendAttester>, attester: Pubkey) -> Result<()> {
+        instructions::suspend_attester(ctx, attester)
+    }
+
+    /// Reinstate a suspended attester
+    /// 
+    /// Restores the attester's registration, keeping its original
+    /// registration time. In multisig or timelock mode, the owner uses a
+    /// `ReinstateAttester` proposal or queued operation instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the attester to reinstate
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterReinstated` - Emitted when the attester is reinstated
+    /// 
+    /// # Errors
+    /// * `AttesterNotSuspended` - If the attester is not suspended
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    pub fn reinstate_attester(ctx: Context<ReinstateAttester>, attester: Pubkey) -> Result<()> {
+        instructions::reinstate_attester(ctx, attester)
+    }
+
+    /// Reject an attester key's attestations issued after a cutoff
+    /// 
+    /// For leaked keys: `validate_attestation` rejects any attestation from
+    /// this key whose `issued_at` is after `timestamp`, while attestations
+    /// issued earlier remain valid until they expire. The cutoff is only
+    /// enforceable if `issued_at` is signed, so once it is set the key's
+    /// attestations are only accepted under the `Sha256WithIssuedAt` digest
+    /// scheme. A leaked key can still backdate `issued_at`, but attestations
+    /// may not outlive their issue time by more than the registry's maximum
+    /// lifetime, so backdated ones expire within one lifetime of the cutoff.
+    /// The cutoff can be tightened but never lifted. In multisig or timelock mode, the owner uses a
+    /// `RevokeAttesterAfter` proposal or queued operation instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the compromised attester
+    /// * `timestamp` - Attestations issued after this time are rejected
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterRevoked` - Emitted when the cutoff is set
+    /// 
+    /// # Errors
+    /// * `InvalidRevocationCutoff` - If the cutoff is not positive or later than an existing cutoff
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    pub fn revoke_attester_after(
+        ctx: Context<RevokeAttesterAfter>,
+        attester: Pubkey,
+        timestamp: i64,
+    ) -> Result<()> {
+        instructions::revoke_attester_after(ctx, attester, timestamp)
+    }
+
+    /// Set the client programs and policies a registered attester may sign for
+    /// 
+    /// Once scoped, `validate_attestation` only accepts the attester's
+    /// attestations for targets in `scope.programs` or policies whose ID
+    /// starts with one of `scope.policy_prefixes`, and requires the scope
+    /// account to be passed. The scope is stored per attester identity, so
+    /// keys rotated from a scoped key stay scoped. An empty scope lifts the
+    /// restriction. The scope account is created on first use, paid for by
+    /// the signer.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the attester
+    /// * `scope` - The new scope (empty = unrestricted)
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterScopeSet` - Emitted when the scope is set
+    /// 
+    /// # Errors
+    /// * `AttesterNotRegistered` - If the attester is not registered
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires an attester manager instead
+    /// * `TimelockEnabled` - If the timelock requires an attester manager instead
+    /// * `InvalidAttesterScope` - If a list is too long, has duplicates or an empty prefix
+    pub fn set_attester_scope(
+        ctx: Context<SetAttesterScope>,
+        attester: Pubkey,
+        scope: AttesterScope,
+    ) -> Result<()> {
+        instructions::set_attester_scope(ctx, attester, scope)
+    }
+
+    /// Update a registered attester's metadata
+    /// 
+    /// Replaces the attester's name, URL, contact and description; unset
+    /// fields are cleared. The attester itself may update its own metadata,
+    /// as may the owner (single-authority mode) or an attester manager. The
+    /// metadata account is created on first use, paid for by the signer.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the attester being described
+    /// * `metadata` - The new metadata
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterMetadataUpdated` - Emitted when the metadata is set
+    /// 
+    /// # Errors
+    /// * `AttesterNotRegistered` - If the attester is not registered
+    /// * `Unauthorized` - If caller is not the attester, the owner or an attester manager
+    /// * `InvalidAttesterMetadata` - If a metadata field is empty or too long
+    pub fn update_attester_metadata(
+        ctx: Context<UpdateAttesterMetadata>,
+        attester: Pubkey,
+        metadata: AttesterMetadata,
+    ) -> Result<()> {
+        instructions::update_attester_metadata(ctx, attester, metadata)
     }
 
     /// Deregister an existing attester
     /// 
-    /// Allows the registry authority to deregister an attester, preventing
-    /// them from providing new attestations.
+    /// Allows the registry owner or any holder of the `AttesterManager` role
+    /// to deregister an attester, preventing them from providing new
+    /// attestations. The attester's metadata and scope PDAs must always be
+    /// passed and are closed with it where they exist, so a later
+    /// registration of the key cannot inherit them; the scope is kept if the
+    /// key was rotated away.
     /// 
     /// # Arguments
     /// * `ctx` - The instruction context containing accounts
@@ -96,14 +443,138 @@ pub mod predicate_registry {
     /// 
     /// # Events
     /// * `AttesterDeregistered` - Emitted when attester is successfully deregistered
+    /// * `AttesterUnindexed` - Emitted when the attester is removed from the attester index
     /// 
     /// # Errors
     /// * `AttesterNotRegistered` - If attester is not currently registered
-    /// * `Unauthorized` - If caller is not the registry authority
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    /// * `AttesterBonded` - If the attester holds a bond and must exit via `begin_unbonding`
+    /// * `AttesterNotIndexed` - If the attester predates the index and must first be listed with `index_attester`
+    /// * `AttesterIndexMismatch` - If the index pages do not list the attester or are not the last page
     pub fn deregister_attester(ctx: Context<DeregisterAttester>, attester: Pubkey) -> Result<()> {
         instructions::deregister_attester(ctx, attester)
     }
 
+    /// Register a batch of attesters in one instruction
+    /// 
+    /// For onboarding attester clusters. Each key's attester PDA is passed as
+    /// a writable remaining account, in the same order as `attesters`, and
+    /// is created and paid for by the signer. All-or-nothing. Like attesters
+    /// registered one at a time, the new attesters start unscoped and are
+    /// listed in the attester index, on its tail page and, when the batch
+    /// overflows it, the next page.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attesters` - The public keys of the attesters to register (at most 16)
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterIndexed` - Emitted for each registered attester
+    /// * `AttesterRegistered` - Emitted for each registered attester
+    /// 
+    /// # Errors
+    /// * `InvalidAttesterBatch` - If the batch is empty, too large, or repeats or zeroes a key
+    /// * `InvalidAccountData` - If the remaining accounts do not match the attester PDAs
+    /// * `AttesterAlreadyRegistered` - If an attester is already registered
+    /// * `AttesterIndexMismatch` - If the batch overflows the tail page and the next page is missing
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires per-attester proposals instead
+    /// * `TimelockEnabled` - If the timelock requires per-attester queued operations instead
+    pub fn register_attesters<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RegisterAttesters<'info>>,
+        attesters: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::register_attesters(ctx, attesters)
+    }
+
+    /// Deregister a batch of attesters in one instruction
+    /// 
+    /// Each key's attester, metadata and scope PDAs are passed as writable
+    /// remaining accounts, in the same order as `attesters`, and are closed
+    /// with their rent returned to the signer, exactly as by
+    /// `deregister_attester` (metadata and scope PDAs that were never created
+    /// are passed all the same). The attester index pages the removals touch
+    /// follow them. All-or-nothing.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attesters` - The public keys of the attesters to deregister (at most 16)
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterUnindexed` - Emitted for each deregistered attester
+    /// * `AttesterDeregistered` - Emitted for each deregistered attester
+    /// 
+    /// # Errors
+    /// * `InvalidAttesterBatch` - If the batch is empty, too large, or repeats or zeroes a key
+    /// * `InvalidAccountData` - If the remaining accounts do not match the attester, metadata and scope PDAs
+    /// * `AttesterNotRegistered` - If an attester is not registered
+    /// * `AttesterBonded` - If an attester holds a bond and must exit via `begin_unbonding`
+    /// * `AttesterNotIndexed` - If an attester predates the index and must first be listed with `index_attester`
+    /// * `AttesterIndexMismatch` - If the index pages are missing, repeated or from another registry
+    /// * `Unauthorized` - If caller is neither the owner nor an attester manager
+    /// * `MultisigEnabled` - If multisig mode requires per-attester proposals instead
+    /// * `TimelockEnabled` - If the timelock requires per-attester queued operations instead
+    pub fn deregister_attesters<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeregisterAttesters<'info>>,
+        attesters: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::deregister_attesters(ctx, attesters)
+    }
+
+    /// List a legacy attester in the attester index
+    /// 
+    /// Every registration path lists its attester, so this backfills
+    /// attesters registered before the index existed, which must be listed
+    /// before they can be deregistered. Restricted to the registry
+    /// authority, which funds a new index page when the last one is full.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the attester to list
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterIndexed` - Emitted when the attester is listed
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the registry authority
+    /// * `AttesterNotRegistered` - If the attester is neither registered nor suspended
+    /// * `AttesterAlreadyIndexed` - If the attester is already listed
+    pub fn index_attester(ctx: Context<IndexAttester>, attester: Pubkey) -> Result<()> {
+        instructions::index_attester(ctx, attester)
+    }
+
+    /// Get one page of the attester index (view function)
+    /// 
+    /// Returns up to `ATTESTER_INDEX_PAGE_SIZE` attester keys as return
+    /// data, for CPI callers and light clients enumerating the attester set
+    /// without scanning program accounts. Pages run from 0 to
+    /// `attester_index_len / ATTESTER_INDEX_PAGE_SIZE`, in no particular
+    /// order.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `page` - The index page to read
+    /// 
+    /// # Returns
+    /// * `Result<Vec<Pubkey>>` - The attesters listed on the page
+    pub fn get_registered_attesters(
+        ctx: Context<GetRegisteredAttesters>,
+        page: u32,
+    ) -> Result<Vec<Pubkey>> {
+        instructions::get_registered_attesters(ctx, page)
+    }
+
     /// Set a policy ID for a client program
     /// 
     /// Creates a policy for a PROGRAM (not a user). Only the program's upgrade
@@ -173,12 +644,17 @@ pub mod predicate_registry {
     /// - `policy_id`: Derived from the validated policy_account PDA - cannot be faked
     /// - Other fields: Provided by caller but validated via signature verification
     /// 
+    /// The signature is checked against an Ed25519 program instruction, a
+    /// secp256k1 program instruction for Ethereum-key attesters, or a
+    /// secp256r1 program instruction for P-256 attesters, placed immediately
+    /// before this instruction.
+    /// 
     /// # Arguments
     /// * `ctx` - The instruction context containing accounts
     /// * `target` - The program being called (e.g., counter program ID)
     /// * `msg_value` - The value being transferred (typically 0 on Solana)
     /// * `encoded_sig_and_args` - The encoded function signature and arguments
-    /// * `attestation` - The attestation containing uuid, expiration, signature, and attester
+    /// * `attestation` - The attestation containing uuid, expiration, issued-at time, signature, and attester
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error
@@ -188,8 +664,21 @@ pub mod predicate_registry {
     /// * `UuidMarkedUsed` - Emitted when UUID is marked as used
     /// 
     /// # Errors
+    /// * `RegistryPaused` - If the registry is paused
+    /// * `PolicyPaused` - If the target program's policy is paused
+    /// * `AttesterSuspended` - If attester is suspended
     /// * `AttesterNotRegisteredForValidation` - If attester is not registered
-    /// * `StatementExpired` - If statement has expired
+    /// * `StatementExpired` - If statement has expired (past the registry's drift buffer)
+    /// * `AttestationLifetimeExceeded` - If expiration is beyond the registry's maximum lifetime
+    /// * `InvalidIssuedAt` - If the attestation is issued in the future, after it expires, or more than the maximum lifetime before it
+    /// * `AttesterNotYetValid` - If the attester's validity window has not started
+    /// * `AttesterValidityEnded` - If the attester's validity window has ended
+    /// * `AttestationRevoked` - If the attestation was issued after the attester's revocation cutoff
+    /// * `IssuedAtNotSigned` - If the attester has a revocation cutoff and the policy's digest does not sign `issued_at`
+    /// * `AttesterScopeRequired` - If the attester is scoped and its scope account is missing
+    /// * `AttesterOutOfScope` - If a scoped attester signs for a program or policy outside its scope
+    /// * `InsufficientCollateral` - If the attester's bond is below the registry's minimum collateral
+    /// * `AttesterQuorumRequired` - If the target's policy requires a threshold validation
     /// * `AttestationExpired` - If attestation has expired
     /// * `InvalidSignature` - If attestation signature is invalid
     /// * `WrongAttester` - If signature doesn't match provided attester
@@ -211,6 +700,65 @@ pub mod predicate_registry {
         ).map(|_| ())
     }
 
+    /// Validate a statement signed by a quorum of distinct attesters
+    /// 
+    /// Builds the Statement exactly as `validate_attestation` does, then
+    /// requires at least the target policy's `attester_quorum` signatures
+    /// over it, from distinct registered attester identities, all carried by
+    /// one multi-signature Ed25519 instruction immediately before this one.
+    /// Pass each signer's attester account as a remaining account, in
+    /// signature order, followed by its scope account when it is scoped.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `target` - The program being called (e.g., counter program ID)
+    /// * `msg_value` - The value being transferred (typically 0 on Solana)
+    /// * `encoded_sig_and_args` - The encoded function signature and arguments
+    /// * `attestation` - The shared uuid, expiration and issued-at time, and each attester's signature
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `ThresholdStatementValidated` - Emitted when the statement is validated
+    /// * `UuidMarkedUsed` - Emitted when UUID is marked as used
+    /// 
+    /// # Errors
+    /// * `RegistryPaused` - If the registry is paused
+    /// * `PolicyPaused` - If the target program's policy is paused
+    /// * `AttesterQuorumNotMet` - If there are fewer signatures than the quorum, or more than 4
+    /// * `StatementExpired` - If statement has expired (past the registry's drift buffer)
+    /// * `AttestationLifetimeExceeded` - If expiration is beyond the registry's maximum lifetime
+    /// * `InvalidIssuedAt` - If the attestation is issued in the future, after it expires, or more than the maximum lifetime before it
+    /// * `WrongAttester` - If a remaining account is not the signer's attester PDA
+    /// * `AttesterSuspended` - If an attester is suspended
+    /// * `AttesterNotRegisteredForValidation` - If an attester is not registered
+    /// * `AttesterNotYetValid` - If an attester's validity window has not started
+    /// * `AttesterValidityEnded` - If an attester's validity window has ended
+    /// * `AttestationRevoked` - If the statement was issued after an attester's revocation cutoff
+    /// * `IssuedAtNotSigned` - If an attester has a revocation cutoff and the policy's digest does not sign `issued_at`
+    /// * `AttesterScopeRequired` - If a scoped attester's scope account is missing
+    /// * `AttesterOutOfScope` - If a scoped attester signs outside its scope
+    /// * `InsufficientCollateral` - If an attester's bond is below the registry's minimum collateral
+    /// * `DuplicateAttester` - If two signatures share an attester identity
+    /// * `InvalidAccountData` - If remaining accounts are missing or left over
+    /// * `InvalidSignature` - If the Ed25519 instruction does not match the signatures
+    pub fn validate_threshold_attestation<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ValidateThresholdAttestation<'info>>,
+        target: Pubkey,
+        msg_value: u64,
+        encoded_sig_and_args: Vec<u8>,
+        attestation: ThresholdAttestation,
+    ) -> Result<()> {
+        instructions::validate_threshold_attestation(
+            ctx,
+            target,
+            msg_value,
+            encoded_sig_and_args,
+            attestation
+        ).map(|_| ())
+    }
+
     /// Cleanup an expired UUID account to reclaim rent
     /// 
     /// Allows anyone to cleanup expired UUID accounts, returning the rent
@@ -229,14 +777,39 @@ pub mod predicate_registry {
         instructions::cleanup_expired_uuid(ctx)
     }
 
-    /// Transfer registry authority to a new account
+    /// Propose a new registry authority
+    /// 
+    /// First step of a two-step authority transfer. The current authority
+    /// nominates a new key, which only takes effect once that key signs
+    /// `accept_authority`. Proposing again replaces the pending key.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `new_authority` - The public key of the proposed new authority
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AuthorityTransferProposed` - Emitted when the new authority is proposed
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the current authority
+    /// * `InvalidAuthority` - If the proposed authority is the zero address
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority(ctx, new_authority)
+    }
+
+    /// Accept a pending registry authority transfer
     /// 
-    /// Allows the current authority to transfer ownership of the registry
-    /// to a new account. This is irreversible.
+    /// Second step of a two-step authority transfer. Must be signed by the
+    /// proposed authority, proving the new key is controlled before it
+    /// replaces the current one.
     /// 
     /// # Arguments
     /// * `ctx` - The instruction context containing accounts
-    /// * `new_authority` - The public key of the new authority
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error
@@ -245,8 +818,729 @@ pub mod predicate_registry {
     /// * `AuthorityTransferred` - Emitted when authority is successfully transferred
     /// 
     /// # Errors
+    /// * `NoPendingAuthorityTransfer` - If no transfer has been proposed
+    /// * `NotPendingAuthority` - If signer is not the proposed authority
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority(ctx)
+    }
+
+    /// Cancel a pending registry authority transfer
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AuthorityTransferCancelled` - Emitted when the pending transfer is cancelled
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the current authority
+    /// * `NoPendingAuthorityTransfer` - If no transfer has been proposed
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::cancel_authority_transfer(ctx)
+    }
+
+    /// Permanently renounce the registry authority
+    /// 
+    /// Sets the authority to a sentinel no one can sign for and clears the
+    /// pending authority and guardian, so every admin path (including role
+    /// holders and pausing) fails from then on and the attester set is fixed.
+    /// Proposals and operations left over from multisig or timelock mode can
+    /// no longer be executed. Only available in single-authority mode and
+    /// while not paused.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `confirmation` - Must equal the registry address, to avoid accidents
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AuthorityRenounced` - Emitted when the authority is renounced
+    /// 
+    /// # Errors
     /// * `Unauthorized` - If caller is not the current authority
-    pub fn transfer_authority(ctx: Context<TransferAuthority>, new_authority: Pubkey) -> Result<()> {
-        instructions::transfer_authority(ctx, new_authority)
+    /// * `RenounceNotConfirmed` - If the confirmation is not the registry address
+    /// * `RegistryPaused` - If the registry is paused
+    /// * `MultisigEnabled` - If multisig mode is enabled
+    /// * `TimelockEnabled` - If the timelock is enabled
+    pub fn renounce_authority(ctx: Context<RenounceAuthority>, confirmation: Pubkey) -> Result<()> {
+        instructions::renounce_authority(ctx, confirmation)
+    }
+
+    /// Enable multisig mode for registry administration
+    /// 
+    /// Sets the multisig signer set and approval threshold. Once enabled,
+    /// `register_attester`, `deregister_attester`, `propose_authority` and
+    /// `cancel_authority_transfer` are rejected and the same actions must be
+    /// carried out through proposals. Can only be called by the authority
+    /// while multisig mode is disabled; afterwards the configuration is
+    /// changed through a `ConfigureMultisig` proposal.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `signers` - The multisig signer set (max 10, unique, non-zero)
+    /// * `threshold` - Approvals required to execute a proposal
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `MultisigConfigured` - Emitted when the signer set is configured
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the registry authority
+    /// * `MultisigEnabled` - If multisig mode is already enabled
+    /// * `InvalidMultisigConfig` - If the signer set or threshold is invalid
+    /// * `TooManyMultisigSigners` - If the signer set is too large
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    pub fn configure_multisig(
+        ctx: Context<ConfigureMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8
+    ) -> Result<()> {
+        instructions::configure_multisig(ctx, signers, threshold)
+    }
+
+    /// Create a multisig admin proposal
+    /// 
+    /// Creates a proposal account for the given action, recording the
+    /// proposer's approval.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `action` - The admin action to propose
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `ProposalCreated` - Emitted when the proposal is created
+    /// * `ProposalApproved` - Emitted for the proposer's implicit approval
+    /// 
+    /// # Errors
+    /// * `MultisigDisabled` - If multisig mode is not enabled
+    /// * `NotMultisigSigner` - If the proposer is not a multisig signer
+    /// * `InvalidAuthority` / `InvalidMultisigConfig` - If the action is malformed
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: AdminAction) -> Result<()> {
+        instructions::create_proposal(ctx, action)
+    }
+
+    /// Approve a multisig admin proposal
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `ProposalApproved` - Emitted when the approval is recorded
+    /// 
+    /// # Errors
+    /// * `MultisigDisabled` - If multisig mode is not enabled
+    /// * `NotMultisigSigner` - If the approver is not a multisig signer
+    /// * `AlreadyApproved` - If the approver has already approved
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal(ctx)
+    }
+
+    /// Execute a multisig admin proposal
+    /// 
+    /// Applies the proposal's action once it has at least `threshold`
+    /// approvals from current signers, then closes the proposal. Anyone may
+    /// execute; the executor pays for any accounts the action creates.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// When the timelock is enabled, the first call after the threshold is met
+    /// queues the proposal and a later call after its eta executes it.
+    /// 
+    /// # Events
+    /// * `ProposalExecuted` - Emitted when the proposal is executed
+    /// * `OperationQueued` - Emitted instead when the proposal is queued behind the timelock
+    /// * The event of the applied action (e.g. `AttesterRegistered`)
+    /// 
+    /// # Errors
+    /// * `AuthorityRenounced` - If the authority has been renounced
+    /// * `MultisigDisabled` - If multisig mode is not enabled
+    /// * `ThresholdNotMet` - If the proposal lacks approvals
+    /// * `TimelockNotExpired` - If the proposal's eta has not passed
+    /// * `ProposalAccountMismatch` - If attester accounts don't match the action
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal(ctx)
+    }
+
+    /// Enable the registry timelock
+    /// 
+    /// Sets the delay applied to admin actions. Once enabled,
+    /// `register_attester`, `deregister_attester`, `propose_authority`,
+    /// `cancel_authority_transfer` and `configure_multisig` are rejected and
+    /// the same actions must be queued with `queue_operation`. In multisig
+    /// mode, proposals are queued for the same delay when they reach their
+    /// threshold. Can only be called while the timelock is disabled; afterwards
+    /// the delay is changed through a queued `SetTimelockDelay` action.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `delay` - Delay in seconds (max 30 days)
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `TimelockDelaySet` - Emitted when the delay is set
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the registry authority
+    /// * `TimelockEnabled` - If the timelock is already enabled
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `InvalidTimelockDelay` - If the delay is out of range
+    pub fn set_timelock_delay(ctx: Context<SetTimelockDelay>, delay: i64) -> Result<()> {
+        instructions::set_timelock_delay(ctx, delay)
+    }
+
+    /// Set the clock drift buffer and maximum attestation lifetime
+    /// 
+    /// `validate_attestation` accepts an attestation until `expiration` plus
+    /// the drift buffer and rejects one whose `expiration` is more than the
+    /// maximum lifetime away; `cleanup_expired_uuid` uses the same drift
+    /// buffer. New registries start at 30 seconds and 7 days. In multisig or
+    /// timelock mode, use a `SetAttestationConfig` action instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `clock_drift_buffer` - Drift buffer in seconds (max 10 minutes)
+    /// * `max_attestation_lifetime` - Maximum lifetime in seconds (1 second to 365 days)
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttestationConfigSet` - Emitted when the config is set
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the registry authority
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    /// * `InvalidAttestationConfig` - If either value is out of range
+    pub fn set_attestation_config(
+        ctx: Context<SetAttestationConfig>,
+        clock_drift_buffer: i64,
+        max_attestation_lifetime: i64,
+    ) -> Result<()> {
+        instructions::set_attestation_config(ctx, clock_drift_buffer, max_attestation_lifetime)
+    }
+
+    /// Set the self-registration bond, unbonding period and minimum collateral
+    /// 
+    /// While the bond is non-zero, anyone can become an attester with
+    /// `register_with_bond`. Exiting attesters wait `unbonding_period` before
+    /// `withdraw_bond`. While the minimum collateral is non-zero, attestations
+    /// are only accepted from attesters holding at least that much in their
+    /// bond vault (see `post_collateral` and `slash_attester`). New registries
+    /// start with all three disabled. Callable by the owner or any holder of
+    /// the `FeeManager` role. In multisig or timelock mode, use a
+    /// `SetBondConfig` action instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester_bond` - Bond in lamports (0 = self-registration disabled)
+    /// * `unbonding_period` - Unbonding period in seconds (max 90 days)
+    /// * `min_attester_collateral` - Minimum collateral in lamports (0 = none)
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `BondConfigSet` - Emitted when the config is set
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is neither the owner nor a fee manager
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    /// * `InvalidBondConfig` - If the unbonding period is out of range
+    pub fn set_bond_config(
+        ctx: Context<SetBondConfig>,
+        attester_bond: u64,
+        unbonding_period: i64,
+        min_attester_collateral: u64,
+    ) -> Result<()> {
+        instructions::set_bond_config(ctx, attester_bond, unbonding_period, min_attester_collateral)
+    }
+
+    /// Register the signer as an attester by posting the registry's bond
+    /// 
+    /// Permissionless self-service alternative to `register_attester`. The
+    /// bond is held in the attester's bond vault PDA and tracked on its
+    /// attester account. Admins cannot deregister bonded attesters, but the
+    /// owner can force them out with `begin_unbonding`. Self-registered
+    /// attesters start scoped with no scope, so their attestations are
+    /// rejected until an admin sets one with `set_attester_scope` (an empty
+    /// scope deliberately lifts the restriction).
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterIndexed` - Emitted when the attester is listed in the attester index
+    /// * `AttesterRegistered` - Emitted when the attester is registered
+    /// * `AttesterBonded` - Emitted when the bond is posted
+    /// 
+    /// # Errors
+    /// * `SelfRegistrationDisabled` - If the registry's bond is 0
+    /// * `AttesterBonded` - If a leftover bond vault still holds collateral
+    /// * `AttesterScopeExists` - If a scope account is left over for the key
+    pub fn register_with_bond(ctx: Context<RegisterWithBond>) -> Result<()> {
+        instructions::register_with_bond(ctx)
+    }
+
+    /// Exit as a bonded attester
+    /// 
+    /// Deregisters the attester and removes it from the attester index
+    /// immediately, closes its metadata and scope accounts where they exist,
+    /// and starts the registry's unbonding period, after which
+    /// `withdraw_bond` returns the bond.
+    /// Signed by the attester, or by the owner in single-authority mode to
+    /// force out a bonded attester (the only way to remove one, and the way
+    /// secp256k1 and P-256 attesters exit).
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the exiting attester
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterUnindexed` - Emitted when the attester is removed from the attester index
+    /// * `AttesterUnbondingStarted` - Emitted when unbonding starts
+    /// 
+    /// # Errors
+    /// * `AttesterNotRegistered` - If the attester is not registered
+    /// * `AttesterAlreadyRotated` - If the key was rotated out (the bond moved to its successor)
+    /// * `AttesterAlreadyUnbonding` - If the attester is already exiting
+    /// * `AttesterNotIndexed` - If the attester predates the index and must first be listed with `index_attester`
+    /// * `AttesterIndexMismatch` - If the index pages do not list the attester or are not the last page
+    /// * `Unauthorized` - If caller is neither the attester nor the owner
+    /// * `MultisigEnabled` - If the owner acts while multisig mode is enabled
+    /// * `TimelockEnabled` - If the owner acts while the timelock is enabled
+    pub fn begin_unbonding(ctx: Context<BeginUnbonding>, attester: Pubkey) -> Result<()> {
+        instructions::begin_unbonding(ctx, attester)
+    }
+
+    /// Withdraw an exited attester's bond
+    /// 
+    /// Closes the bond vault and attester account, returning the bond and
+    /// rent to the attester, or to the registry authority for secp256k1 and
+    /// P-256 attesters, whose keys cannot hold Solana funds. Signed by the
+    /// attester, or by the owner in single-authority mode.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the exited attester
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterBondWithdrawn` - Emitted when the bond is withdrawn
+    /// 
+    /// # Errors
+    /// * `BondNotWithdrawable` - If the attester is not exiting or the unbonding period has not elapsed
+    /// * `AttesterAlreadyRotated` - If the key was rotated out (the bond moved to its successor)
+    /// * `BondRecipientMismatch` - If the recipient is not the attester's bond recipient
+    /// * `Unauthorized` - If caller is neither the attester nor the owner
+    /// * `MultisigEnabled` - If the owner acts while multisig mode is enabled
+    /// * `TimelockEnabled` - If the owner acts while the timelock is enabled
+    pub fn withdraw_bond(ctx: Context<WithdrawBond>, attester: Pubkey) -> Result<()> {
+        instructions::withdraw_bond(ctx, attester)
+    }
+
+    /// Add collateral to a registered attester's bond vault
+    /// 
+    /// Signed by the attester, or by the owner in single-authority mode to
+    /// fund attesters that cannot sign. Deposits count towards the registry's
+    /// minimum attester collateral.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the attester
+    /// * `amount` - Lamports to add
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `CollateralPosted` - Emitted when the collateral is added
+    /// 
+    /// # Errors
+    /// * `AttesterNotRegistered` - If the attester is not registered
+    /// * `AttesterAlreadyRotated` - If the key was rotated out (post for its successor)
+    /// * `AttesterAlreadyUnbonding` - If the attester is exiting
+    /// * `Unauthorized` - If caller is neither the attester nor the owner
+    /// * `MultisigEnabled` - If the owner acts while multisig mode is enabled
+    /// * `TimelockEnabled` - If the owner acts while the timelock is enabled
+    pub fn post_collateral(ctx: Context<PostCollateral>, attester: Pubkey, amount: u64) -> Result<()> {
+        instructions::post_collateral(ctx, attester, amount)
+    }
+
+    /// Slash an attester's bond for an attestation it signed
+    /// 
+    /// A governance action: the program cannot tell a bad attestation from a
+    /// good one, but the slash must point at the used UUID account of an
+    /// attestation the attester signed or co-signed, so it must happen before
+    /// that account is cleaned up. Slashed lamports go to the recipient;
+    /// passing the incinerator burns them. Only available in single-authority
+    /// mode.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `attester` - The public key of the attester
+    /// * `uuid` - The UUID of the offending attestation
+    /// * `amount` - Lamports to slash
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterSlashed` - Emitted when the bond is slashed
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the registry authority
+    /// * `MultisigEnabled` - If multisig mode is enabled
+    /// * `TimelockEnabled` - If the timelock is enabled
+    /// * `SlashEvidenceMismatch` - If the attestation was neither signed nor co-signed by the attester
+    /// * `AttesterAlreadyRotated` - If the key was rotated out (slash its successor)
+    /// * `InvalidSlashAmount` - If the amount is 0 or exceeds the bond
+    pub fn slash_attester(
+        ctx: Context<SlashAttester>,
+        attester: Pubkey,
+        uuid: [u8; 16],
+        amount: u64,
+    ) -> Result<()> {
+        instructions::slash_attester(ctx, attester, uuid, amount)
+    }
+
+    /// Queue an admin action behind the timelock
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `action` - The admin action to queue
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `OperationQueued` - Emitted with the operation's eta
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the registry authority
+    /// * `TimelockDisabled` - If the timelock is not enabled
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    pub fn queue_operation(ctx: Context<QueueOperation>, action: AdminAction) -> Result<()> {
+        instructions::queue_operation(ctx, action)
+    }
+
+    /// Execute a queued admin operation
+    /// 
+    /// Permissionless once the operation's eta has passed. The operation
+    /// account is closed and its rent returned to the authority that queued it.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `OperationExecuted` - Emitted when the operation is executed
+    /// * The event of the applied action (e.g. `AttesterRegistered`)
+    /// 
+    /// # Errors
+    /// * `AuthorityRenounced` - If the authority has since been renounced
+    /// * `TimelockNotExpired` - If the eta has not passed
+    /// * `MultisigEnabled` - If multisig mode has since been enabled
+    /// * `ProposalAccountMismatch` - If attester accounts don't match the action
+    pub fn execute_operation(ctx: Context<ExecuteOperation>) -> Result<()> {
+        instructions::execute_operation(ctx)
+    }
+
+    /// Cancel a queued admin operation
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `OperationCancelled` - Emitted when the operation is cancelled
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the registry authority
+    pub fn cancel_operation(ctx: Context<CancelOperation>) -> Result<()> {
+        instructions::cancel_operation(ctx)
+    }
+
+    /// Pause attestation validation
+    /// 
+    /// While paused, `validate_attestation` is rejected for every target.
+    /// Administration is unaffected. Pausing is never timelocked.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `RegistryPaused` - Emitted when the registry is paused
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the guardian, a pauser, a multisig signer (multisig mode) or the authority
+    /// * `RegistryAlreadyPaused` - If the registry is already paused
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        instructions::pause(ctx)
+    }
+
+    /// Resume attestation validation
+    /// 
+    /// Callable by the owner only; neither the guardian nor holders of the
+    /// `Pauser` role can unpause. In multisig or timelock mode, the owner must
+    /// use an `Unpause` proposal or queued operation instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `RegistryUnpaused` - Emitted when the registry is unpaused
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    /// * `RegistryNotPaused` - If the registry is not paused
+    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+        instructions::unpause(ctx)
+    }
+
+    /// Set or clear the pause guardian
+    /// 
+    /// The guardian is a separate key that can pause, but not unpause, the
+    /// registry. In multisig or timelock mode, use a `SetGuardian` proposal
+    /// or queued operation instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `guardian` - The new guardian, or `None` to remove it
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `GuardianSet` - Emitted when the guardian is changed
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the registry authority
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    /// * `InvalidAuthority` - If the guardian is the default public key
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Option<Pubkey>) -> Result<()> {
+        instructions::set_guardian(ctx, guardian)
+    }
+
+    /// Pause attestation validation for a client program
+    /// 
+    /// While paused, `validate_attestation` is rejected for this target. Only
+    /// the program's upgrade authority can call this instruction.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `PolicyPaused` - Emitted when the policy is paused
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If signer is not the program's upgrade authority
+    /// * `InvalidProgramData` - If program data account is invalid
+    /// * `InvalidClientProgram` - If program doesn't match policy account
+    /// * `PolicyAlreadyPaused` - If the policy is already paused
+    pub fn pause_policy(ctx: Context<SetPolicyPaused>) -> Result<()> {
+        instructions::pause_policy(ctx)
+    }
+
+    /// Resume attestation validation for a client program
+    /// 
+    /// Only the program's upgrade authority can call this instruction.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `PolicyUnpaused` - Emitted when the policy is unpaused
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If signer is not the program's upgrade authority
+    /// * `InvalidProgramData` - If program data account is invalid
+    /// * `InvalidClientProgram` - If program doesn't match policy account
+    /// * `PolicyNotPaused` - If the policy is not paused
+    pub fn unpause_policy(ctx: Context<SetPolicyPaused>) -> Result<()> {
+        instructions::unpause_policy(ctx)
+    }
+
+    /// Set how many distinct attesters must sign each validation for a client program
+    /// 
+    /// With a quorum above 1, `validate_attestation` is rejected for this
+    /// target and callers must use `validate_threshold_attestation`. Only the
+    /// program's upgrade authority can call this instruction.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `quorum` - Required attesters (0 or 1 = a single attestation)
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AttesterQuorumSet` - Emitted when the quorum is set
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If signer is not the program's upgrade authority
+    /// * `InvalidProgramData` - If program data account is invalid
+    /// * `InvalidClientProgram` - If program doesn't match policy account
+    /// * `InvalidAttesterQuorum` - If the quorum exceeds 4
+    pub fn set_attester_quorum(ctx: Context<SetAttesterQuorum>, quorum: u8) -> Result<()> {
+        instructions::set_attester_quorum(ctx, quorum)
+    }
+
+    /// Set the digest over which attesters sign statements for a client program
+    /// 
+    /// `Keccak256` switches the program's statements to keccak256 over an
+    /// EVM ABI encoding (`Statement::hash_statement_evm`), so attester
+    /// backends can share one signing path with the EVM contracts; the
+    /// default `Sha256` keeps the Solana layout. Neither signs `issued_at`,
+    /// so attesters with a revocation cutoff are refused under them;
+    /// `Sha256WithIssuedAt` appends the issue time to the Solana layout
+    /// (`Statement::hash_statement_with_issued_at`). Applies to single and
+    /// threshold validation alike. Only the program's upgrade authority can
+    /// call this instruction.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `digest_scheme` - SHA-256 (Solana layout), Keccak-256 (EVM ABI encoding) or SHA-256 with the issue time
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `DigestSchemeSet` - Emitted when the digest scheme is set
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If signer is not the program's upgrade authority
+    /// * `InvalidProgramData` - If program data account is invalid
+    /// * `InvalidClientProgram` - If program doesn't match policy account
+    pub fn set_digest_scheme(ctx: Context<SetDigestScheme>, digest_scheme: DigestScheme) -> Result<()> {
+        instructions::set_digest_scheme(ctx, digest_scheme)
+    }
+
+    /// Grant a role to a key
+    /// 
+    /// Creates the holder's role PDA. Roles separate day-to-day operational
+    /// keys from the registry owner: attester managers can register and
+    /// deregister attesters, pausers can pause (but not unpause) validation,
+    /// and fee managers can set the bond and collateral config. In multisig
+    /// or timelock mode, use a `GrantRole` proposal or queued operation
+    /// instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `role` - The role to grant
+    /// * `holder` - The key receiving the role
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `RoleGranted` - Emitted when the role is granted
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the registry owner
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    /// * `InvalidAuthority` - If the holder is the default public key
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, holder: Pubkey) -> Result<()> {
+        instructions::grant_role(ctx, role, holder)
+    }
+
+    /// Revoke a role from a key
+    /// 
+    /// Closes the holder's role PDA, returning rent to the owner. In multisig
+    /// or timelock mode, use a `RevokeRole` proposal or queued operation
+    /// instead.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// * `role` - The role to revoke
+    /// * `holder` - The key losing the role
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `RoleRevoked` - Emitted when the role is revoked
+    /// 
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the registry owner
+    /// * `MultisigEnabled` - If multisig mode requires a proposal instead
+    /// * `TimelockEnabled` - If the timelock requires a queued operation instead
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role, holder: Pubkey) -> Result<()> {
+        instructions::revoke_role(ctx, role, holder)
+    }
+
+    /// Migrate an account to the current layout
+    /// 
+    /// Upgrades a registry, attester, policy or used UUID account written
+    /// with an older layout (e.g. one created before accounts carried a version byte)
+    /// in place, reallocating it and filling in the newer fields with defaults. The
+    /// original program's registry becomes registry 0. Anyone may call this; the
+    /// payer covers the additional rent.
+    /// 
+    /// # Arguments
+    /// * `ctx` - The instruction context containing accounts
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    /// 
+    /// # Events
+    /// * `AccountMigrated` - Emitted when the account is migrated
+    /// 
+    /// # Errors
+    /// * `InvalidAccountData` - If the account is not owned by this program or cannot be decoded
+    /// * `UnsupportedAccountMigration` - If the account type has no versioned layout
+    /// * `AccountAlreadyMigrated` - If the account already uses the current layout
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account(ctx)
     }
 }
@@ -0,0 +1,669 @@
+//! # Migration Module
+//!
+//! This module contains the legacy account layouts and the logic that
+//! upgrades them to the current layout for `migrate_account`.
+//!
+//! Version 1 accounts are those written by the original program, before the
+//! `version` byte and reserved space. Attester, policy and used UUID layouts
+//! keep the earlier fields in place and append new ones, so an upgrade
+//! re-serializes the existing fields and fills in defaults for the rest; the
+//! registry gained leading fields and is rebuilt from its original ones.
+//! Attester accounts are at version 4 (suspension history); all other
+//! accounts are at version 2. Used UUID accounts carry no version byte; those
+//! written before attestations had an issue time count as version 1. Role,
+//! metadata, scope, bond and index accounts postdate versioning and need no
+//! migration.
+
+use anchor_lang::prelude::*;
+use anchor_lang::{Discriminator, Space};
+use crate::errors::PredicateRegistryError;
+use crate::state::{
+    Attestation, AttesterAccount, AttesterKeyType, DigestScheme, PolicyAccount, PredicateRegistry, UsedUuidAccount,
+    ATTESTER_RESERVED_SPACE, CURRENT_ACCOUNT_VERSION,
+    CURRENT_ATTESTER_VERSION, DEFAULT_CLOCK_DRIFT_BUFFER, DEFAULT_MAX_ATTESTATION_LIFETIME,
+    POLICY_RESERVED_SPACE, REGISTRY_RESERVED_SPACE,
+};
+
+/// Layout version of accounts created before versioning
+pub const LEGACY_ACCOUNT_VERSION: u8 = 1;
+
+/// Layout version of attester accounts with validity windows but no rotation links
+pub const ATTESTER_V2_VERSION: u8 = 2;
+
+/// Layout version of attester accounts with rotation links but no suspension history
+pub const ATTESTER_V3_VERSION: u8 = 3;
+
+/// Version 1 layout of `PredicateRegistry`, as written by the original program
+/// 
+/// The original registry had a single, un-namespaced address, which registry
+/// 0 still uses; it migrates to registry 0.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PredicateRegistryV1 {
+    pub authority: Pubkey,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub total_attesters: u64,
+    pub total_policies: u64,
+}
+
+/// Version 1 layout of `AttesterAccount`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AttesterAccountV1 {
+    pub attester: Pubkey,
+    pub is_registered: bool,
+    pub registered_at: i64,
+}
+
+/// Version 2 layout of `AttesterAccount`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AttesterAccountV2 {
+    pub attester: Pubkey,
+    pub is_registered: bool,
+    pub registered_at: i64,
+    pub version: u8,
+    pub valid_from: i64,
+    pub valid_until: i64,
+    pub reserved: [u8; 16],
+}
+
+/// Version 3 layout of `AttesterAccount`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AttesterAccountV3 {
+    pub attester: Pubkey,
+    pub is_registered: bool,
+    pub registered_at: i64,
+    pub version: u8,
+    pub valid_from: i64,
+    pub valid_until: i64,
+    pub identity: Pubkey,
+    pub previous_key: Option<Pubkey>,
+    pub next_key: Option<Pubkey>,
+    pub scoped: bool,
+    pub bond: u64,
+    pub reserved: [u8; 7],
+}
+
+/// Version 1 layout of `Attestation`, without an issue time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AttestationV1 {
+    pub uuid: [u8; 16],
+    pub attester: Pubkey,
+    pub signature: [u8; 64],
+    pub expiration: i64,
+}
+
+/// Version 1 layout of `UsedUuidAccount`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UsedUuidAccountV1 {
+    pub attestation: AttestationV1,
+    pub used_at: i64,
+    pub signer: Pubkey,
+}
+
+/// Version 1 layout of `PolicyAccount`, as written by the original program
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PolicyAccountV1 {
+    pub client_program: Pubkey,
+    pub authority: Pubkey,
+    pub policy_id: String,
+    pub set_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<PredicateRegistryV1> for PredicateRegistry {
+    fn from(v1: PredicateRegistryV1) -> Self {
+        let (_, bump) = Pubkey::find_program_address(
+            &[b"predicate_registry", &PredicateRegistry::id_seed(0)],
+            &crate::ID,
+        );
+        Self {
+            registry_id: 0,
+            bump,
+            authority: v1.authority,
+            pending_authority: None,
+            created_at: v1.created_at,
+            updated_at: v1.updated_at,
+            total_attesters: v1.total_attesters,
+            total_policies: v1.total_policies,
+            multisig_signers: Vec::new(),
+            multisig_threshold: 0,
+            proposal_count: 0,
+            timelock_delay: 0,
+            operation_count: 0,
+            paused: false,
+            guardian: None,
+            clock_drift_buffer: DEFAULT_CLOCK_DRIFT_BUFFER,
+            max_attestation_lifetime: DEFAULT_MAX_ATTESTATION_LIFETIME,
+            version: CURRENT_ACCOUNT_VERSION,
+            attester_bond: 0,
+            unbonding_period: 0,
+            min_attester_collateral: 0,
+            attester_index_len: 0,
+            reserved: [0; REGISTRY_RESERVED_SPACE],
+        }
+    }
+}
+
+impl From<AttesterAccountV1> for AttesterAccountV2 {
+    fn from(v1: AttesterAccountV1) -> Self {
+        Self {
+            attester: v1.attester,
+            is_registered: v1.is_registered,
+            registered_at: v1.registered_at,
+            version: ATTESTER_V2_VERSION,
+            valid_from: 0,
+            valid_until: 0,
+            reserved: [0; 16],
+        }
+    }
+}
+
+impl From<AttesterAccountV2> for AttesterAccountV3 {
+    fn from(v2: AttesterAccountV2) -> Self {
+        Self {
+            attester: v2.attester,
+            is_registered: v2.is_registered,
+            registered_at: v2.registered_at,
+            version: ATTESTER_V3_VERSION,
+            valid_from: v2.valid_from,
+            valid_until: v2.valid_until,
+            identity: v2.attester,
+            previous_key: None,
+            next_key: None,
+            scoped: false,
+            bond: 0,
+            reserved: [0; 7],
+        }
+    }
+}
+
+impl From<AttesterAccountV3> for AttesterAccount {
+    fn from(v3: AttesterAccountV3) -> Self {
+        Self {
+            attester: v3.attester,
+            is_registered: v3.is_registered,
+            registered_at: v3.registered_at,
+            version: CURRENT_ATTESTER_VERSION,
+            valid_from: v3.valid_from,
+            valid_until: v3.valid_until,
+            identity: v3.identity,
+            previous_key: v3.previous_key,
+            next_key: v3.next_key,
+            scoped: v3.scoped,
+            bond: v3.bond,
+            is_suspended: false,
+            suspension_count: 0,
+            status_changed_at: v3.registered_at,
+            revoked_after: 0,
+            indexed: false,
+            key_type: AttesterKeyType::Ed25519,
+            reserved: [0; ATTESTER_RESERVED_SPACE],
+        }
+    }
+}
+
+impl From<UsedUuidAccountV1> for UsedUuidAccount {
+    fn from(v1: UsedUuidAccountV1) -> Self {
+        Self {
+            attestation: Attestation {
+                uuid: v1.attestation.uuid,
+                attester: v1.attestation.attester,
+                signature: v1.attestation.signature,
+                expiration: v1.attestation.expiration,
+                issued_at: 0,
+            },
+            used_at: v1.used_at,
+            signer: v1.signer,
+            co_signers: Vec::new(),
+        }
+    }
+}
+
+impl From<PolicyAccountV1> for PolicyAccount {
+    fn from(v1: PolicyAccountV1) -> Self {
+        Self {
+            client_program: v1.client_program,
+            authority: v1.authority,
+            policy_id: v1.policy_id,
+            set_at: v1.set_at,
+            updated_at: v1.updated_at,
+            paused: false,
+            version: CURRENT_ACCOUNT_VERSION,
+            attester_quorum: 0,
+            digest_scheme: DigestScheme::Sha256,
+            reserved: [0; POLICY_RESERVED_SPACE],
+        }
+    }
+}
+
+/// An account that carries a layout version
+pub trait Versioned {
+    /// The layout version written by the current program
+    const CURRENT_VERSION: u8;
+
+    /// The layout version stored in the account
+    fn version(&self) -> u8;
+}
+
+impl Versioned for PredicateRegistry {
+    const CURRENT_VERSION: u8 = CURRENT_ACCOUNT_VERSION;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl Versioned for AttesterAccount {
+    const CURRENT_VERSION: u8 = CURRENT_ATTESTER_VERSION;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl Versioned for PolicyAccount {
+    const CURRENT_VERSION: u8 = CURRENT_ACCOUNT_VERSION;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+/// The result of upgrading an account's data
+pub struct MigratedAccount {
+    /// The account data in the current layout, discriminator included
+    pub data: Vec<u8>,
+    /// The account size required by the current layout
+    pub space: usize,
+    /// The layout version the account was migrated from
+    pub from_version: u8,
+    /// The layout version the account was migrated to
+    pub to_version: u8,
+}
+
+/// Upgrade raw account data to the current layout
+///
+/// The account type is selected by its discriminator. Data that already
+/// decodes as the current layout with the current version is rejected, so
+/// migrating twice is an error rather than a silent reset of reserved space.
+///
+/// # Arguments
+/// * `data` - The full account data, discriminator included
+///
+/// # Returns
+/// * `Result<MigratedAccount>` - The upgraded data and its required size
+pub fn migrate_account_data(data: &[u8]) -> Result<MigratedAccount> {
+    require!(data.len() >= 8, PredicateRegistryError::InvalidAccountData);
+    let discriminator = &data[..8];
+
+    if discriminator == PredicateRegistry::DISCRIMINATOR {
+        upgrade(data, decode_v1::<PredicateRegistryV1, PredicateRegistry>)
+    } else if discriminator == AttesterAccount::DISCRIMINATOR {
+        upgrade(data, decode_attester)
+    } else if discriminator == PolicyAccount::DISCRIMINATOR {
+        upgrade(data, decode_v1::<PolicyAccountV1, PolicyAccount>)
+    } else if discriminator == UsedUuidAccount::DISCRIMINATOR {
+        upgrade_used_uuid(data)
+    } else {
+        err!(PredicateRegistryError::UnsupportedAccountMigration)
+    }
+}
+
+/// Decode a version 1 account body into the current layout
+fn decode_v1<V1, T>(body: &[u8]) -> std::io::Result<(T, u8)>
+where
+    V1: AnchorDeserialize + Into<T>,
+{
+    Ok((V1::deserialize(&mut &body[..])?.into(), LEGACY_ACCOUNT_VERSION))
+}
+
+/// Decode a version 1, 2 or 3 attester account body into the current layout
+fn decode_attester(body: &[u8]) -> std::io::Result<(AttesterAccount, u8)> {
+    // Each layout's bodies are too short to decode as the next one
+    if let Ok(v3) = AttesterAccountV3::deserialize(&mut &body[..]) {
+        if v3.version == ATTESTER_V3_VERSION {
+            return Ok((v3.into(), ATTESTER_V3_VERSION));
+        }
+    }
+    let (v2, from_version) = match AttesterAccountV2::deserialize(&mut &body[..]) {
+        Ok(v2) if v2.version == ATTESTER_V2_VERSION => (v2, ATTESTER_V2_VERSION),
+        _ => (
+            AttesterAccountV1::deserialize(&mut &body[..])?.into(),
+            LEGACY_ACCOUNT_VERSION,
+        ),
+    };
+    Ok((AttesterAccountV3::from(v2).into(), from_version))
+}
+
+/// Decode a legacy account and re-serialize it in the current layout
+///
+/// Legacy accounts allocated with spare space (e.g. a registry with fewer
+/// than the maximum multisig signers) may decode as the current layout with
+/// a zero version byte, so only the current version counts as migrated.
+fn upgrade<T>(
+    data: &[u8],
+    decode_legacy: impl FnOnce(&[u8]) -> std::io::Result<(T, u8)>,
+) -> Result<MigratedAccount>
+where
+    T: AccountSerialize + AccountDeserialize + Space + Versioned,
+{
+    if let Ok(current) = T::try_deserialize(&mut &data[..]) {
+        require!(
+            current.version() != T::CURRENT_VERSION,
+            PredicateRegistryError::AccountAlreadyMigrated
+        );
+    }
+
+    let (account, from_version) = decode_legacy(&data[8..])
+        .map_err(|_| PredicateRegistryError::InvalidAccountData)?;
+
+    let mut migrated = Vec::with_capacity(8 + T::INIT_SPACE);
+    account.try_serialize(&mut migrated)?;
+
+    Ok(MigratedAccount {
+        data: migrated,
+        space: 8 + T::INIT_SPACE,
+        from_version,
+        to_version: T::CURRENT_VERSION,
+    })
+}
+
+/// Upgrade a used UUID account written before attestations had an issue time
+///
+/// Used UUID accounts have no version byte, so any data that decodes as the
+/// current layout counts as migrated. Legacy records get an issue time of 0,
+/// which only affects the audit trail; the UUID stays marked as used.
+fn upgrade_used_uuid(data: &[u8]) -> Result<MigratedAccount> {
+    require!(
+        UsedUuidAccount::try_deserialize(&mut &data[..]).is_err(),
+        PredicateRegistryError::AccountAlreadyMigrated
+    );
+
+    let account: UsedUuidAccount = UsedUuidAccountV1::deserialize(&mut &data[8..])
+        .map_err(|_| PredicateRegistryError::InvalidAccountData)?
+        .into();
+
+    let mut migrated = Vec::with_capacity(8 + UsedUuidAccount::INIT_SPACE);
+    account.try_serialize(&mut migrated)?;
+
+    Ok(MigratedAccount {
+        data: migrated,
+        space: 8 + UsedUuidAccount::INIT_SPACE,
+        from_version: LEGACY_ACCOUNT_VERSION,
+        to_version: CURRENT_ACCOUNT_VERSION,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture<V1: AnchorSerialize>(discriminator: &[u8], account: &V1, space: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        account.serialize(&mut data).unwrap();
+        data.resize(data.len().max(space), 0);
+        data
+    }
+
+    // Space the original program allocated for each account (`8 + INIT_SPACE`
+    // of its layouts, with policy IDs of up to 64 bytes)
+    const BASELINE_REGISTRY_SPACE: usize = 8 + 32 + 8 + 8 + 8 + 8;
+    const BASELINE_ATTESTER_SPACE: usize = 8 + 32 + 1 + 8;
+    const BASELINE_POLICY_SPACE: usize = 8 + 32 + 32 + (4 + 64) + 8 + 8;
+    const BASELINE_USED_UUID_SPACE: usize = 8 + (16 + 32 + 64 + 8) + 8 + 32;
+
+    /// Account data as serialized by the original program: the discriminator,
+    /// each field's Borsh encoding in declaration order, then zero padding up
+    /// to the allocated space
+    fn baseline_account(discriminator: &[u8], fields: &[&[u8]], space: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        for field in fields {
+            data.extend_from_slice(field);
+        }
+        assert!(data.len() <= space);
+        data.resize(space, 0);
+        data
+    }
+
+    fn baseline_registry(authority: &Pubkey) -> Vec<u8> {
+        baseline_account(
+            PredicateRegistry::DISCRIMINATOR,
+            &[
+                authority.as_ref(),
+                &1_700_000_000i64.to_le_bytes(),
+                &1_700_000_100i64.to_le_bytes(),
+                &3u64.to_le_bytes(),
+                &5u64.to_le_bytes(),
+            ],
+            BASELINE_REGISTRY_SPACE,
+        )
+    }
+
+    #[test]
+    fn migrates_registry_v1() {
+        let authority = Pubkey::new_unique();
+        let data = baseline_registry(&authority);
+
+        let migrated = migrate_account_data(&data).unwrap();
+        assert_eq!(migrated.space, 8 + PredicateRegistry::INIT_SPACE);
+        assert_eq!(migrated.from_version, LEGACY_ACCOUNT_VERSION);
+
+        // The original registry becomes registry 0, which kept its address
+        let (_, bump) = Pubkey::find_program_address(&[b"predicate_registry"], &crate::ID);
+        let registry = PredicateRegistry::try_deserialize(&mut &migrated.data[..]).unwrap();
+        assert_eq!(registry.registry_id, 0);
+        assert_eq!(registry.bump, bump);
+        assert_eq!(registry.authority, authority);
+        assert_eq!(registry.pending_authority, None);
+        assert_eq!(registry.created_at, 1_700_000_000);
+        assert_eq!(registry.updated_at, 1_700_000_100);
+        assert_eq!(registry.total_attesters, 3);
+        assert_eq!(registry.total_policies, 5);
+        assert!(registry.multisig_signers.is_empty());
+        assert_eq!(registry.multisig_threshold, 0);
+        assert_eq!(registry.timelock_delay, 0);
+        assert!(!registry.paused);
+        assert_eq!(registry.guardian, None);
+        assert_eq!(registry.clock_drift_buffer, DEFAULT_CLOCK_DRIFT_BUFFER);
+        assert_eq!(registry.max_attestation_lifetime, DEFAULT_MAX_ATTESTATION_LIFETIME);
+        assert_eq!(registry.version, CURRENT_ACCOUNT_VERSION);
+        assert_eq!(registry.attester_bond, 0);
+        assert_eq!(registry.attester_index_len, 0);
+        assert_eq!(registry.reserved, [0; REGISTRY_RESERVED_SPACE]);
+    }
+
+    #[test]
+    fn migrates_attester_v1() {
+        let key = Pubkey::new_unique();
+        let data = baseline_account(
+            AttesterAccount::DISCRIMINATOR,
+            &[key.as_ref(), &[1], &1_700_000_000i64.to_le_bytes()],
+            BASELINE_ATTESTER_SPACE,
+        );
+
+        let migrated = migrate_account_data(&data).unwrap();
+        assert_eq!(migrated.from_version, LEGACY_ACCOUNT_VERSION);
+        assert_eq!(migrated.space, 8 + AttesterAccount::INIT_SPACE);
+
+        let attester = AttesterAccount::try_deserialize(&mut &migrated.data[..]).unwrap();
+        assert_eq!(attester.attester, key);
+        assert!(attester.is_registered);
+        assert_eq!(attester.registered_at, 1_700_000_000);
+        assert_eq!(attester.version, CURRENT_ATTESTER_VERSION);
+        assert_eq!(attester.valid_from, 0);
+        assert_eq!(attester.valid_until, 0);
+        assert_eq!(attester.identity, key);
+        assert_eq!(attester.previous_key, None);
+        assert_eq!(attester.next_key, None);
+        assert!(!attester.is_suspended);
+        assert_eq!(attester.status_changed_at, 1_700_000_000);
+        assert_eq!(attester.reserved, [0; ATTESTER_RESERVED_SPACE]);
+    }
+
+    #[test]
+    fn migrates_attester_v2() {
+        let v2 = AttesterAccountV2 {
+            attester: Pubkey::new_unique(),
+            is_registered: true,
+            registered_at: 1_700_000_000,
+            version: ATTESTER_V2_VERSION,
+            valid_from: 1_700_000_500,
+            valid_until: 1_800_000_000,
+            reserved: [0; 16],
+        };
+        let data = fixture(AttesterAccount::DISCRIMINATOR, &v2, 0);
+
+        let migrated = migrate_account_data(&data).unwrap();
+        assert_eq!(migrated.from_version, ATTESTER_V2_VERSION);
+        assert_eq!(migrated.space, 8 + AttesterAccount::INIT_SPACE);
+
+        let attester = AttesterAccount::try_deserialize(&mut &migrated.data[..]).unwrap();
+        assert_eq!(attester.attester, v2.attester);
+        assert_eq!(attester.registered_at, v2.registered_at);
+        assert_eq!(attester.valid_from, v2.valid_from);
+        assert_eq!(attester.valid_until, v2.valid_until);
+        assert_eq!(attester.version, CURRENT_ATTESTER_VERSION);
+        assert_eq!(attester.identity, v2.attester);
+        assert_eq!(attester.previous_key, None);
+        assert_eq!(attester.next_key, None);
+
+        assert_eq!(
+            migrate_account_data(&migrated.data).err(),
+            Some(PredicateRegistryError::AccountAlreadyMigrated.into())
+        );
+    }
+
+    #[test]
+    fn migrates_attester_v3() {
+        let v3 = AttesterAccountV3 {
+            attester: Pubkey::new_unique(),
+            is_registered: true,
+            registered_at: 1_700_000_000,
+            version: ATTESTER_V3_VERSION,
+            valid_from: 0,
+            valid_until: 1_800_000_000,
+            identity: Pubkey::new_unique(),
+            previous_key: Some(Pubkey::new_unique()),
+            next_key: None,
+            scoped: true,
+            bond: 500_000,
+            reserved: [0; 7],
+        };
+        let data = fixture(AttesterAccount::DISCRIMINATOR, &v3, 0);
+
+        let migrated = migrate_account_data(&data).unwrap();
+        assert_eq!(migrated.from_version, ATTESTER_V3_VERSION);
+        assert_eq!(migrated.space, 8 + AttesterAccount::INIT_SPACE);
+
+        let attester = AttesterAccount::try_deserialize(&mut &migrated.data[..]).unwrap();
+        assert_eq!(attester.attester, v3.attester);
+        assert_eq!(attester.valid_until, v3.valid_until);
+        assert_eq!(attester.identity, v3.identity);
+        assert_eq!(attester.previous_key, v3.previous_key);
+        assert!(attester.scoped);
+        assert_eq!(attester.bond, v3.bond);
+        assert_eq!(attester.version, CURRENT_ATTESTER_VERSION);
+        assert!(!attester.is_suspended);
+        assert_eq!(attester.suspension_count, 0);
+        assert_eq!(attester.status_changed_at, v3.registered_at);
+        assert_eq!(attester.revoked_after, 0);
+        assert!(!attester.indexed);
+        assert!(attester.key_type == AttesterKeyType::Ed25519);
+        assert_eq!(attester.reserved, [0; ATTESTER_RESERVED_SPACE]);
+    }
+
+    #[test]
+    fn migrates_used_uuid_v1() {
+        let attester = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let data = baseline_account(
+            UsedUuidAccount::DISCRIMINATOR,
+            &[
+                &[7; 16],
+                attester.as_ref(),
+                &[9; 64],
+                &1_700_003_600i64.to_le_bytes(),
+                &1_700_000_000i64.to_le_bytes(),
+                signer.as_ref(),
+            ],
+            BASELINE_USED_UUID_SPACE,
+        );
+
+        let migrated = migrate_account_data(&data).unwrap();
+        assert_eq!(migrated.from_version, LEGACY_ACCOUNT_VERSION);
+        assert_eq!(migrated.space, 8 + UsedUuidAccount::INIT_SPACE);
+
+        let used = UsedUuidAccount::try_deserialize(&mut &migrated.data[..]).unwrap();
+        assert_eq!(used.attestation.uuid, [7; 16]);
+        assert_eq!(used.attestation.attester, attester);
+        assert_eq!(used.attestation.signature, [9; 64]);
+        assert_eq!(used.attestation.expiration, 1_700_003_600);
+        assert_eq!(used.attestation.issued_at, 0);
+        assert_eq!(used.used_at, 1_700_000_000);
+        assert_eq!(used.signer, signer);
+        assert!(used.co_signers.is_empty());
+
+        assert_eq!(
+            migrate_account_data(&migrated.data).err(),
+            Some(PredicateRegistryError::AccountAlreadyMigrated.into())
+        );
+    }
+
+    #[test]
+    fn migrates_policy_v1() {
+        let client_program = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let policy_id = "x-policy-v1";
+        // Policy IDs shorter than the maximum leave spare space in the account
+        let data = baseline_account(
+            PolicyAccount::DISCRIMINATOR,
+            &[
+                client_program.as_ref(),
+                authority.as_ref(),
+                &(policy_id.len() as u32).to_le_bytes(),
+                policy_id.as_bytes(),
+                &1_700_000_000i64.to_le_bytes(),
+                &1_700_000_200i64.to_le_bytes(),
+            ],
+            BASELINE_POLICY_SPACE,
+        );
+
+        let migrated = migrate_account_data(&data).unwrap();
+        assert_eq!(migrated.from_version, LEGACY_ACCOUNT_VERSION);
+        assert_eq!(migrated.space, 8 + PolicyAccount::INIT_SPACE);
+
+        let policy = PolicyAccount::try_deserialize(&mut &migrated.data[..]).unwrap();
+        assert_eq!(policy.client_program, client_program);
+        assert_eq!(policy.authority, authority);
+        assert_eq!(policy.policy_id, policy_id);
+        assert_eq!(policy.set_at, 1_700_000_000);
+        assert_eq!(policy.updated_at, 1_700_000_200);
+        assert!(!policy.paused);
+        assert_eq!(policy.version, CURRENT_ACCOUNT_VERSION);
+        assert_eq!(policy.attester_quorum, 0);
+        assert!(policy.digest_scheme == DigestScheme::Sha256);
+        assert_eq!(policy.reserved, [0; POLICY_RESERVED_SPACE]);
+    }
+
+    #[test]
+    fn rejects_already_migrated_account() {
+        let data = baseline_registry(&Pubkey::new_unique());
+        let migrated = migrate_account_data(&data).unwrap();
+
+        let result = migrate_account_data(&migrated.data);
+        assert_eq!(
+            result.err(),
+            Some(PredicateRegistryError::AccountAlreadyMigrated.into())
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_account() {
+        let data = [0u8; 64];
+        let result = migrate_account_data(&data);
+        assert_eq!(
+            result.err(),
+            Some(PredicateRegistryError::UnsupportedAccountMigration.into())
+        );
+    }
+}
@@ -5,12 +5,114 @@
 
 use anchor_lang::prelude::*;
 
+/// Maximum number of signers in the registry's multisig signer set
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// Maximum timelock delay for queued admin operations (30 days)
+pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60;
+
+/// Clock drift buffer applied to new registries (30 seconds)
+pub const DEFAULT_CLOCK_DRIFT_BUFFER: i64 = 30;
+
+/// Maximum configurable clock drift buffer (10 minutes)
+pub const MAX_CLOCK_DRIFT_BUFFER: i64 = 10 * 60;
+
+/// Maximum attestation lifetime applied to new registries (7 days)
+pub const DEFAULT_MAX_ATTESTATION_LIFETIME: i64 = 7 * 24 * 60 * 60;
+
+/// Upper bound for the configurable maximum attestation lifetime (365 days)
+pub const MAX_ATTESTATION_LIFETIME: i64 = 365 * 24 * 60 * 60;
+
+/// Maximum length of an attester's display name
+pub const MAX_ATTESTER_NAME_LEN: usize = 32;
+
+/// Maximum length of an attester's service endpoint URL
+pub const MAX_ATTESTER_URL_LEN: usize = 128;
+
+/// Maximum length of an attester's contact
+pub const MAX_ATTESTER_CONTACT_LEN: usize = 64;
+
+/// Maximum length of an attester's free-form description
+pub const MAX_ATTESTER_DESCRIPTION_LEN: usize = 256;
+
+/// Maximum number of client programs in an attester's scope
+pub const MAX_ATTESTER_SCOPE_PROGRAMS: usize = 8;
+
+/// Maximum number of policy ID prefixes in an attester's scope
+pub const MAX_ATTESTER_SCOPE_PREFIXES: usize = 4;
+
+/// Maximum length of a policy ID (and of a policy ID prefix in an attester's scope)
+pub const MAX_POLICY_ID_LEN: usize = 64;
+
+/// Maximum attester quorum a policy can require
+/// 
+/// Also caps the signatures in one threshold validation, which must fit in a
+/// single transaction alongside their Ed25519 instruction.
+pub const MAX_ATTESTER_QUORUM: u8 = 4;
+
+/// Maximum co-signers recorded for a threshold attestation (every signer but the first)
+pub const MAX_CO_SIGNERS: usize = MAX_ATTESTER_QUORUM as usize - 1;
+
+/// Sentinel authority of a registry whose ownership has been renounced
+/// 
+/// No one can sign for the default key, so every `has_one = authority`
+/// admin path fails permanently once it is set.
+pub const RENOUNCED_AUTHORITY: Pubkey = Pubkey::new_from_array([0; 32]);
+
+/// Layout version written to newly created and migrated accounts (other than attesters)
+/// 
+/// Version 1 is the original layout without a version byte or reserved
+/// space; `migrate_account` upgrades such accounts in place.
+pub const CURRENT_ACCOUNT_VERSION: u8 = 2;
+
+/// Layout version of attester accounts
+/// 
+/// Version 3 added key rotation links and version 4 suspension history;
+/// version 2 and 3 attester accounts are also upgraded by `migrate_account`.
+pub const CURRENT_ATTESTER_VERSION: u8 = 4;
+
+/// Maximum overlap during which both keys of a rotated attester are accepted (30 days)
+pub const MAX_ROTATION_OVERLAP: i64 = 30 * 24 * 60 * 60;
+
+/// Maximum unbonding period for self-registered attesters (90 days)
+pub const MAX_UNBONDING_PERIOD: i64 = 90 * 24 * 60 * 60;
+
+/// Maximum attesters registered or deregistered in one batch
+pub const MAX_ATTESTER_BATCH: usize = 16;
+
+/// The P-256 field prime, big endian; a P-256 x-coordinate must be below it
+pub const P256_FIELD_PRIME: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+];
+
+/// Maximum attesters per attester index page, small enough to return a full page as return data
+pub const ATTESTER_INDEX_PAGE_SIZE: usize = 30;
+
+/// Reserved bytes left at the end of the registry account after the bond config
+pub const REGISTRY_RESERVED_SPACE: usize = 64 - 32;
+
+/// Reserved bytes at the end of attester, policy and role accounts for future fields
+pub const ACCOUNT_RESERVED_SPACE: usize = 32;
+
+/// Reserved bytes left in policy accounts after the attester quorum and digest scheme
+pub const POLICY_RESERVED_SPACE: usize = ACCOUNT_RESERVED_SPACE - 2;
+
+/// Reserved bytes left in attester accounts after the suspension history and revocation cutoff
+pub const ATTESTER_RESERVED_SPACE: usize = ACCOUNT_RESERVED_SPACE - 23;
+
 /// The main registry account that stores the registry state
 #[account]
 #[derive(InitSpace)]
 pub struct PredicateRegistry {
+    /// Namespace identifier for this registry (PDA seed)
+    pub registry_id: u64,
+    /// PDA bump seed
+    pub bump: u8,
     /// The authority that can modify this registry (owner)
     pub authority: Pubkey,
+    /// The proposed new authority awaiting acceptance (two-step transfer)
+    pub pending_authority: Option<Pubkey>,
     /// Timestamp when the registry was created
     pub created_at: i64,
     /// Timestamp when the registry was last updated
@@ -19,6 +121,37 @@ pub struct PredicateRegistry {
     pub total_attesters: u64,
     /// Total number of policies set
     pub total_policies: u64,
+    /// Signers allowed to create and approve admin proposals (multisig mode)
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub multisig_signers: Vec<Pubkey>,
+    /// Approvals required to execute a proposal (0 = multisig mode disabled)
+    pub multisig_threshold: u8,
+    /// Number of admin proposals created (used to derive proposal PDAs)
+    pub proposal_count: u64,
+    /// Delay in seconds before queued admin operations can execute (0 = timelock disabled)
+    pub timelock_delay: i64,
+    /// Number of admin operations queued (used to derive operation PDAs)
+    pub operation_count: u64,
+    /// Whether attestation validation is halted (emergency pause)
+    pub paused: bool,
+    /// Optional key allowed to pause (but not unpause) the registry
+    pub guardian: Option<Pubkey>,
+    /// Seconds past expiration during which an attestation is still accepted
+    pub clock_drift_buffer: i64,
+    /// Maximum seconds between validation time and an attestation's expiration
+    pub max_attestation_lifetime: i64,
+    /// Account layout version
+    pub version: u8,
+    /// Lamports a self-registering attester must bond (0 = self-registration disabled)
+    pub attester_bond: u64,
+    /// Seconds a self-registered attester waits after exiting before withdrawing its bond
+    pub unbonding_period: i64,
+    /// Collateral in lamports every attester must hold in its bond vault to be accepted (0 = none)
+    pub min_attester_collateral: u64,
+    /// Number of attesters listed across the attester index pages
+    pub attester_index_len: u64,
+    /// Reserved for future fields
+    pub reserved: [u8; REGISTRY_RESERVED_SPACE],
 }
 
 /// Account for storing attester registration data
@@ -31,6 +164,187 @@ pub struct AttesterAccount {
     pub is_registered: bool,
     /// Timestamp when registered
     pub registered_at: i64,
+    /// Account layout version
+    pub version: u8,
+    /// Earliest timestamp at which the attester's attestations are accepted (0 = no lower bound)
+    pub valid_from: i64,
+    /// Latest timestamp at which the attester's attestations are accepted (0 = no end date)
+    pub valid_until: i64,
+    /// Stable identity shared by every key of this attester (its first key)
+    pub identity: Pubkey,
+    /// The key this one replaced, if registered by rotation
+    pub previous_key: Option<Pubkey>,
+    /// The key that replaced this one, once rotated
+    pub next_key: Option<Pubkey>,
+    /// Whether the identity's `AttesterScopeAccount` restricts what this attester may sign for
+    /// 
+    /// Admin-registered attesters (single, batch, proposal or operation)
+    /// start unscoped. Self-registered attesters start scoped, and are
+    /// rejected until an admin sets their scope.
+    pub scoped: bool,
+    /// Lamports held in the attester's bond vault (bond and collateral, net of slashing)
+    pub bond: u64,
+    /// Whether the attester is suspended (not registered, but reinstatable)
+    pub is_suspended: bool,
+    /// Number of times the attester has been suspended
+    pub suspension_count: u32,
+    /// Timestamp of the last registration, suspension or reinstatement
+    pub status_changed_at: i64,
+    /// Attestations issued after this timestamp are rejected (0 = not revoked)
+    pub revoked_after: i64,
+    /// Whether the attester is listed in the registry's attester index
+    pub indexed: bool,
+    /// Signature scheme of the attester's key
+    pub key_type: AttesterKeyType,
+    /// Reserved for future fields
+    pub reserved: [u8; ATTESTER_RESERVED_SPACE],
+}
+
+/// Signature scheme of an attester key
+/// 
+/// Secp256k1 attesters are Ethereum keys, identified on-chain by their
+/// 20-byte address left-padded with zeros to a 32-byte key (see
+/// `AttesterAccount::secp256k1_key`), so they share attester PDAs, events
+/// and the attester index with Ed25519 attesters. Secp256r1 (P-256) attesters
+/// are likewise identified by the 32-byte x-coordinate of their public key
+/// (see `AttesterAccount::secp256r1_key`); either y parity is accepted at
+/// validation, as both points can only sign for the holder of the one
+/// private key. Only the secp256r1 program checks that the point is on the
+/// curve, so an off-curve x-coordinate registers but never validates.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub enum AttesterKeyType {
+    /// Solana key, verified by the Ed25519 program
+    #[default]
+    Ed25519,
+    /// Ethereum key, verified by the secp256k1 program
+    Secp256k1,
+    /// P-256 key (HSM or cloud KMS), verified by the secp256r1 program
+    Secp256r1,
+}
+
+/// Descriptive metadata for an attester
+/// 
+/// Purely informational, for explorers and dashboards; never consulted when
+/// validating attestations. Every field is optional and length-bounded.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, InitSpace)]
+pub struct AttesterMetadata {
+    /// Display name
+    #[max_len(MAX_ATTESTER_NAME_LEN)]
+    pub name: Option<String>,
+    /// Service endpoint URL
+    #[max_len(MAX_ATTESTER_URL_LEN)]
+    pub url: Option<String>,
+    /// Contact (e.g. an email address)
+    #[max_len(MAX_ATTESTER_CONTACT_LEN)]
+    pub contact: Option<String>,
+    /// Free-form description
+    #[max_len(MAX_ATTESTER_DESCRIPTION_LEN)]
+    pub description: Option<String>,
+}
+
+/// Account for storing an attester's metadata
+/// 
+/// Companion to `AttesterAccount`, created when metadata is first set at
+/// registration or via `update_attester_metadata`. Closed alongside the
+/// attester account when it is supplied to `deregister_attester`.
+#[account]
+#[derive(InitSpace)]
+pub struct AttesterMetadataAccount {
+    /// The attester described by this metadata
+    pub attester: Pubkey,
+    /// The attester's metadata
+    pub metadata: AttesterMetadata,
+    /// Who last set the metadata
+    pub updated_by: Pubkey,
+    /// Timestamp when the metadata was last set
+    pub updated_at: i64,
+    /// Account layout version
+    pub version: u8,
+    /// Reserved for future fields
+    pub reserved: [u8; ACCOUNT_RESERVED_SPACE],
+}
+
+/// The client programs and policies an attester may sign for
+/// 
+/// An attestation is in scope when its target is one of `programs` or its
+/// policy ID starts with one of `policy_prefixes`. An empty scope places no
+/// restriction on the attester.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, InitSpace)]
+pub struct AttesterScope {
+    /// Client programs the attester may sign for
+    #[max_len(MAX_ATTESTER_SCOPE_PROGRAMS)]
+    pub programs: Vec<Pubkey>,
+    /// Policy ID prefixes the attester may sign for
+    #[max_len(MAX_ATTESTER_SCOPE_PREFIXES, MAX_POLICY_ID_LEN)]
+    pub policy_prefixes: Vec<String>,
+}
+
+/// Account for storing an attester's scope
+/// 
+/// Keyed by the attester's identity, so every key of a rotated attester
+/// shares the same scope. Only consulted while the attester account's
+/// `scoped` flag is set.
+#[account]
+#[derive(InitSpace)]
+pub struct AttesterScopeAccount {
+    /// The identity of the attester this scope applies to
+    pub identity: Pubkey,
+    /// The attester's scope
+    pub scope: AttesterScope,
+    /// Who last set the scope
+    pub updated_by: Pubkey,
+    /// Timestamp when the scope was last set
+    pub updated_at: i64,
+    /// Account layout version
+    pub version: u8,
+    /// Reserved for future fields
+    pub reserved: [u8; ACCOUNT_RESERVED_SPACE],
+}
+
+/// Vault holding an attester's bond and collateral
+/// 
+/// Created when an attester self-registers or first posts collateral. The
+/// bonded lamports are held on top of this account's rent exemption, can be
+/// slashed by the registry authority, and are returned with the rent when
+/// the account is closed by `withdraw_bond`. Keyed by the attester's
+/// identity, so the vault stays put when the attester rotates its key.
+#[account]
+#[derive(InitSpace)]
+pub struct AttesterBondAccount {
+    /// The identity of the attester the bond belongs to
+    pub identity: Pubkey,
+    /// Lamports bonded (net of slashing)
+    pub amount: u64,
+    /// Timestamp when the bond was posted
+    pub bonded_at: i64,
+    /// Earliest timestamp at which the bond can be withdrawn (0 = not exiting)
+    pub withdrawable_at: i64,
+    /// Account layout version
+    pub version: u8,
+    /// Reserved for future fields
+    pub reserved: [u8; ACCOUNT_RESERVED_SPACE],
+}
+
+/// One page of the registry's on-chain attester index
+/// 
+/// Page `n` holds entries `n * ATTESTER_INDEX_PAGE_SIZE` onwards, so the
+/// index can be enumerated by reading pages 0 through
+/// `attester_index_len / ATTESTER_INDEX_PAGE_SIZE`. Entries are unordered:
+/// removal moves the last entry into the freed slot.
+#[account]
+#[derive(InitSpace)]
+pub struct AttesterIndexPage {
+    /// The registry this page belongs to
+    pub registry: Pubkey,
+    /// Page number (PDA seed)
+    pub page: u32,
+    /// Attester keys on this page
+    #[max_len(ATTESTER_INDEX_PAGE_SIZE)]
+    pub attesters: Vec<Pubkey>,
+    /// Account layout version
+    pub version: u8,
+    /// Reserved for future fields
+    pub reserved: [u8; ACCOUNT_RESERVED_SPACE],
 }
 
 /// Account for storing client policy ID
@@ -47,12 +361,186 @@ pub struct PolicyAccount {
     /// The upgrade authority that set this policy
     pub authority: Pubkey,
     /// The policy ID (string identifier, not content)
-    #[max_len(64)]
+    #[max_len(MAX_POLICY_ID_LEN)]
     pub policy_id: String,
     /// Timestamp when policy was set
     pub set_at: i64,
     /// Timestamp when policy was last updated
     pub updated_at: i64,
+    /// Whether attestation validation is halted for this program
+    pub paused: bool,
+    /// Account layout version
+    pub version: u8,
+    /// Distinct attesters whose signatures each validation requires (0 or 1 = a single attestation)
+    pub attester_quorum: u8,
+    /// Digest over which attesters sign statements for this program
+    pub digest_scheme: DigestScheme,
+    /// Reserved for future fields
+    pub reserved: [u8; POLICY_RESERVED_SPACE],
+}
+
+/// Digest over which attesters sign a statement
+/// 
+/// `Keccak256` lets an attester backend share one signing path with the EVM
+/// contracts: the digest is keccak256 of an `abi.encode` of the statement
+/// (see `Statement::hash_statement_evm`). Only `Sha256WithIssuedAt` signs the
+/// statement's issue time, which revocation cutoffs rely on.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub enum DigestScheme {
+    /// SHA-256 over the Solana layout (see `Statement::hash_statement_safe`)
+    #[default]
+    Sha256,
+    /// Keccak-256 over an EVM ABI encoding (see `Statement::hash_statement_evm`)
+    Keccak256,
+    /// SHA-256 over the Solana layout followed by the issue time
+    /// (see `Statement::hash_statement_with_issued_at`)
+    Sha256WithIssuedAt,
+}
+
+impl DigestScheme {
+    /// Whether the digest covers `issued_at`, so it cannot be rewritten after signing
+    pub fn signs_issued_at(self) -> bool {
+        matches!(self, DigestScheme::Sha256WithIssuedAt)
+    }
+}
+
+/// A role that the registry owner can grant to additional keys
+/// 
+/// The owner itself is the registry `authority` and is not a grantable role.
+/// Roles let day-to-day operational keys act without holding ownership.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    /// May register and deregister attesters
+    AttesterManager,
+    /// May pause (but not unpause) attestation validation
+    Pauser,
+    /// May set the attester bond, unbonding period and minimum collateral
+    FeeManager,
+}
+
+impl Role {
+    /// Seed byte used to derive role PDAs
+    pub fn seed(&self) -> [u8; 1] {
+        [*self as u8]
+    }
+}
+
+/// Account proving that `holder` has been granted `role`
+/// 
+/// Created by the owner with `grant_role` and closed by `revoke_role`.
+#[account]
+#[derive(InitSpace)]
+pub struct RoleAccount {
+    /// The granted role
+    pub role: Role,
+    /// The key holding the role
+    pub holder: Pubkey,
+    /// The owner (or proposal/operation account) that granted the role
+    pub granted_by: Pubkey,
+    /// Timestamp when granted
+    pub granted_at: i64,
+    /// Account layout version
+    pub version: u8,
+    /// Reserved for future fields
+    pub reserved: [u8; ACCOUNT_RESERVED_SPACE],
+}
+
+/// An administrative action that can be executed through a multisig proposal
+/// or a timelocked operation
+/// 
+/// Mirrors the single-authority admin instructions so that, in multisig or
+/// timelock mode, the same state changes are applied once enough signers have
+/// approved and/or the delay has elapsed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum AdminAction {
+    /// Register a new attester (see `register_attester`)
+    RegisterAttester { attester: Pubkey },
+    /// Register a new Ethereum-key attester (see `register_secp256k1_attester`)
+    RegisterSecp256k1Attester { eth_address: [u8; 20] },
+    /// Register a new P-256 attester (see `register_secp256r1_attester`)
+    RegisterSecp256r1Attester { public_key: [u8; 33] },
+    /// Deregister an attester and close its account (see `deregister_attester`)
+    DeregisterAttester { attester: Pubkey },
+    /// Propose a new registry authority (see `propose_authority`)
+    ProposeAuthority { new_authority: Pubkey },
+    /// Cancel a pending authority transfer (see `cancel_authority_transfer`)
+    CancelAuthorityTransfer,
+    /// Replace the multisig signer set and threshold (threshold 0 disables multisig mode)
+    ConfigureMultisig {
+        #[max_len(MAX_MULTISIG_SIGNERS)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    /// Change the timelock delay (0 disables the timelock)
+    SetTimelockDelay { delay: i64 },
+    /// Resume attestation validation (see `unpause`)
+    Unpause,
+    /// Set or clear the pause guardian (see `set_guardian`)
+    SetGuardian { guardian: Option<Pubkey> },
+    /// Grant a role to a key (see `grant_role`)
+    GrantRole { role: Role, holder: Pubkey },
+    /// Revoke a role from a key and close its role account (see `revoke_role`)
+    RevokeRole { role: Role, holder: Pubkey },
+    /// Rotate an attester to a new key (see `rotate_attester_key`)
+    RotateAttesterKey { attester: Pubkey, new_key: Pubkey, overlap: i64 },
+    /// Set an attester's validity window (see `set_attester_validity`)
+    SetAttesterValidity { attester: Pubkey, valid_from: i64, valid_until: i64 },
+    /// Suspend an attester without closing its account (see `suspend_attester`)
+    SuspendAttester { attester: Pubkey },
+    /// Reinstate a suspended attester (see `reinstate_attester`)
+    ReinstateAttester { attester: Pubkey },
+    /// Reject an attester's attestations issued after a cutoff (see `revoke_attester_after`)
+    RevokeAttesterAfter { attester: Pubkey, timestamp: i64 },
+    /// Change the clock drift buffer and maximum attestation lifetime (see `set_attestation_config`)
+    SetAttestationConfig { clock_drift_buffer: i64, max_attestation_lifetime: i64 },
+    /// Change the self-registration bond, unbonding period and minimum collateral (see `set_bond_config`)
+    SetBondConfig { attester_bond: u64, unbonding_period: i64, min_attester_collateral: u64 },
+}
+
+/// Account for a pending multisig admin proposal
+/// 
+/// Created by a multisig signer, collects approvals from other signers and
+/// is closed (rent returned to the proposer) once executed.
+#[account]
+#[derive(InitSpace)]
+pub struct AdminProposal {
+    /// The registry this proposal administers
+    pub registry: Pubkey,
+    /// Sequential proposal identifier (PDA seed)
+    pub proposal_id: u64,
+    /// The multisig signer who created the proposal
+    pub proposer: Pubkey,
+    /// The action to apply once the threshold is met
+    pub action: AdminAction,
+    /// Signers who have approved this proposal
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    /// Timestamp when the proposal was created
+    pub created_at: i64,
+    /// Earliest execution time when the registry timelock applies (0 = not yet queued)
+    pub eta: i64,
+}
+
+/// Account for an admin operation queued behind the registry timelock
+/// 
+/// Created by the authority, executable by anyone once `eta` has passed and
+/// cancellable by the authority until then. Closed on execution or
+/// cancellation, returning rent to the authority that queued it.
+#[account]
+#[derive(InitSpace)]
+pub struct QueuedOperation {
+    /// The registry this operation administers
+    pub registry: Pubkey,
+    /// Sequential operation identifier (PDA seed)
+    pub operation_id: u64,
+    /// The authority that queued the operation
+    pub queued_by: Pubkey,
+    /// The action to apply once the delay has elapsed
+    pub action: AdminAction,
+    /// Timestamp when the operation was queued
+    pub queued_at: i64,
+    /// Earliest timestamp at which the operation can execute
+    pub eta: i64,
 }
 
 /// Account for tracking used attestations to prevent replay attacks
@@ -70,6 +558,29 @@ pub struct UsedUuidAccount {
     pub used_at: i64,
     /// Who performed the validation (the transaction signer)
     pub signer: Pubkey,
+    /// The attesters that co-signed a threshold attestation alongside
+    /// `attestation.attester`, in signature order (empty for single attestations)
+    #[max_len(MAX_CO_SIGNERS)]
+    pub co_signers: Vec<Pubkey>,
+}
+
+impl UsedUuidAccount {
+    /// Check whether an attester signed the used attestation
+    /// 
+    /// Matches the attester's current key or its identity (first key) against
+    /// the recorded attester and co-signers, so an attestation signed before
+    /// a single rotation still counts as the identity's own.
+    /// 
+    /// # Arguments
+    /// * `attester_account` - The attester to look for
+    /// 
+    /// # Returns
+    /// * `true` if the attester signed the attestation or co-signed it
+    pub fn is_signed_by(&self, attester_account: &AttesterAccount) -> bool {
+        [attester_account.attester, attester_account.identity]
+            .iter()
+            .any(|key| self.attestation.attester == *key || self.co_signers.contains(key))
+    }
 }
 
 
@@ -91,6 +602,8 @@ pub struct Statement {
     pub policy_id: String,
     /// Expiration timestamp
     pub expiration: i64,
+    /// Timestamp at which the statement was attested
+    pub issued_at: i64,
 }
 
 /// Attestation structure matching the Solidity version
@@ -100,6 +613,7 @@ pub struct Statement {
 /// - A unique UUID for replay protection
 /// - The attester's identity and signature
 /// - An expiration timestamp after which the attestation is no longer valid
+/// - An issued-at timestamp, checked against the attester's revocation cutoff
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct Attestation {
     /// Unique identifier matching the statement (UUID as 16 bytes)
@@ -107,9 +621,36 @@ pub struct Attestation {
     /// The attester's public key
     pub attester: Pubkey,
     /// The signature from the attester
-    pub signature: [u8; 64], // Ed25519 signature
+    pub signature: [u8; 64], // Ed25519, secp256k1 without the recovery id, or secp256r1 (low-S)
     /// Expiration timestamp
     pub expiration: i64,
+    /// Timestamp at which the attester issued the attestation
+    pub issued_at: i64,
+}
+
+/// One attester's signature over a statement in a threshold validation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct AttesterSignature {
+    /// The attester's public key
+    pub attester: Pubkey,
+    /// The attester's signature over the statement hash (Ed25519, secp256k1 without the recovery id, or secp256r1)
+    pub signature: [u8; 64],
+}
+
+/// A statement signed by several attesters, for threshold validation
+/// 
+/// The threshold counterpart of `Attestation`: every signature covers the
+/// same statement, so the UUID, expiration and issue time are shared.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ThresholdAttestation {
+    /// Unique identifier matching the statement (UUID as 16 bytes)
+    pub uuid: [u8; 16],
+    /// Expiration timestamp
+    pub expiration: i64,
+    /// Timestamp at which the attesters issued the attestation
+    pub issued_at: i64,
+    /// Each attester's signature, in Ed25519 instruction order
+    pub signatures: Vec<AttesterSignature>,
 }
 
 impl Attestation {
@@ -120,12 +661,13 @@ impl Attestation {
     /// 
     /// # Arguments
     /// * `current_timestamp` - The current Unix timestamp to check against
+    /// * `clock_drift_buffer` - The registry's configured drift buffer in seconds
     /// 
     /// # Returns
     /// * `true` if the attestation is still valid for use
     /// * `false` if the attestation has expired
-    pub fn is_valid_at(&self, current_timestamp: i64) -> bool {
-        current_timestamp <= self.expiration + crate::instructions::CLOCK_DRIFT_BUFFER
+    pub fn is_valid_at(&self, current_timestamp: i64, clock_drift_buffer: i64) -> bool {
+        current_timestamp <= self.expiration.saturating_add(clock_drift_buffer)
     }
 
     /// Check if the attestation has fully expired at the given timestamp
@@ -136,12 +678,48 @@ impl Attestation {
     /// 
     /// # Arguments
     /// * `current_timestamp` - The current Unix timestamp to check against
+    /// * `clock_drift_buffer` - The registry's configured drift buffer in seconds
     /// 
     /// # Returns
     /// * `true` if the attestation has fully expired (safe for cleanup)
     /// * `false` if the attestation is still within its validity window
-    pub fn is_expired(&self, current_timestamp: i64) -> bool {
-        current_timestamp > self.expiration + crate::instructions::CLOCK_DRIFT_BUFFER
+    pub fn is_expired(&self, current_timestamp: i64, clock_drift_buffer: i64) -> bool {
+        !self.is_valid_at(current_timestamp, clock_drift_buffer)
+    }
+
+    /// Check if the attestation expires further in the future than allowed
+    /// 
+    /// Bounds how long a leaked attestation can remain usable and how long its
+    /// used UUID account must be kept before cleanup.
+    /// 
+    /// # Arguments
+    /// * `current_timestamp` - The current Unix timestamp to check against
+    /// * `max_lifetime` - The registry's configured maximum lifetime in seconds
+    pub fn exceeds_lifetime(&self, current_timestamp: i64, max_lifetime: i64) -> bool {
+        self.expiration.saturating_sub(current_timestamp) > max_lifetime
+    }
+
+    /// Check that the issued-at timestamp is plausible
+    /// 
+    /// The attestation must not be issued in the future (beyond the clock
+    /// drift buffer) or after it expires, and must not outlive its issue time
+    /// by more than the maximum lifetime. The last bound means an attestation
+    /// backdated to before a revocation cutoff expires within one lifetime of
+    /// that cutoff.
+    /// 
+    /// # Arguments
+    /// * `current_timestamp` - The current Unix timestamp to check against
+    /// * `clock_drift_buffer` - The registry's configured drift buffer in seconds
+    /// * `max_lifetime` - The registry's configured maximum lifetime in seconds
+    pub fn has_valid_issued_at(
+        &self,
+        current_timestamp: i64,
+        clock_drift_buffer: i64,
+        max_lifetime: i64,
+    ) -> bool {
+        self.issued_at <= current_timestamp.saturating_add(clock_drift_buffer)
+            && self.issued_at <= self.expiration
+            && self.expiration.saturating_sub(self.issued_at) <= max_lifetime
     }
 
     /// Format UUID with standard dashes (8-4-4-4-12 format)
@@ -159,18 +737,259 @@ impl Attestation {
 
 impl PredicateRegistry {
     /// Initialize a new registry with default values
-    pub fn initialize(&mut self, authority: Pubkey, clock: &Clock) -> Result<()> {
+    pub fn initialize(&mut self, authority: Pubkey, registry_id: u64, bump: u8, clock: &Clock) -> Result<()> {
+        self.registry_id = registry_id;
+        self.bump = bump;
         self.authority = authority;
+        self.pending_authority = None;
         self.created_at = clock.unix_timestamp;
         self.updated_at = clock.unix_timestamp;
         self.total_attesters = 0;
         self.total_policies = 0;
+        self.multisig_signers = Vec::new();
+        self.multisig_threshold = 0;
+        self.proposal_count = 0;
+        self.timelock_delay = 0;
+        self.operation_count = 0;
+        self.paused = false;
+        self.guardian = None;
+        self.clock_drift_buffer = DEFAULT_CLOCK_DRIFT_BUFFER;
+        self.max_attestation_lifetime = DEFAULT_MAX_ATTESTATION_LIFETIME;
+        self.version = CURRENT_ACCOUNT_VERSION;
+        self.attester_bond = 0;
+        self.unbonding_period = 0;
+        self.min_attester_collateral = 0;
+        self.attester_index_len = 0;
+        self.reserved = [0; REGISTRY_RESERVED_SPACE];
+        Ok(())
+    }
+
+    /// Seed identifying the registry with the given ID in its PDA
+    ///
+    /// Empty for registry 0, which keeps the original `[b"predicate_registry"]`
+    /// address from before registries were namespaced; other IDs use their
+    /// little-endian bytes.
+    pub fn id_seed(registry_id: u64) -> Vec<u8> {
+        if registry_id == 0 {
+            Vec::new()
+        } else {
+            registry_id.to_le_bytes().to_vec()
+        }
+    }
+
+    /// Seed scoping child account PDAs (attesters, policies, used UUIDs, ...) to this registry
+    ///
+    /// `key` is this registry's address. Empty for registry 0, whose children
+    /// keep their original un-namespaced addresses (e.g. `[b"attester",
+    /// attester]`); other registries use their address.
+    pub fn namespace_seed(&self, key: &Pubkey) -> Vec<u8> {
+        if self.registry_id == 0 {
+            Vec::new()
+        } else {
+            key.to_bytes().to_vec()
+        }
+    }
+
+    /// Whether admin actions must go through multisig proposals
+    pub fn is_multisig_enabled(&self) -> bool {
+        self.multisig_threshold > 0
+    }
+
+    /// Whether the given key is a member of the multisig signer set
+    pub fn is_multisig_signer(&self, key: &Pubkey) -> bool {
+        self.multisig_signers.contains(key)
+    }
+
+    /// Validate a multisig signer set and threshold
+    /// 
+    /// A threshold of 0 with an empty signer set disables multisig mode.
+    /// Otherwise the set must be non-empty, free of duplicates and the zero
+    /// address, and the threshold must be reachable.
+    pub fn validate_multisig_config(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        if threshold == 0 {
+            require!(signers.is_empty(), crate::PredicateRegistryError::InvalidMultisigConfig);
+            return Ok(());
+        }
+        require!(
+            signers.len() <= MAX_MULTISIG_SIGNERS,
+            crate::PredicateRegistryError::TooManyMultisigSigners
+        );
+        require!(
+            (threshold as usize) <= signers.len(),
+            crate::PredicateRegistryError::InvalidMultisigConfig
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(
+                *signer != Pubkey::default() && !signers[..i].contains(signer),
+                crate::PredicateRegistryError::InvalidMultisigConfig
+            );
+        }
+        Ok(())
+    }
+
+    /// Replace the multisig signer set and threshold
+    pub fn configure_multisig(&mut self, signers: Vec<Pubkey>, threshold: u8, clock: &Clock) -> Result<()> {
+        Self::validate_multisig_config(&signers, threshold)?;
+        self.multisig_signers = signers;
+        self.multisig_threshold = threshold;
+        self.updated_at = clock.unix_timestamp;
         Ok(())
     }
 
+    /// Whether admin actions must be queued behind the timelock
+    pub fn is_timelock_enabled(&self) -> bool {
+        self.timelock_delay > 0
+    }
+
+    /// Validate a timelock delay
+    pub fn validate_timelock_delay(delay: i64) -> Result<()> {
+        require!(
+            (0..=MAX_TIMELOCK_DELAY).contains(&delay),
+            crate::PredicateRegistryError::InvalidTimelockDelay
+        );
+        Ok(())
+    }
+
+    /// Set the timelock delay
+    pub fn set_timelock_delay(&mut self, delay: i64, clock: &Clock) -> Result<()> {
+        Self::validate_timelock_delay(delay)?;
+        self.timelock_delay = delay;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Validate an attestation config
+    /// 
+    /// The drift buffer may be zero but not negative; the maximum lifetime
+    /// must be positive. Both are capped to keep expirations bounded.
+    pub fn validate_attestation_config(clock_drift_buffer: i64, max_attestation_lifetime: i64) -> Result<()> {
+        require!(
+            (0..=MAX_CLOCK_DRIFT_BUFFER).contains(&clock_drift_buffer)
+                && (1..=MAX_ATTESTATION_LIFETIME).contains(&max_attestation_lifetime),
+            crate::PredicateRegistryError::InvalidAttestationConfig
+        );
+        Ok(())
+    }
+
+    /// Set the clock drift buffer and maximum attestation lifetime
+    pub fn set_attestation_config(&mut self, clock_drift_buffer: i64, max_attestation_lifetime: i64, clock: &Clock) -> Result<()> {
+        Self::validate_attestation_config(clock_drift_buffer, max_attestation_lifetime)?;
+        self.clock_drift_buffer = clock_drift_buffer;
+        self.max_attestation_lifetime = max_attestation_lifetime;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Validate a self-registration bond config
+    /// 
+    /// Any bond is accepted (0 disables self-registration); the unbonding
+    /// period may be zero but is capped.
+    pub fn validate_bond_config(unbonding_period: i64) -> Result<()> {
+        require!(
+            (0..=MAX_UNBONDING_PERIOD).contains(&unbonding_period),
+            crate::PredicateRegistryError::InvalidBondConfig
+        );
+        Ok(())
+    }
+
+    /// Set the self-registration bond, unbonding period and minimum collateral
+    pub fn set_bond_config(
+        &mut self,
+        attester_bond: u64,
+        unbonding_period: i64,
+        min_attester_collateral: u64,
+        clock: &Clock,
+    ) -> Result<()> {
+        Self::validate_bond_config(unbonding_period)?;
+        self.attester_bond = attester_bond;
+        self.unbonding_period = unbonding_period;
+        self.min_attester_collateral = min_attester_collateral;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Whether attesters may register themselves by posting a bond
+    pub fn is_self_registration_enabled(&self) -> bool {
+        self.attester_bond > 0
+    }
+
+    /// Earliest execution time for an action queued now
+    pub fn timelock_eta(&self, clock: &Clock) -> Result<i64> {
+        clock.unix_timestamp.checked_add(self.timelock_delay)
+            .ok_or(crate::PredicateRegistryError::ArithmeticError.into())
+    }
+
+    /// Whether the given key may pause the registry
+    /// 
+    /// No one can pause a renounced registry. Otherwise the guardian can always pause. Otherwise pausing follows the admin
+    /// mode: any multisig signer in multisig mode, the authority otherwise.
+    /// Pausing is never timelocked.
+    pub fn can_pause(&self, key: &Pubkey) -> bool {
+        if self.is_renounced() {
+            return false;
+        }
+        if self.guardian == Some(*key) {
+            return true;
+        }
+        if self.is_multisig_enabled() {
+            self.is_multisig_signer(key)
+        } else {
+            self.authority == *key
+        }
+    }
+
+    /// Halt attestation validation
+    pub fn pause(&mut self, clock: &Clock) -> Result<()> {
+        require!(!self.paused, crate::PredicateRegistryError::RegistryAlreadyPaused);
+        self.paused = true;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Resume attestation validation
+    pub fn unpause(&mut self, clock: &Clock) -> Result<()> {
+        require!(self.paused, crate::PredicateRegistryError::RegistryNotPaused);
+        self.paused = false;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Set or clear the pause guardian, returning the previous guardian
+    pub fn set_guardian(&mut self, guardian: Option<Pubkey>, clock: &Clock) -> Result<Option<Pubkey>> {
+        require!(
+            guardian != Some(Pubkey::default()),
+            crate::PredicateRegistryError::InvalidAuthority
+        );
+        let previous_guardian = self.guardian;
+        self.guardian = guardian;
+        self.updated_at = clock.unix_timestamp;
+        Ok(previous_guardian)
+    }
+
+    /// Allocate the next operation identifier
+    pub fn next_operation_id(&mut self) -> Result<u64> {
+        let operation_id = self.operation_count;
+        self.operation_count = self.operation_count.checked_add(1)
+            .ok_or(crate::PredicateRegistryError::ArithmeticError)?;
+        Ok(operation_id)
+    }
+
+    /// Allocate the next proposal identifier
+    pub fn next_proposal_id(&mut self) -> Result<u64> {
+        let proposal_id = self.proposal_count;
+        self.proposal_count = self.proposal_count.checked_add(1)
+            .ok_or(crate::PredicateRegistryError::ArithmeticError)?;
+        Ok(proposal_id)
+    }
+
     /// Increment the attester count
     pub fn increment_attester_count(&mut self, clock: &Clock) -> Result<()> {
-        self.total_attesters = self.total_attesters.checked_add(1)
+        self.increment_attester_count_by(1, clock)
+    }
+
+    /// Increase the attester count by a batch of registrations
+    pub fn increment_attester_count_by(&mut self, count: u64, clock: &Clock) -> Result<()> {
+        self.total_attesters = self.total_attesters.checked_add(count)
             .ok_or(crate::PredicateRegistryError::ArithmeticError)?;
         self.updated_at = clock.unix_timestamp;
         Ok(())
@@ -178,12 +997,80 @@ impl PredicateRegistry {
 
     /// Decrement the attester count
     pub fn decrement_attester_count(&mut self, clock: &Clock) -> Result<()> {
-        self.total_attesters = self.total_attesters.checked_sub(1)
+        self.decrement_attester_count_by(1, clock)
+    }
+
+    /// Decrease the attester count by a batch of deregistrations
+    pub fn decrement_attester_count_by(&mut self, count: u64, clock: &Clock) -> Result<()> {
+        self.total_attesters = self.total_attesters.checked_sub(count)
             .ok_or(crate::PredicateRegistryError::ArithmeticError)?;
         self.updated_at = clock.unix_timestamp;
         Ok(())
     }
 
+    /// Page the next attester index entry is appended to
+    pub fn attester_index_tail_page(&self) -> u32 {
+        (self.attester_index_len / ATTESTER_INDEX_PAGE_SIZE as u64) as u32
+    }
+
+    /// Page holding the last attester index entry (0 while the index is empty)
+    pub fn attester_index_last_page(&self) -> u32 {
+        (self.attester_index_len.saturating_sub(1) / ATTESTER_INDEX_PAGE_SIZE as u64) as u32
+    }
+
+    /// Append an attester to the attester index
+    /// 
+    /// `tail_page` must be the page at `attester_index_tail_page()`, which is
+    /// initialized on first use. Returns the page the attester was listed on.
+    pub fn index_attester(
+        &mut self,
+        registry: Pubkey,
+        attester_account: &mut AttesterAccount,
+        tail_page: &mut AttesterIndexPage,
+    ) -> Result<u32> {
+        require!(!attester_account.indexed, crate::PredicateRegistryError::AttesterAlreadyIndexed);
+        let page = self.attester_index_tail_page();
+        tail_page.push(registry, page, attester_account.attester)?;
+        self.attester_index_len = self.attester_index_len.checked_add(1)
+            .ok_or(crate::PredicateRegistryError::ArithmeticError)?;
+        attester_account.indexed = true;
+        Ok(page)
+    }
+
+    /// Remove an attester from the attester index
+    /// 
+    /// `page` is the page listing the attester. Unless that is the last page,
+    /// `tail_page` must be the last page, whose last entry is moved into the
+    /// freed slot to keep the index dense. Returns the page the attester was
+    /// removed from.
+    pub fn unindex_attester(
+        &mut self,
+        attester_account: &mut AttesterAccount,
+        page: &mut AttesterIndexPage,
+        tail_page: Option<&mut AttesterIndexPage>,
+    ) -> Result<u32> {
+        require!(attester_account.indexed, crate::PredicateRegistryError::AttesterNotIndexed);
+        let last_page = self.attester_index_last_page();
+        match tail_page {
+            None => {
+                require!(page.page == last_page, crate::PredicateRegistryError::AttesterIndexMismatch);
+                page.remove(&attester_account.attester, None)?;
+            }
+            Some(tail_page) => {
+                require!(
+                    page.page != last_page && tail_page.page == last_page,
+                    crate::PredicateRegistryError::AttesterIndexMismatch
+                );
+                let moved = tail_page.pop()?;
+                page.remove(&attester_account.attester, Some(moved))?;
+            }
+        }
+        self.attester_index_len = self.attester_index_len.checked_sub(1)
+            .ok_or(crate::PredicateRegistryError::ArithmeticError)?;
+        attester_account.indexed = false;
+        Ok(page.page)
+    }
+
     /// Increment the policy count
     pub fn increment_policy_count(&mut self, clock: &Clock) -> Result<()> {
         self.total_policies = self.total_policies.checked_add(1)
@@ -192,12 +1079,64 @@ impl PredicateRegistry {
         Ok(())
     }
 
-    /// Transfer authority to a new account
-    pub fn transfer_authority(&mut self, new_authority: Pubkey, clock: &Clock) -> Result<()> {
-        self.authority = new_authority;
+    /// Propose a new authority, replacing any previously pending proposal
+    pub fn propose_authority(&mut self, new_authority: Pubkey, clock: &Clock) -> Result<()> {
+        require!(
+            new_authority != Pubkey::default(),
+            crate::PredicateRegistryError::InvalidAuthority
+        );
+        self.pending_authority = Some(new_authority);
         self.updated_at = clock.unix_timestamp;
         Ok(())
     }
+
+    /// Complete a pending transfer, making the pending authority the new authority
+    ///
+    /// Returns the previous authority.
+    pub fn accept_authority(&mut self, new_authority: Pubkey, clock: &Clock) -> Result<Pubkey> {
+        require!(
+            self.pending_authority == Some(new_authority),
+            crate::PredicateRegistryError::NotPendingAuthority
+        );
+        let previous_authority = self.authority;
+        self.authority = new_authority;
+        self.pending_authority = None;
+        self.updated_at = clock.unix_timestamp;
+        Ok(previous_authority)
+    }
+
+    /// Cancel a pending transfer
+    ///
+    /// Returns the authority whose proposal was cancelled.
+    pub fn cancel_authority_transfer(&mut self, clock: &Clock) -> Result<Pubkey> {
+        let cancelled = self.pending_authority
+            .take()
+            .ok_or(crate::PredicateRegistryError::NoPendingAuthorityTransfer)?;
+        self.updated_at = clock.unix_timestamp;
+        Ok(cancelled)
+    }
+
+    /// Whether the registry authority has been renounced
+    pub fn is_renounced(&self) -> bool {
+        self.authority == RENOUNCED_AUTHORITY
+    }
+
+    /// Permanently give up the registry authority
+    ///
+    /// Also clears the pending authority and guardian so that no key can
+    /// regain control or halt validation afterwards. Refuses while paused,
+    /// since a renounced registry could never be unpaused.
+    ///
+    /// Returns the previous authority.
+    pub fn renounce_authority(&mut self, clock: &Clock) -> Result<Pubkey> {
+        require!(!self.paused, crate::PredicateRegistryError::RegistryPaused);
+        let previous_authority = self.authority;
+        self.authority = RENOUNCED_AUTHORITY;
+        self.pending_authority = None;
+        self.guardian = None;
+        self.updated_at = clock.unix_timestamp;
+        Ok(previous_authority)
+    }
 }
 
 impl AttesterAccount {
@@ -206,12 +1145,250 @@ impl AttesterAccount {
         self.attester = attester;
         self.is_registered = true;
         self.registered_at = clock.unix_timestamp;
+        self.version = CURRENT_ATTESTER_VERSION;
+        self.valid_from = 0;
+        self.valid_until = 0;
+        self.identity = attester;
+        self.previous_key = None;
+        self.next_key = None;
+        self.scoped = false;
+        self.bond = 0;
+        self.is_suspended = false;
+        self.suspension_count = 0;
+        self.status_changed_at = clock.unix_timestamp;
+        self.revoked_after = 0;
+        self.indexed = false;
+        self.key_type = AttesterKeyType::Ed25519;
+        self.reserved = [0; ATTESTER_RESERVED_SPACE];
+        Ok(())
+    }
+
+    /// Initialize a new attester account for an Ethereum (secp256k1) key
+    pub fn initialize_secp256k1(&mut self, eth_address: &[u8; 20], clock: &Clock) -> Result<()> {
+        require!(*eth_address != [0; 20], crate::PredicateRegistryError::InvalidAuthority);
+        self.initialize(Self::secp256k1_key(eth_address), clock)?;
+        self.key_type = AttesterKeyType::Secp256k1;
+        Ok(())
+    }
+
+    /// The attester key identifying an Ethereum address (left-padded with zeros)
+    pub fn secp256k1_key(eth_address: &[u8; 20]) -> Pubkey {
+        let mut key = [0u8; 32];
+        key[12..].copy_from_slice(eth_address);
+        Pubkey::new_from_array(key)
+    }
+
+    /// The Ethereum address identified by a secp256k1 attester key
+    pub fn eth_address(key: &Pubkey) -> [u8; 20] {
+        let mut eth_address = [0u8; 20];
+        eth_address.copy_from_slice(&key.as_ref()[12..]);
+        eth_address
+    }
+
+    /// Initialize a new attester account for a P-256 (secp256r1) key
+    pub fn initialize_secp256r1(&mut self, public_key: &[u8; 33], clock: &Clock) -> Result<()> {
+        Self::validate_secp256r1_key(public_key)?;
+        self.initialize(Self::secp256r1_key(public_key), clock)?;
+        self.key_type = AttesterKeyType::Secp256r1;
         Ok(())
     }
 
+    /// Validate a SEC1 compressed P-256 public key
+    /// 
+    /// Checks the encoding and that the x-coordinate is a non-zero field
+    /// element. Whether it lies on the curve is left to the secp256r1
+    /// program: decompressing it on-chain needs a 256-bit modular square
+    /// root, too costly for a registration, and the program rejects every
+    /// signature for an off-curve key, so such an attester can never pass
+    /// validation.
+    pub fn validate_secp256r1_key(public_key: &[u8; 33]) -> Result<()> {
+        require!(
+            matches!(public_key[0], 0x02 | 0x03)
+                && AttesterKeyType::Secp256r1.validate_key(&Self::secp256r1_key(public_key)).is_ok(),
+            crate::PredicateRegistryError::InvalidAuthority
+        );
+        Ok(())
+    }
+
+    /// The attester key identifying a compressed P-256 public key (its x-coordinate)
+    pub fn secp256r1_key(public_key: &[u8; 33]) -> Pubkey {
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&public_key[1..]);
+        Pubkey::new_from_array(key)
+    }
+
+    /// Validate a key rotation overlap window
+    pub fn validate_rotation_overlap(overlap: i64) -> Result<()> {
+        require!(
+            (0..=MAX_ROTATION_OVERLAP).contains(&overlap),
+            crate::PredicateRegistryError::InvalidRotationOverlap
+        );
+        Ok(())
+    }
+
+    /// Rotate this attester to a new key
+    /// 
+    /// Initializes `new_account` for `new_key` under the same identity and
+    /// key type, keeping the original `registered_at` and validity window
+    /// (start and end dates), and ends
+    /// this key's validity `overlap` seconds from now (or at its existing end
+    /// date, if sooner). Both keys are accepted during the overlap. The bond moves to
+    /// the new key along with the identity's vault, so under a minimum
+    /// collateral only the new key keeps being accepted.
+    /// 
+    /// Returns the timestamp until which the old key remains valid.
+    pub fn rotate_to(
+        &mut self,
+        new_account: &mut AttesterAccount,
+        new_key: Pubkey,
+        overlap: i64,
+        clock: &Clock,
+    ) -> Result<i64> {
+        require!(self.is_registered, crate::PredicateRegistryError::AttesterNotRegistered);
+        require!(self.next_key.is_none(), crate::PredicateRegistryError::AttesterAlreadyRotated);
+        require!(
+            new_key != self.attester && new_key != Pubkey::default(),
+            crate::PredicateRegistryError::InvalidAuthority
+        );
+        self.key_type.validate_key(&new_key)?;
+        Self::validate_rotation_overlap(overlap)?;
+
+        let overlap_end = clock.unix_timestamp.checked_add(overlap)
+            .ok_or(crate::PredicateRegistryError::ArithmeticError)?;
+        let old_key_valid_until = if self.valid_until == 0 {
+            overlap_end
+        } else {
+            self.valid_until.min(overlap_end)
+        };
+
+        new_account.initialize(new_key, clock)?;
+        new_account.key_type = self.key_type;
+        new_account.registered_at = self.registered_at;
+        new_account.valid_from = self.valid_from;
+        new_account.valid_until = self.valid_until;
+        new_account.identity = self.identity;
+        new_account.previous_key = Some(self.attester);
+        new_account.scoped = self.scoped;
+        new_account.suspension_count = self.suspension_count;
+        new_account.bond = self.bond;
+
+        self.bond = 0;
+        self.valid_until = old_key_valid_until;
+        self.next_key = Some(new_key);
+        Ok(old_key_valid_until)
+    }
+
+    /// Validate a batch of attester keys
+    /// 
+    /// Batches must hold between 1 and `MAX_ATTESTER_BATCH` distinct keys,
+    /// none of them the zero address.
+    pub fn validate_batch(attesters: &[Pubkey]) -> Result<()> {
+        require!(
+            !attesters.is_empty() && attesters.len() <= MAX_ATTESTER_BATCH,
+            crate::PredicateRegistryError::InvalidAttesterBatch
+        );
+        for (i, attester) in attesters.iter().enumerate() {
+            require!(
+                *attester != Pubkey::default() && !attesters[..i].contains(attester),
+                crate::PredicateRegistryError::InvalidAttesterBatch
+            );
+        }
+        Ok(())
+    }
+
+    /// Validate an attester validity window
+    /// 
+    /// Bounds must be non-negative, with 0 meaning unbounded. When both are
+    /// set, `valid_from` must not be after `valid_until`.
+    pub fn validate_validity(valid_from: i64, valid_until: i64) -> Result<()> {
+        require!(
+            valid_from >= 0
+                && valid_until >= 0
+                && (valid_from == 0 || valid_until == 0 || valid_from <= valid_until),
+            crate::PredicateRegistryError::InvalidAttesterValidity
+        );
+        Ok(())
+    }
+
+    /// Set the attester's validity window
+    pub fn set_validity(&mut self, valid_from: i64, valid_until: i64) -> Result<()> {
+        Self::validate_validity(valid_from, valid_until)?;
+        self.valid_from = valid_from;
+        self.valid_until = valid_until;
+        Ok(())
+    }
+
+    /// Whether the attester's validity window has started at the given timestamp
+    pub fn has_started_at(&self, current_timestamp: i64) -> bool {
+        self.valid_from == 0 || current_timestamp >= self.valid_from
+    }
+
+    /// Whether the attester's validity window has ended at the given timestamp
+    pub fn has_ended_at(&self, current_timestamp: i64) -> bool {
+        self.valid_until != 0 && current_timestamp > self.valid_until
+    }
+
+    /// Revoke every attestation the attester issues after `timestamp`
+    /// 
+    /// A cutoff can only be tightened once set, so a revocation cannot be
+    /// undone by a later call.
+    pub fn revoke_after(&mut self, timestamp: i64) -> Result<()> {
+        require!(
+            timestamp > 0 && (self.revoked_after == 0 || timestamp <= self.revoked_after),
+            crate::PredicateRegistryError::InvalidRevocationCutoff
+        );
+        self.revoked_after = timestamp;
+        Ok(())
+    }
+
+    /// Whether an attestation issued at the given timestamp falls after the revocation cutoff
+    pub fn is_revoked_for(&self, issued_at: i64) -> bool {
+        self.revoked_after != 0 && issued_at > self.revoked_after
+    }
+
+    /// Whether the attester is registered or suspended, i.e. still counted by the registry
+    pub fn is_listed(&self) -> bool {
+        self.is_registered || self.is_suspended
+    }
+
+    /// Who receives the attester's bond and collateral when it is withdrawn
+    /// 
+    /// Ed25519 attesters receive their own bond. Other key types cannot sign
+    /// Solana transactions, so only the registry authority can have funded
+    /// them, and it is refunded instead.
+    pub fn bond_recipient(&self, authority: &Pubkey) -> Pubkey {
+        match self.key_type {
+            AttesterKeyType::Ed25519 => self.attester,
+            AttesterKeyType::Secp256k1 | AttesterKeyType::Secp256r1 => *authority,
+        }
+    }
+
     /// Deregister the attester
     pub fn deregister(&mut self) -> Result<()> {
         self.is_registered = false;
+        self.is_suspended = false;
+        Ok(())
+    }
+
+    /// Suspend the attester, keeping its account and history
+    /// 
+    /// Returns the updated suspension count.
+    pub fn suspend(&mut self, clock: &Clock) -> Result<u32> {
+        require!(self.is_registered, crate::PredicateRegistryError::AttesterNotRegistered);
+        self.suspension_count = self.suspension_count.checked_add(1)
+            .ok_or(crate::PredicateRegistryError::ArithmeticError)?;
+        self.is_registered = false;
+        self.is_suspended = true;
+        self.status_changed_at = clock.unix_timestamp;
+        Ok(self.suspension_count)
+    }
+
+    /// Reinstate a suspended attester, keeping its original `registered_at`
+    pub fn reinstate(&mut self, clock: &Clock) -> Result<()> {
+        require!(self.is_suspended, crate::PredicateRegistryError::AttesterNotSuspended);
+        self.is_registered = true;
+        self.is_suspended = false;
+        self.status_changed_at = clock.unix_timestamp;
         Ok(())
     }
 
@@ -223,6 +1400,438 @@ impl AttesterAccount {
     }
 }
 
+impl AttesterMetadata {
+    /// Validate metadata field lengths
+    /// 
+    /// Fields that are set must be non-empty and within their maximum length.
+    pub fn validate(&self) -> Result<()> {
+        for (field, max_len) in [
+            (&self.name, MAX_ATTESTER_NAME_LEN),
+            (&self.url, MAX_ATTESTER_URL_LEN),
+            (&self.contact, MAX_ATTESTER_CONTACT_LEN),
+            (&self.description, MAX_ATTESTER_DESCRIPTION_LEN),
+        ] {
+            if let Some(value) = field {
+                require!(
+                    !value.is_empty() && value.len() <= max_len,
+                    crate::PredicateRegistryError::InvalidAttesterMetadata
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AttesterMetadataAccount {
+    /// Set the attester's metadata, replacing any previous values
+    pub fn set(
+        &mut self,
+        attester: Pubkey,
+        metadata: AttesterMetadata,
+        updated_by: Pubkey,
+        clock: &Clock,
+    ) -> Result<()> {
+        metadata.validate()?;
+        self.attester = attester;
+        self.metadata = metadata;
+        self.updated_by = updated_by;
+        self.updated_at = clock.unix_timestamp;
+        self.version = CURRENT_ACCOUNT_VERSION;
+        Ok(())
+    }
+}
+
+impl AttesterBondAccount {
+    /// Record a freshly posted bond
+    pub fn initialize(&mut self, identity: Pubkey, amount: u64, clock: &Clock) -> Result<()> {
+        self.identity = identity;
+        self.amount = amount;
+        self.bonded_at = clock.unix_timestamp;
+        self.withdrawable_at = 0;
+        self.version = CURRENT_ACCOUNT_VERSION;
+        self.reserved = [0; ACCOUNT_RESERVED_SPACE];
+        Ok(())
+    }
+
+    /// Add collateral to the bond
+    pub fn deposit(&mut self, amount: u64) -> Result<u64> {
+        require!(!self.is_unbonding(), crate::PredicateRegistryError::AttesterAlreadyUnbonding);
+        self.amount = self.amount.checked_add(amount)
+            .ok_or(crate::PredicateRegistryError::ArithmeticError)?;
+        Ok(self.amount)
+    }
+
+    /// Remove slashed lamports from the bond, returning what remains
+    pub fn slash(&mut self, amount: u64) -> Result<u64> {
+        require!(
+            amount > 0 && amount <= self.amount,
+            crate::PredicateRegistryError::InvalidSlashAmount
+        );
+        self.amount -= amount;
+        Ok(self.amount)
+    }
+
+    /// Whether the attester has begun exiting
+    pub fn is_unbonding(&self) -> bool {
+        self.withdrawable_at != 0
+    }
+
+    /// Start the unbonding period, returning when the bond becomes withdrawable
+    pub fn begin_unbonding(&mut self, unbonding_period: i64, clock: &Clock) -> Result<i64> {
+        require!(!self.is_unbonding(), crate::PredicateRegistryError::AttesterAlreadyUnbonding);
+        // Never 0, which would read as "not exiting"
+        let withdrawable_at = clock.unix_timestamp.checked_add(unbonding_period)
+            .ok_or(crate::PredicateRegistryError::ArithmeticError)?
+            .max(1);
+        self.withdrawable_at = withdrawable_at;
+        Ok(withdrawable_at)
+    }
+
+    /// Whether the bond can be withdrawn at the given timestamp
+    pub fn is_withdrawable_at(&self, current_timestamp: i64) -> bool {
+        self.is_unbonding() && current_timestamp >= self.withdrawable_at
+    }
+}
+
+impl AttesterIndexPage {
+    /// Append an attester, initializing the page if it was just created
+    pub fn push(&mut self, registry: Pubkey, page: u32, attester: Pubkey) -> Result<()> {
+        if self.registry == Pubkey::default() {
+            self.registry = registry;
+            self.page = page;
+            self.attesters = Vec::new();
+            self.version = CURRENT_ACCOUNT_VERSION;
+            self.reserved = [0; ACCOUNT_RESERVED_SPACE];
+        }
+        require!(
+            self.page == page && self.attesters.len() < ATTESTER_INDEX_PAGE_SIZE,
+            crate::PredicateRegistryError::AttesterIndexMismatch
+        );
+        self.attesters.push(attester);
+        Ok(())
+    }
+
+    /// Remove and return the last attester on the page
+    pub fn pop(&mut self) -> Result<Pubkey> {
+        self.attesters
+            .pop()
+            .ok_or(crate::PredicateRegistryError::AttesterIndexMismatch.into())
+    }
+
+    /// Remove an attester, filling its slot with `replacement` if given
+    /// 
+    /// Without a replacement the page's own last entry fills the slot.
+    pub fn remove(&mut self, attester: &Pubkey, replacement: Option<Pubkey>) -> Result<()> {
+        let position = self.attesters
+            .iter()
+            .position(|key| key == attester)
+            .ok_or(crate::PredicateRegistryError::AttesterIndexMismatch)?;
+        match replacement {
+            Some(replacement) => self.attesters[position] = replacement,
+            None => {
+                self.attesters.swap_remove(position);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AttesterKeyType {
+    /// Check that `key` can identify an attester key of this type
+    /// 
+    /// Secp256k1 keys must be an Ethereum address left-padded with zeros (see
+    /// `AttesterAccount::secp256k1_key`), and P-256 x-coordinates non-zero
+    /// and below the field prime (see `AttesterAccount::validate_secp256r1_key`).
+    /// Ed25519 keys can be any 32 bytes.
+    pub fn validate_key(&self, key: &Pubkey) -> Result<()> {
+        match self {
+            AttesterKeyType::Ed25519 => {}
+            AttesterKeyType::Secp256k1 => require!(
+                key.as_ref()[..12] == [0; 12],
+                crate::PredicateRegistryError::InvalidRotationKey
+            ),
+            AttesterKeyType::Secp256r1 => require!(
+                *key != Pubkey::default() && key.to_bytes() < P256_FIELD_PRIME,
+                crate::PredicateRegistryError::InvalidRotationKey
+            ),
+        }
+        Ok(())
+    }
+}
+
+impl AttesterScope {
+    /// Validate scope list sizes and prefixes
+    /// 
+    /// Lists must be within their maximum size and free of duplicates, and
+    /// prefixes must be non-empty and no longer than a policy ID.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.programs.len() <= MAX_ATTESTER_SCOPE_PROGRAMS
+                && self.policy_prefixes.len() <= MAX_ATTESTER_SCOPE_PREFIXES,
+            crate::PredicateRegistryError::InvalidAttesterScope
+        );
+        for (i, program) in self.programs.iter().enumerate() {
+            require!(
+                !self.programs[..i].contains(program),
+                crate::PredicateRegistryError::InvalidAttesterScope
+            );
+        }
+        for (i, prefix) in self.policy_prefixes.iter().enumerate() {
+            require!(
+                !prefix.is_empty()
+                    && prefix.len() <= MAX_POLICY_ID_LEN
+                    && !self.policy_prefixes[..i].contains(prefix),
+                crate::PredicateRegistryError::InvalidAttesterScope
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether the scope places no restriction on the attester
+    pub fn is_empty(&self) -> bool {
+        self.programs.is_empty() && self.policy_prefixes.is_empty()
+    }
+
+    /// Whether an attestation for the given target and policy is in scope
+    pub fn permits(&self, target: &Pubkey, policy_id: &str) -> bool {
+        self.is_empty()
+            || self.programs.contains(target)
+            || self.policy_prefixes.iter().any(|prefix| policy_id.starts_with(prefix.as_str()))
+    }
+}
+
+impl AttesterScopeAccount {
+    /// Set the attester's scope, replacing any previous values
+    pub fn set(
+        &mut self,
+        identity: Pubkey,
+        scope: AttesterScope,
+        updated_by: Pubkey,
+        clock: &Clock,
+    ) -> Result<()> {
+        scope.validate()?;
+        self.identity = identity;
+        self.scope = scope;
+        self.updated_by = updated_by;
+        self.updated_at = clock.unix_timestamp;
+        self.version = CURRENT_ACCOUNT_VERSION;
+        Ok(())
+    }
+}
+
+impl AdminAction {
+    /// The attester targeted by this action, if any
+    /// 
+    /// Used to derive the attester PDA passed to `execute_proposal`.
+    pub fn attester(&self) -> Option<Pubkey> {
+        match self {
+            AdminAction::RegisterAttester { attester }
+            | AdminAction::DeregisterAttester { attester }
+            | AdminAction::SetAttesterValidity { attester, .. }
+            | AdminAction::SuspendAttester { attester }
+            | AdminAction::ReinstateAttester { attester }
+            | AdminAction::RevokeAttesterAfter { attester, .. }
+            | AdminAction::RotateAttesterKey { attester, .. } => Some(*attester),
+            AdminAction::RegisterSecp256k1Attester { eth_address } => {
+                Some(AttesterAccount::secp256k1_key(eth_address))
+            }
+            AdminAction::RegisterSecp256r1Attester { public_key } => {
+                Some(AttesterAccount::secp256r1_key(public_key))
+            }
+            _ => None,
+        }
+    }
+
+    /// The attester key whose account this action creates, if any
+    /// 
+    /// Used to derive the new attester PDA passed to `execute_proposal`.
+    pub fn new_attester(&self) -> Option<Pubkey> {
+        match self {
+            AdminAction::RegisterAttester { attester } => Some(*attester),
+            AdminAction::RegisterSecp256k1Attester { eth_address } => {
+                Some(AttesterAccount::secp256k1_key(eth_address))
+            }
+            AdminAction::RegisterSecp256r1Attester { public_key } => {
+                Some(AttesterAccount::secp256r1_key(public_key))
+            }
+            AdminAction::RotateAttesterKey { new_key, .. } => Some(*new_key),
+            _ => None,
+        }
+    }
+
+    /// The role and holder targeted by this action, if any
+    /// 
+    /// Used to derive the role PDA passed to `execute_proposal`.
+    pub fn role_grant(&self) -> Option<(Role, Pubkey)> {
+        match self {
+            AdminAction::GrantRole { role, holder }
+            | AdminAction::RevokeRole { role, holder } => Some((*role, *holder)),
+            _ => None,
+        }
+    }
+
+    /// Seed byte of the targeted role PDA (zero when the action has none)
+    pub fn role_seed(&self) -> [u8; 1] {
+        self.role_grant().map(|(role, _)| role.seed()).unwrap_or_default()
+    }
+
+    /// Holder of the targeted role PDA (default key when the action has none)
+    pub fn role_holder(&self) -> Pubkey {
+        self.role_grant().map(|(_, holder)| holder).unwrap_or_default()
+    }
+
+    /// Validate action parameters before a proposal is created
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            AdminAction::ProposeAuthority { new_authority } => {
+                require!(
+                    *new_authority != Pubkey::default(),
+                    crate::PredicateRegistryError::InvalidAuthority
+                );
+            }
+            AdminAction::ConfigureMultisig { signers, threshold } => {
+                PredicateRegistry::validate_multisig_config(signers, *threshold)?;
+            }
+            AdminAction::SetTimelockDelay { delay } => {
+                PredicateRegistry::validate_timelock_delay(*delay)?;
+            }
+            AdminAction::RotateAttesterKey { new_key, overlap, .. } => {
+                require!(
+                    *new_key != Pubkey::default(),
+                    crate::PredicateRegistryError::InvalidAuthority
+                );
+                AttesterAccount::validate_rotation_overlap(*overlap)?;
+            }
+            AdminAction::RegisterSecp256k1Attester { eth_address } => {
+                require!(*eth_address != [0; 20], crate::PredicateRegistryError::InvalidAuthority);
+            }
+            AdminAction::RegisterSecp256r1Attester { public_key } => {
+                AttesterAccount::validate_secp256r1_key(public_key)?;
+            }
+            AdminAction::SetAttesterValidity { valid_from, valid_until, .. } => {
+                AttesterAccount::validate_validity(*valid_from, *valid_until)?;
+            }
+            AdminAction::RevokeAttesterAfter { timestamp, .. } => {
+                require!(*timestamp > 0, crate::PredicateRegistryError::InvalidRevocationCutoff);
+            }
+            AdminAction::SetAttestationConfig { clock_drift_buffer, max_attestation_lifetime } => {
+                PredicateRegistry::validate_attestation_config(*clock_drift_buffer, *max_attestation_lifetime)?;
+            }
+            AdminAction::SetBondConfig { unbonding_period, .. } => {
+                PredicateRegistry::validate_bond_config(*unbonding_period)?;
+            }
+            AdminAction::SetGuardian { guardian } => {
+                require!(
+                    *guardian != Some(Pubkey::default()),
+                    crate::PredicateRegistryError::InvalidAuthority
+                );
+            }
+            AdminAction::GrantRole { holder, .. } => {
+                require!(
+                    *holder != Pubkey::default(),
+                    crate::PredicateRegistryError::InvalidAuthority
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl RoleAccount {
+    /// Initialize a new role grant
+    pub fn initialize(
+        &mut self,
+        role: Role,
+        holder: Pubkey,
+        granted_by: Pubkey,
+        clock: &Clock,
+    ) -> Result<()> {
+        require!(
+            holder != Pubkey::default(),
+            crate::PredicateRegistryError::InvalidAuthority
+        );
+        self.role = role;
+        self.holder = holder;
+        self.granted_by = granted_by;
+        self.granted_at = clock.unix_timestamp;
+        self.version = CURRENT_ACCOUNT_VERSION;
+        self.reserved = [0; ACCOUNT_RESERVED_SPACE];
+        Ok(())
+    }
+}
+
+impl AdminProposal {
+    /// Initialize a new proposal, counting the proposer's approval
+    pub fn initialize(
+        &mut self,
+        registry: Pubkey,
+        proposal_id: u64,
+        proposer: Pubkey,
+        action: AdminAction,
+        clock: &Clock,
+    ) -> Result<()> {
+        self.registry = registry;
+        self.proposal_id = proposal_id;
+        self.proposer = proposer;
+        self.action = action;
+        self.approvals = vec![proposer];
+        self.created_at = clock.unix_timestamp;
+        self.eta = 0;
+        Ok(())
+    }
+
+    /// Record an approval from a multisig signer
+    pub fn approve(&mut self, approver: Pubkey) -> Result<()> {
+        require!(
+            !self.approvals.contains(&approver),
+            crate::PredicateRegistryError::AlreadyApproved
+        );
+        require!(
+            self.approvals.len() < MAX_MULTISIG_SIGNERS,
+            crate::PredicateRegistryError::TooManyMultisigSigners
+        );
+        self.approvals.push(approver);
+        Ok(())
+    }
+
+    /// Count approvals from keys that are still in the registry's signer set
+    /// 
+    /// Approvals from signers removed after approving no longer count.
+    pub fn valid_approvals(&self, registry: &PredicateRegistry) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approver| registry.is_multisig_signer(approver))
+            .count()
+    }
+}
+
+impl QueuedOperation {
+    /// Initialize a newly queued operation
+    pub fn initialize(
+        &mut self,
+        registry: Pubkey,
+        operation_id: u64,
+        queued_by: Pubkey,
+        action: AdminAction,
+        eta: i64,
+        clock: &Clock,
+    ) -> Result<()> {
+        self.registry = registry;
+        self.operation_id = operation_id;
+        self.queued_by = queued_by;
+        self.action = action;
+        self.queued_at = clock.unix_timestamp;
+        self.eta = eta;
+        Ok(())
+    }
+
+    /// Whether the timelock delay has elapsed
+    pub fn is_ready(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.eta
+    }
+}
+
 impl PolicyAccount {
     /// Validate policy ID format constraints
     /// 
@@ -234,7 +1843,7 @@ impl PolicyAccount {
     /// called by both `initialize()` and `update_policy_id()`.
     pub fn validate_policy_id(policy_id: &str) -> Result<()> {
         require!(!policy_id.is_empty(), crate::PredicateRegistryError::InvalidPolicyId);
-        require!(policy_id.len() <= 64, crate::PredicateRegistryError::PolicyIdTooLong);
+        require!(policy_id.len() <= MAX_POLICY_ID_LEN, crate::PredicateRegistryError::PolicyIdTooLong);
         Ok(())
     }
 
@@ -253,6 +1862,11 @@ impl PolicyAccount {
         self.policy_id = policy_id;
         self.set_at = clock.unix_timestamp;
         self.updated_at = clock.unix_timestamp;
+        self.paused = false;
+        self.version = CURRENT_ACCOUNT_VERSION;
+        self.attester_quorum = 0;
+        self.digest_scheme = DigestScheme::Sha256;
+        self.reserved = [0; POLICY_RESERVED_SPACE];
         Ok(())
     }
 
@@ -264,6 +1878,45 @@ impl PolicyAccount {
         self.updated_at = clock.unix_timestamp;
         Ok(())
     }
+
+    /// Halt attestation validation for this program
+    pub fn pause(&mut self, clock: &Clock) -> Result<()> {
+        require!(!self.paused, crate::PredicateRegistryError::PolicyAlreadyPaused);
+        self.paused = true;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Resume attestation validation for this program
+    pub fn unpause(&mut self, clock: &Clock) -> Result<()> {
+        require!(self.paused, crate::PredicateRegistryError::PolicyNotPaused);
+        self.paused = false;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Set the number of distinct attesters each validation requires
+    pub fn set_attester_quorum(&mut self, quorum: u8, clock: &Clock) -> Result<()> {
+        require!(
+            quorum <= MAX_ATTESTER_QUORUM,
+            crate::PredicateRegistryError::InvalidAttesterQuorum
+        );
+        self.attester_quorum = quorum;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Whether validations for this program need signatures from several attesters
+    pub fn requires_quorum(&self) -> bool {
+        self.attester_quorum > 1
+    }
+
+    /// Set the digest over which attesters sign statements for this program
+    pub fn set_digest_scheme(&mut self, digest_scheme: DigestScheme, clock: &Clock) -> Result<()> {
+        self.digest_scheme = digest_scheme;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
 }
 
 
@@ -281,7 +1934,10 @@ impl Statement {
         )
     }
 
-    /// Hash the statement for signature verification (equivalent to hashStatementSafe in Solidity)
+    /// Hash the statement for signature verification (the default SHA-256 digest)
+    /// 
+    /// This is the digest existing attester backends sign, so its layout is
+    /// fixed; it does not cover `issued_at` (see `hash_statement_with_issued_at`).
     /// 
     /// Security: 
     /// - Domain separator prevents signature reuse across different contexts
@@ -289,6 +1945,22 @@ impl Statement {
     ///   before concatenation to prevent hash collisions. This ensures that different combinations
     ///   of these fields cannot produce the same final hash.
     pub fn hash_statement_safe(&self) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hash(&self.safe_preimage()).to_bytes()
+    }
+
+    /// Hash the statement and its issue time for signature verification
+    /// 
+    /// The `hash_statement_safe` preimage followed by `issued_at` (little
+    /// endian), so a relayer cannot move an attestation's issue time across
+    /// a revocation cutoff.
+    pub fn hash_statement_with_issued_at(&self) -> [u8; 32] {
+        let mut data = self.safe_preimage();
+        data.extend_from_slice(&self.issued_at.to_le_bytes());
+        anchor_lang::solana_program::hash::hash(&data).to_bytes()
+    }
+
+    /// Build the data hashed by `hash_statement_safe`
+    fn safe_preimage(&self) -> Vec<u8> {
         use anchor_lang::solana_program::hash::hash;
         
         let domain_separator = b"predicate_solana_attestation";
@@ -307,7 +1979,278 @@ impl Statement {
         data.extend_from_slice(&encoded_sig_and_args_hash);
         data.extend_from_slice(&policy_id_hash);
         data.extend_from_slice(&self.expiration.to_le_bytes());
-        
-        hash(&data).to_bytes()
+        data
+    }
+
+    /// Hash the statement with keccak256 over an EVM ABI encoding
+    /// 
+    /// Mirrors `hashStatementSafe` in the EVM contracts: the digest is
+    /// `keccak256(abi.encode(uuid, msgSender, target, msgValue,
+    /// encodedSigAndArgs, policy, expiration))` with the contract's types
+    /// `(string, address, address, uint256, bytes, string, uint256)`, so
+    /// attester backends can sign with the same encoder they use for the EVM
+    /// contracts. The UUID is passed as its dashed string (`format_uuid`), and
+    /// Solana keys are converted like Solidity's `address(uint160(uint256(key)))`,
+    /// keeping their low 20 bytes (which undoes the padding of Ethereum
+    /// attester keys). `issued_at` is not part of the EVM statement and is
+    /// left out, so attesters with a revocation cutoff are refused under this
+    /// scheme.
+    pub fn hash_statement_evm(&self) -> [u8; 32] {
+        use anchor_lang::solana_program::keccak::hash;
+
+        const WORD: usize = 32;
+        const HEAD_LEN: usize = 7 * WORD;
+
+        let uint_word = |value: u64| {
+            let mut word = [0u8; WORD];
+            word[WORD - 8..].copy_from_slice(&value.to_be_bytes());
+            word
+        };
+
+        // An address is the key's low 20 bytes, right-aligned in its word
+        let address_word = |key: &Pubkey| {
+            let mut word = [0u8; WORD];
+            word[12..].copy_from_slice(&key.to_bytes()[12..]);
+            word
+        };
+
+        // Dynamic values are placed after the head as a length word followed
+        // by the bytes, zero-padded to a whole number of words
+        let mut head = Vec::with_capacity(HEAD_LEN);
+        let mut tail = Vec::new();
+        let mut push_dynamic = |head: &mut Vec<u8>, bytes: &[u8]| {
+            head.extend_from_slice(&uint_word((HEAD_LEN + tail.len()) as u64));
+            tail.extend_from_slice(&uint_word(bytes.len() as u64));
+            tail.extend_from_slice(bytes);
+            tail.resize(tail.len().div_ceil(WORD) * WORD, 0);
+        };
+
+        push_dynamic(&mut head, self.format_uuid().as_bytes());
+        head.extend_from_slice(&address_word(&self.msg_sender));
+        head.extend_from_slice(&address_word(&self.target));
+        head.extend_from_slice(&uint_word(self.msg_value));
+        push_dynamic(&mut head, &self.encoded_sig_and_args);
+        push_dynamic(&mut head, self.policy_id.as_bytes());
+        // A negative expiration is already past; it encodes as uint256(int256(expiration))
+        let mut expiration_word = if self.expiration < 0 { [0xff; WORD] } else { [0u8; WORD] };
+        expiration_word[WORD - 8..].copy_from_slice(&self.expiration.to_be_bytes());
+        head.extend_from_slice(&expiration_word);
+
+        head.extend_from_slice(&tail);
+        hash(&head).to_bytes()
+    }
+
+    /// Hash the statement with the given digest scheme
+    pub fn digest(&self, digest_scheme: DigestScheme) -> [u8; 32] {
+        match digest_scheme {
+            DigestScheme::Sha256 => self.hash_statement_safe(),
+            DigestScheme::Keccak256 => self.hash_statement_evm(),
+            DigestScheme::Sha256WithIssuedAt => self.hash_statement_with_issued_at(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statement() -> Statement {
+        Statement {
+            uuid: core::array::from_fn(|i| i as u8),
+            msg_sender: Pubkey::new_from_array([1; 32]),
+            target: Pubkey::new_from_array([2; 32]),
+            msg_value: 1_000_000,
+            encoded_sig_and_args: b"test-encoded-data".to_vec(),
+            policy_id: "test-policy-v1".to_string(),
+            expiration: 1_700_000_600,
+            issued_at: 1_700_000_000,
+        }
+    }
+
+    // Vectors for hashStatementSafe's (string, address, address, uint256,
+    // bytes, string, uint256) encoding, computed with a separate abi.encode
+    // and keccak256 implementation. The first reproduces with Foundry:
+    //   cast keccak $(cast abi-encode \
+    //     "f(string,address,address,uint256,bytes,string,uint256)" \
+    //     00010203-0405-0607-0809-0a0b0c0d0e0f \
+    //     0x0101010101010101010101010101010101010101 \
+    //     0x0202020202020202020202020202020202020202 \
+    //     1000000 0x746573742d656e636f6465642d64617461 test-policy-v1 1700000600)
+    #[test]
+    fn evm_digest_matches_reference_vectors() {
+        assert_eq!(
+            hex::encode(statement().hash_statement_evm()),
+            "0c0103440890a8d5a9f84306cc83f15a905a29ff93a2574356248cea2ab36b81"
+        );
+
+        let padded = Statement {
+            uuid: [0xab; 16],
+            msg_sender: Pubkey::new_from_array([3; 32]),
+            target: Pubkey::new_from_array([4; 32]),
+            msg_value: 0,
+            encoded_sig_and_args: Vec::new(),
+            policy_id: "x".repeat(40),
+            expiration: 1_700_000_600,
+            issued_at: -1,
+        };
+        assert_eq!(
+            hex::encode(padded.hash_statement_evm()),
+            "39b0134f8e110d53a51189447045e7d44889f4cb1f01905393a039560f76d8b4"
+        );
+
+        // The EVM statement has no issue time
+        let reissued = Statement { issued_at: 0, ..padded.clone() };
+        assert_eq!(reissued.hash_statement_evm(), padded.hash_statement_evm());
+    }
+
+    fn registry(registry_id: u64) -> PredicateRegistry {
+        let mut registry = PredicateRegistry::deserialize(&mut &[0u8; PredicateRegistry::INIT_SPACE][..]).unwrap();
+        registry.registry_id = registry_id;
+        registry
+    }
+
+    #[test]
+    fn default_registry_keeps_legacy_addresses() {
+        let (registry_key, _) = Pubkey::find_program_address(
+            &[b"predicate_registry", &PredicateRegistry::id_seed(0)],
+            &crate::ID,
+        );
+        let (legacy_registry_key, _) = Pubkey::find_program_address(&[b"predicate_registry"], &crate::ID);
+        assert_eq!(registry_key, legacy_registry_key);
+
+        let attester = Pubkey::new_from_array([5; 32]);
+        let namespace = registry(0).namespace_seed(&registry_key);
+        let (attester_pda, _) = Pubkey::find_program_address(
+            &[b"attester", &namespace, attester.as_ref()],
+            &crate::ID,
+        );
+        let (legacy_attester_pda, _) = Pubkey::find_program_address(&[b"attester", attester.as_ref()], &crate::ID);
+        assert_eq!(attester_pda, legacy_attester_pda);
+    }
+
+    #[test]
+    fn other_registries_are_namespaced() {
+        let (registry_key, _) = Pubkey::find_program_address(
+            &[b"predicate_registry", &PredicateRegistry::id_seed(7)],
+            &crate::ID,
+        );
+        let (legacy_registry_key, _) = Pubkey::find_program_address(&[b"predicate_registry"], &crate::ID);
+        assert_ne!(registry_key, legacy_registry_key);
+        assert_eq!(registry(7).namespace_seed(&registry_key), registry_key.to_bytes().to_vec());
+    }
+
+    #[test]
+    fn rotation_keeps_key_type_and_validity_window() {
+        let clock = Clock { unix_timestamp: 1_700_000_000, ..Clock::default() };
+        let attester = |init: &dyn Fn(&mut AttesterAccount) -> Result<()>| {
+            let mut account = AttesterAccount::deserialize(&mut &[0u8; AttesterAccount::INIT_SPACE][..]).unwrap();
+            init(&mut account).unwrap();
+            account
+        };
+        let blank = || attester(&|_| Ok(()));
+
+        let mut ed25519 = attester(&|account| account.initialize(Pubkey::new_from_array([5; 32]), &clock));
+        let valid_from = clock.unix_timestamp + 3600;
+        ed25519.set_validity(valid_from, 0).unwrap();
+        let mut rotated = blank();
+        ed25519.rotate_to(&mut rotated, Pubkey::new_from_array([6; 32]), 60, &clock).unwrap();
+        assert!(rotated.key_type == AttesterKeyType::Ed25519);
+        // A rotated key does not start before the window it inherits
+        assert_eq!(rotated.valid_from, valid_from);
+        assert!(!rotated.has_started_at(clock.unix_timestamp));
+
+        let mut secp256k1 = attester(&|account| account.initialize_secp256k1(&[7; 20], &clock));
+        let mut rotated = blank();
+        assert!(secp256k1.rotate_to(&mut rotated, Pubkey::new_from_array([8; 32]), 60, &clock).is_err());
+        secp256k1
+            .rotate_to(&mut rotated, AttesterAccount::secp256k1_key(&[8; 20]), 60, &clock)
+            .unwrap();
+        assert!(rotated.key_type == AttesterKeyType::Secp256k1);
+        assert_eq!(AttesterAccount::eth_address(&rotated.attester), [8; 20]);
+
+        let mut secp256r1 = attester(&|account| account.initialize_secp256r1(&[2; 33], &clock));
+        let mut rotated = blank();
+        secp256r1
+            .rotate_to(&mut rotated, AttesterAccount::secp256r1_key(&[3; 33]), 60, &clock)
+            .unwrap();
+        assert!(rotated.key_type == AttesterKeyType::Secp256r1);
+        assert_eq!(rotated.identity, secp256r1.identity);
+        let mut beyond_field = blank();
+        assert!(secp256r1.rotate_to(&mut beyond_field, Pubkey::new_from_array(P256_FIELD_PRIME), 60, &clock).is_err());
+    }
+
+    #[test]
+    fn p256_keys_are_compressed_field_elements() {
+        // The P-256 generator, compressed
+        let mut generator = [0x03; 33];
+        generator[1..].copy_from_slice(
+            &hex::decode("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296").unwrap(),
+        );
+        assert!(AttesterAccount::validate_secp256r1_key(&generator).is_ok());
+
+        let mut uncompressed = generator;
+        uncompressed[0] = 0x04;
+        assert!(AttesterAccount::validate_secp256r1_key(&uncompressed).is_err());
+
+        let mut beyond_field = [0x02; 33];
+        beyond_field[1..].copy_from_slice(&P256_FIELD_PRIME);
+        assert!(AttesterAccount::validate_secp256r1_key(&beyond_field).is_err());
+        assert!(AttesterAccount::validate_secp256r1_key(&[0x02; 33]).is_ok());
+        let mut zero = [0; 33];
+        zero[0] = 0x02;
+        assert!(AttesterAccount::validate_secp256r1_key(&zero).is_err());
+    }
+
+    #[test]
+    fn slash_evidence_matches_signers_and_identity() {
+        let clock = Clock { unix_timestamp: 1_700_000_000, ..Clock::default() };
+        let attester = |key: u8| {
+            let mut account = AttesterAccount::deserialize(&mut &[0u8; AttesterAccount::INIT_SPACE][..]).unwrap();
+            account.initialize(Pubkey::new_from_array([key; 32]), &clock).unwrap();
+            account
+        };
+        let mut used = UsedUuidAccount::deserialize(&mut &[0u8; UsedUuidAccount::INIT_SPACE][..]).unwrap();
+        used.attestation.attester = Pubkey::new_from_array([1; 32]);
+        used.co_signers = vec![Pubkey::new_from_array([2; 32])];
+
+        assert!(used.is_signed_by(&attester(1)));
+        assert!(used.is_signed_by(&attester(2)));
+        assert!(!used.is_signed_by(&attester(3)));
+
+        // A key rotated in after signing is matched through its identity
+        let mut rotated = attester(1);
+        let mut successor = attester(0);
+        rotated.rotate_to(&mut successor, Pubkey::new_from_array([4; 32]), 60, &clock).unwrap();
+        assert!(used.is_signed_by(&successor));
+    }
+
+    #[test]
+    fn digest_dispatches_on_scheme() {
+        let statement = statement();
+        assert_eq!(statement.digest(DigestScheme::Sha256), statement.hash_statement_safe());
+        assert_eq!(statement.digest(DigestScheme::Keccak256), statement.hash_statement_evm());
+        assert_eq!(
+            statement.digest(DigestScheme::Sha256WithIssuedAt),
+            statement.hash_statement_with_issued_at()
+        );
+        assert_ne!(statement.hash_statement_safe(), statement.hash_statement_evm());
+    }
+
+    // The default digest is the one attester backends already sign, so it
+    // must not move; only the opt-in scheme covers the issue time
+    #[test]
+    fn default_digest_keeps_its_layout() {
+        let statement = statement();
+        assert_eq!(
+            hex::encode(statement.hash_statement_safe()),
+            "e0fcc954c49ca61a55c201db410b3aaaa7ec5ef7cfc4cd330ba7667cf4327174"
+        );
+
+        let backdated = Statement { issued_at: 1, ..statement.clone() };
+        assert_eq!(backdated.hash_statement_safe(), statement.hash_statement_safe());
+        assert_ne!(backdated.hash_statement_with_issued_at(), statement.hash_statement_with_issued_at());
+        assert!(!DigestScheme::Sha256.signs_issued_at());
+        assert!(!DigestScheme::Keccak256.signs_issued_at());
+        assert!(DigestScheme::Sha256WithIssuedAt.signs_issued_at());
     }
 }
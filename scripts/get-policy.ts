@@ -62,9 +62,24 @@ async function main() {
   console.log("Using RPC:", rpcUrl);
   console.log("");
 
-  // Derive the policy PDA
+  // Derive the registry and policy PDAs
+  // Registry 0 keeps the original un-namespaced PDAs
+  const registryId = new anchor.BN(process.env.REGISTRY_ID || "0");
+  const [registryPda] = PublicKey.findProgramAddressSync(
+    registryId.isZero()
+      ? [Buffer.from("predicate_registry")]
+      : [
+          Buffer.from("predicate_registry"),
+          registryId.toArrayLike(Buffer, "le", 8),
+        ],
+    predicateRegistryProgramId
+  );
   const [policyPda, bump] = PublicKey.findProgramAddressSync(
-    [Buffer.from("policy"), clientProgramId.toBuffer()],
+    [
+      Buffer.from("policy"),
+      registryId.isZero() ? Buffer.alloc(0) : registryPda.toBuffer(),
+      clientProgramId.toBuffer(),
+    ],
     predicateRegistryProgramId
   );
 
@@ -32,6 +32,16 @@ import * as path from "path";
 
 // Configuration
 const CLUSTER_URL = process.env.ANCHOR_PROVIDER_URL || "http://127.0.0.1:8899";
+const REGISTRY_ID = new anchor.BN(process.env.REGISTRY_ID || "0");
+
+// Registry 0 keeps the original un-namespaced PDAs; other registries add
+// their ID to the registry seeds and their address to child account seeds
+const REGISTRY_ID_SEED = REGISTRY_ID.isZero()
+  ? Buffer.alloc(0)
+  : REGISTRY_ID.toArrayLike(Buffer, "le", 8);
+const registryNamespace = (registryPda: PublicKey): Buffer =>
+  REGISTRY_ID.isZero() ? Buffer.alloc(0) : registryPda.toBuffer();
+
 const OWNER_WALLET_PATH =
   process.env.ANCHOR_WALLET ||
   path.join(__dirname, "test-keys", "authority.json");
@@ -206,7 +216,10 @@ function findPDAs(
 } {
   // Registry PDA
   const [registryPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("predicate_registry")],
+    [
+      Buffer.from("predicate_registry"),
+      REGISTRY_ID_SEED,
+    ],
     predicateProgram.programId
   );
 
@@ -218,13 +231,17 @@ function findPDAs(
 
   // Attester PDA
   const [attesterPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("attester"), attester.toBuffer()],
+    [Buffer.from("attester"), registryNamespace(registryPda), attester.toBuffer()],
     predicateProgram.programId
   );
 
   // Policy PDA - derived from COUNTER PROGRAM, not user
   const [policyPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("policy"), counterProgram.programId.toBuffer()],
+    [
+      Buffer.from("policy"),
+      registryNamespace(registryPda),
+      counterProgram.programId.toBuffer(),
+    ],
     predicateProgram.programId
   );
 
@@ -277,6 +294,7 @@ function createIncrementStatement(
     encodedSigAndArgs: encodedSigAndArgs,
     policyId: DEFAULT_POLICY,
     expiration: new anchor.BN(expiration),
+    issuedAt: new anchor.BN(Math.floor(Date.now() / 1000)),
   };
 }
 
@@ -331,13 +349,15 @@ function createAttestation(
   uuid: Uint8Array,
   attesterKeypair: Keypair,
   expiration: number,
-  signature: Uint8Array
+  signature: Uint8Array,
+  issuedAt: anchor.BN
 ): any {
   return {
     uuid: Array.from(uuid),
     attester: attesterKeypair.publicKey,
     signature: Array.from(signature),
     expiration: new anchor.BN(expiration),
+    issuedAt,
   };
 }
 
@@ -374,12 +394,17 @@ async function incrementCounter(
     Buffer.from(statement.uuid),
     attester,
     statement.expiration.toNumber(),
-    signature
+    signature,
+    statement.issuedAt
   );
 
   // Calculate used UUID PDA (for replay protection)
   const [usedUuidPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("used_uuid"), Buffer.from(statement.uuid)],
+    [
+      Buffer.from("used_uuid"),
+      registryNamespace(pdas.registryPda),
+      Buffer.from(statement.uuid),
+    ],
     predicateProgram.programId
   );
 
@@ -406,6 +431,7 @@ async function incrementCounter(
       owner: owner.publicKey,
       predicateRegistry: pdas.registryPda,
       attesterAccount: pdas.attesterPda,
+      attesterScopeAccount: null,
       policyAccount: pdas.policyPda,
       usedUuidAccount: usedUuidPda,
       instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
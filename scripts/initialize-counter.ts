@@ -33,6 +33,16 @@ const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
 
 // Configuration
 const CLUSTER_URL = process.env.ANCHOR_PROVIDER_URL || "http://127.0.0.1:8899";
+const REGISTRY_ID = new anchor.BN(process.env.REGISTRY_ID || "0");
+
+// Registry 0 keeps the original un-namespaced PDAs; other registries add
+// their ID to the registry seeds and their address to child account seeds
+const REGISTRY_ID_SEED = REGISTRY_ID.isZero()
+  ? Buffer.alloc(0)
+  : REGISTRY_ID.toArrayLike(Buffer, "le", 8);
+const registryNamespace = (registryPda: PublicKey): Buffer =>
+  REGISTRY_ID.isZero() ? Buffer.alloc(0) : registryPda.toBuffer();
+
 const WALLET_PATH =
   process.env.ANCHOR_WALLET ||
   path.join(__dirname, "test-keys", "authority.json");
@@ -194,7 +204,10 @@ function findPDAs(
 } {
   // Registry PDA
   const [registryPda, registryBump] = PublicKey.findProgramAddressSync(
-    [Buffer.from("predicate_registry")],
+    [
+      Buffer.from("predicate_registry"),
+      REGISTRY_ID_SEED,
+    ],
     predicateProgram.programId
   );
 
@@ -207,7 +220,11 @@ function findPDAs(
   // Policy PDA - derived from COUNTER PROGRAM, not user
   // Policies are owned by programs, not users
   const [policyPda, policyBump] = PublicKey.findProgramAddressSync(
-    [Buffer.from("policy"), counterProgram.programId.toBuffer()],
+    [
+      Buffer.from("policy"),
+      registryNamespace(registryPda),
+      counterProgram.programId.toBuffer(),
+    ],
     predicateProgram.programId
   );
 
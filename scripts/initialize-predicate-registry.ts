@@ -21,6 +21,19 @@ import * as path from "path";
 
 // Configuration
 const CLUSTER_URL = process.env.ANCHOR_PROVIDER_URL || "http://127.0.0.1:8899";
+const REGISTRY_ID = new anchor.BN(process.env.REGISTRY_ID || "0");
+
+// Registry 0 keeps the original un-namespaced PDAs; other registries add
+// their ID to the registry seeds and their address to child account seeds
+const REGISTRY_ID_SEED = REGISTRY_ID.isZero()
+  ? Buffer.alloc(0)
+  : REGISTRY_ID.toArrayLike(Buffer, "le", 8);
+const registryNamespace = (registryPda: PublicKey): Buffer =>
+  REGISTRY_ID.isZero() ? Buffer.alloc(0) : registryPda.toBuffer();
+
+// Attester keys per attester index page (mirrors `ATTESTER_INDEX_PAGE_SIZE`)
+const ATTESTER_INDEX_PAGE_SIZE = 30;
+
 const WALLET_PATH =
   process.env.ANCHOR_WALLET ||
   path.join(__dirname, "test-keys", "authority.json");
@@ -180,7 +193,10 @@ function findRegistryPDA(programId: PublicKey): {
   registryBump: number;
 } {
   const [registryPda, registryBump] = PublicKey.findProgramAddressSync(
-    [Buffer.from("predicate_registry")],
+    [
+      Buffer.from("predicate_registry"),
+      REGISTRY_ID_SEED,
+    ],
     programId
   );
 
@@ -212,10 +228,18 @@ async function initializeRegistry(
 ): Promise<string> {
   console.log("📝 Initializing predicate registry...");
 
+  // Initialization is restricted to the registry program's upgrade authority,
+  // which is verified against the program's ProgramData account
+  const [programDataPda] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+
   const tx = await program.methods
-    .initialize()
+    .initialize(REGISTRY_ID)
     .accounts({
       registry: registryPda,
+      programData: programDataPda,
       authority: authority.publicKey,
       systemProgram: SystemProgram.programId,
     } as any)
@@ -230,15 +254,36 @@ async function initializeRegistry(
  */
 function findAttesterPDA(
   attester: PublicKey,
+  registryPda: PublicKey,
   programId: PublicKey
 ): { attesterPda: PublicKey; attesterBump: number } {
   const [attesterPda, attesterBump] = PublicKey.findProgramAddressSync(
-    [Buffer.from("attester"), attester.toBuffer()],
+    [Buffer.from("attester"), registryNamespace(registryPda), attester.toBuffer()],
     programId
   );
   return { attesterPda, attesterBump };
 }
 
+/**
+ * Find the attester index tail page PDA, which lists the next attester
+ */
+async function findAttesterIndexTailPDA(
+  program: Program<PredicateRegistry>,
+  registryPda: PublicKey
+): Promise<PublicKey> {
+  const registry = await program.account.predicateRegistry.fetch(registryPda);
+  const page = registry.attesterIndexLen.divn(ATTESTER_INDEX_PAGE_SIZE);
+  const [indexPagePda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("attester_index"),
+      registryNamespace(registryPda),
+      page.toArrayLike(Buffer, "le", 4),
+    ],
+    program.programId
+  );
+  return indexPagePda;
+}
+
 /**
  * Check if attester is already registered
  */
@@ -265,7 +310,11 @@ async function registerAttester(
   attesterPubkey: PublicKey,
   registryPda: PublicKey
 ): Promise<AttesterRegistration> {
-  const { attesterPda } = findAttesterPDA(attesterPubkey, program.programId);
+  const { attesterPda } = findAttesterPDA(
+    attesterPubkey,
+    registryPda,
+    program.programId
+  );
 
   // Check if already registered
   const alreadyRegistered = await checkAttesterExists(program, attesterPda);
@@ -289,11 +338,14 @@ async function registerAttester(
   );
 
   const tx = await program.methods
-    .registerAttester(attesterPubkey)
+    .registerAttester(attesterPubkey, null)
     .accounts({
       registry: registryPda,
       attesterAccount: attesterPda,
       authority: authority.publicKey,
+      roleAccount: null,
+      metadataAccount: null,
+      indexPage: await findAttesterIndexTailPDA(program, registryPda),
       systemProgram: SystemProgram.programId,
     } as any)
     .signers([authority])
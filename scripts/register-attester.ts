@@ -22,6 +22,19 @@ import * as path from "path";
 
 // Configuration
 const CLUSTER_URL = process.env.ANCHOR_PROVIDER_URL || "http://127.0.0.1:8899";
+const REGISTRY_ID = new anchor.BN(process.env.REGISTRY_ID || "0");
+
+// Registry 0 keeps the original un-namespaced PDAs; other registries add
+// their ID to the registry seeds and their address to child account seeds
+const REGISTRY_ID_SEED = REGISTRY_ID.isZero()
+  ? Buffer.alloc(0)
+  : REGISTRY_ID.toArrayLike(Buffer, "le", 8);
+const registryNamespace = (registryPda: PublicKey): Buffer =>
+  REGISTRY_ID.isZero() ? Buffer.alloc(0) : registryPda.toBuffer();
+
+// Attester keys per attester index page (mirrors `ATTESTER_INDEX_PAGE_SIZE`)
+const ATTESTER_INDEX_PAGE_SIZE = 30;
+
 const WALLET_PATH = process.env.ANCHOR_WALLET || "~/.config/solana/id.json";
 const ATTESTER_PUBKEY = process.env.ATTESTER_PUBKEY;
 
@@ -126,7 +139,10 @@ function findRegistryPDA(programId: PublicKey): {
   registryBump: number;
 } {
   const [registryPda, registryBump] = PublicKey.findProgramAddressSync(
-    [Buffer.from("predicate_registry")],
+    [
+      Buffer.from("predicate_registry"),
+      REGISTRY_ID_SEED,
+    ],
     programId
   );
 
@@ -138,16 +154,37 @@ function findRegistryPDA(programId: PublicKey): {
  */
 function findAttesterPDA(
   attester: PublicKey,
+  registryPda: PublicKey,
   programId: PublicKey
 ): { attesterPda: PublicKey; attesterBump: number } {
   const [attesterPda, attesterBump] = PublicKey.findProgramAddressSync(
-    [Buffer.from("attester"), attester.toBuffer()],
+    [Buffer.from("attester"), registryNamespace(registryPda), attester.toBuffer()],
     programId
   );
 
   return { attesterPda, attesterBump };
 }
 
+/**
+ * Find the attester index tail page PDA, which lists the next attester
+ */
+async function findAttesterIndexTailPDA(
+  program: Program<PredicateRegistry>,
+  registryPda: PublicKey
+): Promise<PublicKey> {
+  const registry = await program.account.predicateRegistry.fetch(registryPda);
+  const page = registry.attesterIndexLen.divn(ATTESTER_INDEX_PAGE_SIZE);
+  const [indexPagePda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("attester_index"),
+      registryNamespace(registryPda),
+      page.toArrayLike(Buffer, "le", 4),
+    ],
+    program.programId
+  );
+  return indexPagePda;
+}
+
 /**
  * Check if registry is initialized
  */
@@ -193,11 +230,14 @@ async function registerAttester(
   console.log("📝 Registering attester...");
 
   const tx = await program.methods
-    .registerAttester(attester)
+    .registerAttester(attester, null)
     .accounts({
       registry: registryPda,
       attesterAccount: attesterPda,
       authority: authority.publicKey,
+      roleAccount: null,
+      metadataAccount: null,
+      indexPage: await findAttesterIndexTailPDA(program, registryPda),
       systemProgram: SystemProgram.programId,
     } as any)
     .signers([authority])
@@ -281,6 +321,7 @@ async function main(): Promise<AttesterRegistrationResult> {
     const { registryPda, registryBump } = findRegistryPDA(program.programId);
     const { attesterPda, attesterBump } = findAttesterPDA(
       attesterPubkey,
+      registryPda,
       program.programId
     );
 
@@ -42,13 +42,24 @@ async function setCustomerPolicy(customerProgramId: string, policyId: string) {
   console.log();
 
   // Derive PDAs
+  // Registry 0 keeps the original un-namespaced PDAs
+  const registryId = new anchor.BN(process.env.REGISTRY_ID || "0");
   const [registryPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("predicate_registry")],
+    registryId.isZero()
+      ? [Buffer.from("predicate_registry")]
+      : [
+          Buffer.from("predicate_registry"),
+          registryId.toArrayLike(Buffer, "le", 8),
+        ],
     program.programId
   );
 
   const [policyPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("policy"), customerProgram.toBuffer()],
+    [
+      Buffer.from("policy"),
+      registryId.isZero() ? Buffer.alloc(0) : registryPda.toBuffer(),
+      customerProgram.toBuffer(),
+    ],
     program.programId
   );
 
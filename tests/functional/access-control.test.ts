@@ -0,0 +1,433 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  createTestAccount,
+  findAttesterPDA,
+  findRolePDA,
+  grantRole,
+  revokeRole,
+  findAttesterIndexPages,
+  findAttesterIndexTailPDA,
+  findAttesterSideAccounts,
+} from "../helpers/test-utils";
+import {
+  setupSharedTestContext,
+  SharedTestContext,
+  verifyAuthorityState,
+} from "../helpers/shared-setup";
+
+describe("Role-Based Access Control", () => {
+  let context: SharedTestContext;
+  let manager: Keypair;
+  let pauser: Keypair;
+  let feeManager: Keypair;
+
+  before(async () => {
+    context = await setupSharedTestContext();
+    manager = (await createTestAccount(context.provider)).keypair;
+    pauser = (await createTestAccount(context.provider)).keypair;
+    feeManager = (await createTestAccount(context.provider)).keypair;
+  });
+
+  after(async () => {
+    await verifyAuthorityState(context, {
+      when: "after",
+      suiteName: "access control test suite",
+    });
+  });
+
+  async function registerAs(
+    signer: Keypair,
+    attester: PublicKey,
+    withRole: boolean
+  ) {
+    const [attesterPda] = findAttesterPDA(attester, context.program.programId);
+    const [rolePda] = findRolePDA(
+      "attesterManager",
+      signer.publicKey,
+      context.program.programId
+    );
+    await context.program.methods
+      .registerAttester(attester, null)
+      .accounts({
+        registry: context.registry.registryPda,
+        attesterAccount: attesterPda,
+        authority: signer.publicKey,
+        roleAccount: withRole ? rolePda : null,
+        metadataAccount: null,
+        indexPage: await findAttesterIndexTailPDA(context.program),
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([signer])
+      .rpc();
+  }
+
+  async function deregisterAs(signer: Keypair, attester: PublicKey) {
+    const [attesterPda] = findAttesterPDA(attester, context.program.programId);
+    const [rolePda] = findRolePDA(
+      "attesterManager",
+      signer.publicKey,
+      context.program.programId
+    );
+    await context.program.methods
+      .deregisterAttester(attester)
+      .accounts({
+        registry: context.registry.registryPda,
+        attesterAccount: attesterPda,
+        authority: signer.publicKey,
+        roleAccount: rolePda,
+        ...findAttesterSideAccounts(attester, context.program.programId),
+        ...(await findAttesterIndexPages(context.program, attester)),
+      } as any)
+      .signers([signer])
+      .rpc();
+  }
+
+  describe("Attester Manager", () => {
+    before(async () => {
+      await grantRole(
+        context.program,
+        context.authority.keypair,
+        "attesterManager",
+        manager.publicKey,
+        context.registry.registryPda
+      );
+    });
+
+    it("Should store the role grant", async () => {
+      const [rolePda] = findRolePDA(
+        "attesterManager",
+        manager.publicKey,
+        context.program.programId
+      );
+      const roleAccount = await context.program.account.roleAccount.fetch(
+        rolePda
+      );
+      expect(roleAccount.role).to.deep.equal({ attesterManager: {} });
+      expect(roleAccount.holder.toString()).to.equal(
+        manager.publicKey.toString()
+      );
+      expect(roleAccount.grantedBy.toString()).to.equal(
+        context.authority.keypair.publicKey.toString()
+      );
+    });
+
+    it("Should allow an attester manager to register and deregister attesters", async () => {
+      const attester = Keypair.generate().publicKey;
+      const [attesterPda] = findAttesterPDA(
+        attester,
+        context.program.programId
+      );
+
+      await registerAs(manager, attester, true);
+      const attesterAccount =
+        await context.program.account.attesterAccount.fetch(attesterPda);
+      expect(attesterAccount.isRegistered).to.be.true;
+
+      await deregisterAs(manager, attester);
+      const closed = await context.provider.connection.getAccountInfo(
+        attesterPda
+      );
+      expect(closed).to.be.null;
+    });
+
+    it("Should reject a manager acting without their role account", async () => {
+      try {
+        await registerAs(manager, Keypair.generate().publicKey, false);
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should not let an attester manager grant roles", async () => {
+      try {
+        await grantRole(
+          context.program,
+          manager,
+          "attesterManager",
+          Keypair.generate().publicKey,
+          context.registry.registryPda
+        );
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should reject a role account belonging to another role", async () => {
+      await grantRole(
+        context.program,
+        context.authority.keypair,
+        "pauser",
+        pauser.publicKey,
+        context.registry.registryPda
+      );
+      const attester = Keypair.generate().publicKey;
+      const [attesterPda] = findAttesterPDA(
+        attester,
+        context.program.programId
+      );
+      const [pauserRolePda] = findRolePDA(
+        "pauser",
+        pauser.publicKey,
+        context.program.programId
+      );
+
+      try {
+        await context.program.methods
+          .registerAttester(attester, null)
+          .accounts({
+            registry: context.registry.registryPda,
+            attesterAccount: attesterPda,
+            authority: pauser.publicKey,
+            roleAccount: pauserRolePda,
+            metadataAccount: null,
+            indexPage: await findAttesterIndexTailPDA(context.program),
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .signers([pauser])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("ConstraintSeeds");
+      } finally {
+        await revokeRole(
+          context.program,
+          context.authority.keypair,
+          "pauser",
+          pauser.publicKey,
+          context.registry.registryPda
+        );
+      }
+    });
+
+    it("Should reject the manager once the role is revoked", async () => {
+      await revokeRole(
+        context.program,
+        context.authority.keypair,
+        "attesterManager",
+        manager.publicKey,
+        context.registry.registryPda
+      );
+
+      try {
+        await registerAs(manager, Keypair.generate().publicKey, false);
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("Pauser", () => {
+    before(async () => {
+      await grantRole(
+        context.program,
+        context.authority.keypair,
+        "pauser",
+        pauser.publicKey,
+        context.registry.registryPda
+      );
+    });
+
+    after(async () => {
+      await revokeRole(
+        context.program,
+        context.authority.keypair,
+        "pauser",
+        pauser.publicKey,
+        context.registry.registryPda
+      );
+    });
+
+    it("Should allow a pauser to pause but not unpause", async () => {
+      const [rolePda] = findRolePDA(
+        "pauser",
+        pauser.publicKey,
+        context.program.programId
+      );
+
+      await context.program.methods
+        .pause()
+        .accounts({
+          registry: context.registry.registryPda,
+          signer: pauser.publicKey,
+          roleAccount: rolePda,
+        } as any)
+        .signers([pauser])
+        .rpc();
+
+      let registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      expect(registry.paused).to.be.true;
+
+      try {
+        await context.program.methods
+          .unpause()
+          .accounts({
+            registry: context.registry.registryPda,
+            authority: pauser.publicKey,
+          } as any)
+          .signers([pauser])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+
+      await context.program.methods
+        .unpause()
+        .accounts({
+          registry: context.registry.registryPda,
+          authority: context.authority.keypair.publicKey,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+
+      registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      expect(registry.paused).to.be.false;
+    });
+  });
+
+  describe("Fee Manager", () => {
+    const MIN_COLLATERAL = 1_000;
+
+    async function setBondConfigAs(
+      signer: Keypair,
+      minCollateral: number,
+      roleAccount: PublicKey | null
+    ) {
+      await context.program.methods
+        .setBondConfig(
+          new anchor.BN(0),
+          new anchor.BN(0),
+          new anchor.BN(minCollateral)
+        )
+        .accounts({
+          registry: context.registry.registryPda,
+          authority: signer.publicKey,
+          roleAccount,
+        } as any)
+        .signers([signer])
+        .rpc();
+    }
+
+    before(async () => {
+      await grantRole(
+        context.program,
+        context.authority.keypair,
+        "feeManager",
+        feeManager.publicKey,
+        context.registry.registryPda
+      );
+    });
+
+    after(async () => {
+      await revokeRole(
+        context.program,
+        context.authority.keypair,
+        "feeManager",
+        feeManager.publicKey,
+        context.registry.registryPda
+      );
+    });
+
+    it("Should allow a fee manager to set the bond config", async () => {
+      const [rolePda] = findRolePDA(
+        "feeManager",
+        feeManager.publicKey,
+        context.program.programId
+      );
+
+      await setBondConfigAs(feeManager, MIN_COLLATERAL, rolePda);
+      let registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      expect(registry.minAttesterCollateral.toNumber()).to.equal(
+        MIN_COLLATERAL
+      );
+
+      await setBondConfigAs(feeManager, 0, rolePda);
+      registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      expect(registry.minAttesterCollateral.toNumber()).to.equal(0);
+    });
+
+    it("Should reject a fee manager without its role account", async () => {
+      try {
+        await setBondConfigAs(feeManager, MIN_COLLATERAL, null);
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should reject a pauser setting the bond config", async () => {
+      const [pauserRolePda] = findRolePDA(
+        "pauser",
+        pauser.publicKey,
+        context.program.programId
+      );
+      await grantRole(
+        context.program,
+        context.authority.keypair,
+        "pauser",
+        pauser.publicKey,
+        context.registry.registryPda
+      );
+
+      try {
+        await setBondConfigAs(pauser, MIN_COLLATERAL, pauserRolePda);
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("ConstraintSeeds");
+      } finally {
+        await revokeRole(
+          context.program,
+          context.authority.keypair,
+          "pauser",
+          pauser.publicKey,
+          context.registry.registryPda
+        );
+      }
+    });
+  });
+
+  it("Should reject granting a role twice", async () => {
+    const holder = Keypair.generate().publicKey;
+    await grantRole(
+      context.program,
+      context.authority.keypair,
+      "pauser",
+      holder,
+      context.registry.registryPda
+    );
+
+    try {
+      await grantRole(
+        context.program,
+        context.authority.keypair,
+        "pauser",
+        holder,
+        context.registry.registryPda
+      );
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("already in use");
+    } finally {
+      await revokeRole(
+        context.program,
+        context.authority.keypair,
+        "pauser",
+        holder,
+        context.registry.registryPda
+      );
+    }
+  });
+});
@@ -1,10 +1,21 @@
 import { expect } from "chai";
+import * as anchor from "@coral-xyz/anchor";
 import { Keypair, SystemProgram } from "@solana/web3.js";
 import {
   findAttesterPDA,
+  findAttesterMetadataPDA,
+  findAttesterScopePDA,
+  findAttesterBondPDA,
+  findAttesterIndexPDA,
+  ATTESTER_INDEX_PAGE_SIZE,
   registerAttester,
   createFundedKeypair,
   createTestAccount,
+  transferAuthority,
+  secp256k1AttesterKey,
+  findAttesterIndexPages,
+  findAttesterIndexTailPDA,
+  findAttesterSideAccounts,
 } from "../helpers/test-utils";
 import {
   setupSharedTestContext,
@@ -139,11 +150,14 @@ describe("Attester Management", () => {
 
       try {
         await context.program.methods
-          .registerAttester(attester1.keypair.publicKey)
+          .registerAttester(attester1.keypair.publicKey, null)
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
             authority: unauthorizedAuthority.publicKey,
+            roleAccount: null,
+            metadataAccount: null,
+            indexPage: await findAttesterIndexTailPDA(context.program),
             systemProgram: SystemProgram.programId,
           })
           .signers([unauthorizedAuthority])
@@ -178,11 +192,14 @@ describe("Attester Management", () => {
 
       try {
         await context.program.methods
-          .registerAttester(attester1.keypair.publicKey)
+          .registerAttester(attester1.keypair.publicKey, null)
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
             authority: context.authority.keypair.publicKey,
+            roleAccount: null,
+            metadataAccount: null,
+            indexPage: await findAttesterIndexTailPDA(context.program),
             systemProgram: SystemProgram.programId,
           })
           .signers([context.authority.keypair])
@@ -200,14 +217,12 @@ describe("Attester Management", () => {
 
       try {
         // Transfer authority first
-        await context.program.methods
-          .transferAuthority(newAuthority.keypair.publicKey)
-          .accounts({
-            registry: context.registry.registryPda,
-            authority: context.authority.keypair.publicKey,
-          } as any)
-          .signers([context.authority.keypair])
-          .rpc();
+        await transferAuthority(
+          context.program,
+          context.authority.keypair,
+          newAuthority.keypair,
+          context.registry.registryPda
+        );
 
         // Register with new authority
         await registerAttester(
@@ -226,14 +241,12 @@ describe("Attester Management", () => {
         expect(attesterAccount.isRegistered).to.be.true;
       } finally {
         // Always transfer authority back to original authority
-        await context.program.methods
-          .transferAuthority(context.authority.keypair.publicKey)
-          .accounts({
-            registry: context.registry.registryPda,
-            authority: newAuthority.keypair.publicKey,
-          } as any)
-          .signers([newAuthority.keypair])
-          .rpc();
+        await transferAuthority(
+          context.program,
+          newAuthority.keypair,
+          context.authority.keypair,
+          context.registry.registryPda
+        );
       }
     });
   });
@@ -283,6 +296,15 @@ describe("Attester Management", () => {
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
           authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            attester1.keypair.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            attester1.keypair.publicKey
+          )),
         })
         .signers([context.authority.keypair])
         .rpc();
@@ -337,6 +359,15 @@ describe("Attester Management", () => {
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
           authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            attester1.keypair.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            attester1.keypair.publicKey
+          )),
         })
         .signers([context.authority.keypair])
         .rpc();
@@ -361,6 +392,15 @@ describe("Attester Management", () => {
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
             authority: unauthorizedAuthority.publicKey,
+            roleAccount: null,
+            ...findAttesterSideAccounts(
+              attester1.keypair.publicKey,
+              context.program.programId
+            ),
+            ...(await findAttesterIndexPages(
+              context.program,
+              attester1.keypair.publicKey
+            )),
           })
           .signers([unauthorizedAuthority])
           .rpc();
@@ -384,6 +424,15 @@ describe("Attester Management", () => {
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
           authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            attester1.keypair.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            attester1.keypair.publicKey
+          )),
         })
         .signers([context.authority.keypair])
         .rpc();
@@ -406,6 +455,13 @@ describe("Attester Management", () => {
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
             authority: context.authority.keypair.publicKey,
+            roleAccount: null,
+            ...findAttesterSideAccounts(
+              attester1.keypair.publicKey,
+              context.program.programId
+            ),
+            indexPage: findAttesterIndexPDA(0, context.program.programId)[0],
+            tailPage: null,
           })
           .signers([context.authority.keypair])
           .rpc();
@@ -431,6 +487,13 @@ describe("Attester Management", () => {
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
             authority: context.authority.keypair.publicKey,
+            roleAccount: null,
+            ...findAttesterSideAccounts(
+              nonExistentAttester.publicKey,
+              context.program.programId
+            ),
+            indexPage: findAttesterIndexPDA(0, context.program.programId)[0],
+            tailPage: null,
           })
           .signers([context.authority.keypair])
           .rpc();
@@ -476,6 +539,15 @@ describe("Attester Management", () => {
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
           authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            attester1.keypair.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            attester1.keypair.publicKey
+          )),
         })
         .signers([context.authority.keypair])
         .rpc();
@@ -515,12 +587,6 @@ describe("Attester Management", () => {
         context.program.programId
       );
 
-      // Get authority balance before registration
-      const authorityBalanceBefore =
-        await context.provider.connection.getBalance(
-          context.authority.keypair.publicKey
-        );
-
       // Register attester (authority pays rent)
       await registerAttester(
         context.program,
@@ -529,13 +595,15 @@ describe("Attester Management", () => {
         context.registry.registryPda
       );
 
-      // Get authority balance after registration (should be less due to rent)
+      // Get authority balance after registration, and the attester account's
+      // rent (registration may also have funded a new attester index page)
       const authorityBalanceAfterRegistration =
         await context.provider.connection.getBalance(
           context.authority.keypair.publicKey
         );
-      const rentPaid =
-        authorityBalanceBefore - authorityBalanceAfterRegistration;
+      const rentPaid = await context.provider.connection.getBalance(
+        attesterPda
+      );
       expect(rentPaid).to.be.greaterThan(0);
 
       // Verify account exists
@@ -550,6 +618,15 @@ describe("Attester Management", () => {
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
           authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            attester1.keypair.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            attester1.keypair.publicKey
+          )),
         })
         .signers([context.authority.keypair])
         .rpc();
@@ -608,6 +685,15 @@ describe("Attester Management", () => {
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
           authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            attester1.keypair.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            attester1.keypair.publicKey
+          )),
         })
         .signers([context.authority.keypair])
         .rpc();
@@ -659,6 +745,15 @@ describe("Attester Management", () => {
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
           authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            attester1.keypair.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            attester1.keypair.publicKey
+          )),
         })
         .signers([context.authority.keypair])
         .rpc();
@@ -695,6 +790,15 @@ describe("Attester Management", () => {
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
           authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            attester1.keypair.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            attester1.keypair.publicKey
+          )),
         })
         .signers([context.authority.keypair])
         .rpc();
@@ -726,4 +830,1224 @@ describe("Attester Management", () => {
       );
     });
   });
+
+  describe("Attester Metadata", () => {
+    const metadata = {
+      name: "Example Attester",
+      url: "https://attester.example.com",
+      contact: "ops@example.com",
+      description: null,
+    };
+
+    async function registerWithMetadata(attester: Keypair) {
+      const [attesterPda] = findAttesterPDA(
+        attester.publicKey,
+        context.program.programId
+      );
+      const [metadataPda] = findAttesterMetadataPDA(
+        attester.publicKey,
+        context.program.programId
+      );
+      await context.program.methods
+        .registerAttester(attester.publicKey, metadata)
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          metadataAccount: metadataPda,
+          indexPage: await findAttesterIndexTailPDA(context.program),
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+      return { attesterPda, metadataPda };
+    }
+
+    async function updateMetadata(
+      attester: Keypair,
+      signer: Keypair,
+      newMetadata: any
+    ) {
+      const [attesterPda] = findAttesterPDA(
+        attester.publicKey,
+        context.program.programId
+      );
+      const [metadataPda] = findAttesterMetadataPDA(
+        attester.publicKey,
+        context.program.programId
+      );
+      await context.program.methods
+        .updateAttesterMetadata(attester.publicKey, newMetadata)
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          metadataAccount: metadataPda,
+          signer: signer.publicKey,
+          roleAccount: null,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([signer])
+        .rpc();
+    }
+
+    it("Should store metadata given at registration", async () => {
+      const attester = Keypair.generate();
+      const { metadataPda } = await registerWithMetadata(attester);
+
+      const account =
+        await context.program.account.attesterMetadataAccount.fetch(
+          metadataPda
+        );
+      expect(account.attester.toString()).to.equal(
+        attester.publicKey.toString()
+      );
+      expect(account.metadata.name).to.equal(metadata.name);
+      expect(account.metadata.url).to.equal(metadata.url);
+      expect(account.metadata.contact).to.equal(metadata.contact);
+      expect(account.metadata.description).to.be.null;
+      expect(account.updatedBy.toString()).to.equal(
+        context.authority.keypair.publicKey.toString()
+      );
+    });
+
+    it("Should let the attester update its own metadata", async () => {
+      const attester = await createFundedKeypair(context.provider);
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        attester.publicKey,
+        context.registry.registryPda
+      );
+
+      await updateMetadata(attester, attester, {
+        ...metadata,
+        description: "Validates compliance policies",
+      });
+
+      const [metadataPda] = findAttesterMetadataPDA(
+        attester.publicKey,
+        context.program.programId
+      );
+      const account =
+        await context.program.account.attesterMetadataAccount.fetch(
+          metadataPda
+        );
+      expect(account.metadata.description).to.equal(
+        "Validates compliance policies"
+      );
+      expect(account.updatedBy.toString()).to.equal(
+        attester.publicKey.toString()
+      );
+    });
+
+    it("Should reject metadata updates from unrelated keys", async () => {
+      const attester = Keypair.generate();
+      await registerWithMetadata(attester);
+      const stranger = await createFundedKeypair(context.provider);
+
+      try {
+        await updateMetadata(attester, stranger, metadata);
+        expect.fail("Should have rejected an unrelated signer");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should reject fields that are too long", async () => {
+      const attester = Keypair.generate();
+      await registerWithMetadata(attester);
+
+      try {
+        await updateMetadata(attester, context.authority.keypair, {
+          ...metadata,
+          name: "x".repeat(33),
+        });
+        expect.fail("Should have rejected an oversized name");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidAttesterMetadata");
+      }
+    });
+
+    it("Should close the metadata account on deregistration", async () => {
+      const attester = Keypair.generate();
+      const { attesterPda, metadataPda } = await registerWithMetadata(
+        attester
+      );
+
+      await context.program.methods
+        .deregisterAttester(attester.publicKey)
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            attester.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            attester.publicKey
+          )),
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+
+      const info = await context.provider.connection.getAccountInfo(
+        metadataPda
+      );
+      expect(info).to.be.null;
+    });
+  });
+
+  describe("Key Rotation", () => {
+    async function rotateAttesterKey(
+      oldKey: anchor.web3.PublicKey,
+      newKey: anchor.web3.PublicKey,
+      overlap: number
+    ) {
+      const [attesterPda] = findAttesterPDA(oldKey, context.program.programId);
+      const [newAttesterPda] = findAttesterPDA(
+        newKey,
+        context.program.programId
+      );
+      await context.program.methods
+        .rotateAttesterKey(oldKey, newKey, new anchor.BN(overlap))
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          newAttesterAccount: newAttesterPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          indexPage: await findAttesterIndexTailPDA(context.program),
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+      return { attesterPda, newAttesterPda };
+    }
+
+    it("Should link the new key to the old key's identity and history", async () => {
+      const oldKey = Keypair.generate().publicKey;
+      const newKey = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        oldKey,
+        context.registry.registryPda
+      );
+
+      const { attesterPda, newAttesterPda } = await rotateAttesterKey(
+        oldKey,
+        newKey,
+        3600
+      );
+
+      const oldAccount = await context.program.account.attesterAccount.fetch(
+        attesterPda
+      );
+      const newAccount = await context.program.account.attesterAccount.fetch(
+        newAttesterPda
+      );
+      expect(newAccount.isRegistered).to.be.true;
+      expect(newAccount.keyType).to.deep.equal({ ed25519: {} });
+      expect(newAccount.identity.toString()).to.equal(oldKey.toString());
+      expect(newAccount.previousKey.toString()).to.equal(oldKey.toString());
+      expect(newAccount.registeredAt.toNumber()).to.equal(
+        oldAccount.registeredAt.toNumber()
+      );
+      expect(newAccount.validFrom.toNumber()).to.equal(
+        oldAccount.validFrom.toNumber()
+      );
+      expect(oldAccount.nextKey.toString()).to.equal(newKey.toString());
+      // The old key stays valid for the overlap only
+      expect(oldAccount.isRegistered).to.be.true;
+      expect(oldAccount.validUntil.toNumber()).to.be.greaterThan(0);
+      // Both keys are listed in the attester index
+      expect(oldAccount.indexed).to.be.true;
+      expect(newAccount.indexed).to.be.true;
+      await findAttesterIndexPages(context.program, newKey);
+    });
+
+    it("Should carry the identity across successive rotations", async () => {
+      const firstKey = Keypair.generate().publicKey;
+      const secondKey = Keypair.generate().publicKey;
+      const thirdKey = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        firstKey,
+        context.registry.registryPda
+      );
+
+      await rotateAttesterKey(firstKey, secondKey, 0);
+      const { newAttesterPda } = await rotateAttesterKey(
+        secondKey,
+        thirdKey,
+        0
+      );
+
+      const thirdAccount =
+        await context.program.account.attesterAccount.fetch(newAttesterPda);
+      expect(thirdAccount.identity.toString()).to.equal(firstKey.toString());
+      expect(thirdAccount.previousKey.toString()).to.equal(
+        secondKey.toString()
+      );
+    });
+
+    it("Should reject rotating a key that was already rotated", async () => {
+      const oldKey = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        oldKey,
+        context.registry.registryPda
+      );
+      await rotateAttesterKey(oldKey, Keypair.generate().publicKey, 60);
+
+      try {
+        await rotateAttesterKey(oldKey, Keypair.generate().publicKey, 60);
+        expect.fail("Should have rejected a second rotation");
+      } catch (error: any) {
+        expect(error.message).to.include("AttesterAlreadyRotated");
+      }
+    });
+
+    it("Should reject an overlap longer than 30 days", async () => {
+      const oldKey = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        oldKey,
+        context.registry.registryPda
+      );
+
+      try {
+        await rotateAttesterKey(
+          oldKey,
+          Keypair.generate().publicKey,
+          31 * 24 * 60 * 60
+        );
+        expect.fail("Should have rejected the overlap");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidRotationOverlap");
+      }
+    });
+  });
+
+  describe("Suspension", () => {
+    async function setSuspended(attester: anchor.web3.PublicKey, suspended: boolean) {
+      const [attesterPda] = findAttesterPDA(attester, context.program.programId);
+      const method = suspended
+        ? context.program.methods.suspendAttester(attester)
+        : context.program.methods.reinstateAttester(attester);
+      await method
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+      return attesterPda;
+    }
+
+    it("Should suspend an attester without closing its account", async () => {
+      const attester = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        attester,
+        context.registry.registryPda
+      );
+      const registryBefore = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+
+      const attesterPda = await setSuspended(attester, true);
+
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterPda
+      );
+      expect(account.isRegistered).to.be.false;
+      expect(account.isSuspended).to.be.true;
+      expect(account.suspensionCount).to.equal(1);
+      expect(account.statusChangedAt.toNumber()).to.be.greaterThan(0);
+
+      // Suspended attesters remain counted by the registry
+      const registryAfter = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      expect(registryAfter.totalAttesters.toNumber()).to.equal(
+        registryBefore.totalAttesters.toNumber()
+      );
+    });
+
+    it("Should reinstate a suspended attester and keep its history", async () => {
+      const attester = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        attester,
+        context.registry.registryPda
+      );
+      await setSuspended(attester, true);
+      await setSuspended(attester, false);
+      const attesterPda = await setSuspended(attester, true);
+      await setSuspended(attester, false);
+
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterPda
+      );
+      expect(account.isRegistered).to.be.true;
+      expect(account.isSuspended).to.be.false;
+      expect(account.suspensionCount).to.equal(2);
+    });
+
+    it("Should reject suspending an attester twice", async () => {
+      const attester = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        attester,
+        context.registry.registryPda
+      );
+      await setSuspended(attester, true);
+
+      try {
+        await setSuspended(attester, true);
+        expect.fail("Should have rejected a second suspension");
+      } catch (error: any) {
+        expect(error.message).to.include("AttesterNotRegistered");
+      }
+    });
+
+    it("Should reject reinstating an attester that is not suspended", async () => {
+      const attester = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        attester,
+        context.registry.registryPda
+      );
+
+      try {
+        await setSuspended(attester, false);
+        expect.fail("Should have rejected reinstatement");
+      } catch (error: any) {
+        expect(error.message).to.include("AttesterNotSuspended");
+      }
+    });
+
+    it("Should allow deregistering a suspended attester", async () => {
+      const attester = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        attester,
+        context.registry.registryPda
+      );
+      const attesterPda = await setSuspended(attester, true);
+
+      await context.program.methods
+        .deregisterAttester(attester)
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(attester, context.program.programId),
+          ...(await findAttesterIndexPages(context.program, attester)),
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+
+      const account = await context.provider.connection.getAccountInfo(
+        attesterPda
+      );
+      expect(account).to.be.null;
+    });
+  });
+
+  describe("Bonded Self-Registration", () => {
+    const BOND = anchor.web3.LAMPORTS_PER_SOL / 10;
+
+    async function setBondConfig(bond: number, unbondingPeriod: number) {
+      await context.program.methods
+        .setBondConfig(new anchor.BN(bond), new anchor.BN(unbondingPeriod), new anchor.BN(0))
+        .accounts({
+          registry: context.registry.registryPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    function bondedAccounts(attester: anchor.web3.PublicKey) {
+      const [attesterPda] = findAttesterPDA(
+        attester,
+        context.program.programId
+      );
+      const [bondPda] = findAttesterBondPDA(
+        attester,
+        context.program.programId
+      );
+      return {
+        registry: context.registry.registryPda,
+        attesterAccount: attesterPda,
+        bondAccount: bondPda,
+      };
+    }
+
+    async function registerWithBond(attester: Keypair) {
+      await context.program.methods
+        .registerWithBond()
+        .accounts({
+          ...bondedAccounts(attester.publicKey),
+          scopeAccount: findAttesterScopePDA(
+            attester.publicKey,
+            context.program.programId
+          )[0],
+          indexPage: await findAttesterIndexTailPDA(context.program),
+          attester: attester.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([attester])
+        .rpc();
+    }
+
+    async function beginUnbonding(
+      attester: anchor.web3.PublicKey,
+      caller: Keypair
+    ) {
+      await context.program.methods
+        .beginUnbonding(attester)
+        .accounts({
+          ...bondedAccounts(attester),
+          ...findAttesterSideAccounts(attester, context.program.programId),
+          ...(await findAttesterIndexPages(context.program, attester)),
+          caller: caller.publicKey,
+        } as any)
+        .signers([caller])
+        .rpc();
+    }
+
+    async function withdrawBond(
+      attester: anchor.web3.PublicKey,
+      caller: Keypair,
+      recipient: anchor.web3.PublicKey = attester
+    ) {
+      await context.program.methods
+        .withdrawBond(attester)
+        .accounts({
+          ...bondedAccounts(attester),
+          recipient,
+          caller: caller.publicKey,
+        } as any)
+        .signers([caller])
+        .rpc();
+    }
+
+    async function postCollateral(
+      attester: anchor.web3.PublicKey,
+      depositor: Keypair,
+      amount: number
+    ) {
+      await context.program.methods
+        .postCollateral(attester, new anchor.BN(amount))
+        .accounts({
+          ...bondedAccounts(attester),
+          depositor: depositor.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([depositor])
+        .rpc();
+    }
+
+    after(async () => {
+      await setBondConfig(0, 0);
+    });
+
+    it("Should reject self-registration while no bond is configured", async () => {
+      await setBondConfig(0, 0);
+      const attester = await createFundedKeypair(context.provider);
+
+      try {
+        await registerWithBond(attester);
+        expect.fail("Should have rejected self-registration");
+      } catch (error: any) {
+        expect(error.message).to.include("SelfRegistrationDisabled");
+      }
+    });
+
+    it("Should register an attester that posts the bond", async () => {
+      await setBondConfig(BOND, 0);
+      const attester = await createFundedKeypair(context.provider);
+
+      await registerWithBond(attester);
+
+      const { attesterAccount, bondAccount } = bondedAccounts(attester.publicKey);
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterAccount
+      );
+      expect(account.isRegistered).to.be.true;
+      expect(account.bond.toNumber()).to.equal(BOND);
+      // Denied everything until an admin scopes it
+      expect(account.scoped).to.be.true;
+
+      const vault = await context.program.account.attesterBondAccount.fetch(
+        bondAccount
+      );
+      expect(vault.amount.toNumber()).to.equal(BOND);
+      expect(vault.withdrawableAt.toNumber()).to.equal(0);
+    });
+
+    it("Should not let an admin deregister a bonded attester", async () => {
+      await setBondConfig(BOND, 0);
+      const attester = await createFundedKeypair(context.provider);
+      await registerWithBond(attester);
+
+      try {
+        await context.program.methods
+          .deregisterAttester(attester.publicKey)
+          .accounts({
+            registry: context.registry.registryPda,
+            attesterAccount: bondedAccounts(attester.publicKey)
+              .attesterAccount,
+            authority: context.authority.keypair.publicKey,
+            roleAccount: null,
+            ...findAttesterSideAccounts(
+              attester.publicKey,
+              context.program.programId
+            ),
+            ...(await findAttesterIndexPages(
+              context.program,
+              attester.publicKey
+            )),
+          } as any)
+          .signers([context.authority.keypair])
+          .rpc();
+        expect.fail("Should have rejected deregistration");
+      } catch (error: any) {
+        expect(error.message).to.include("AttesterBonded");
+      }
+    });
+
+    it("Should return the bond after the attester exits", async () => {
+      await setBondConfig(BOND, 0);
+      const attester = await createFundedKeypair(context.provider);
+      await registerWithBond(attester);
+
+      try {
+        await withdrawBond(attester.publicKey, attester);
+        expect.fail("Should have rejected withdrawal before exiting");
+      } catch (error: any) {
+        expect(error.message).to.include("BondNotWithdrawable");
+      }
+
+      await beginUnbonding(attester.publicKey, attester);
+      const { attesterAccount, bondAccount } = bondedAccounts(
+        attester.publicKey
+      );
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterAccount
+      );
+      expect(account.isRegistered).to.be.false;
+      // Exiting attesters leave the attester index straight away
+      expect(account.indexed).to.be.false;
+
+      const balanceBefore = await context.provider.connection.getBalance(
+        attester.publicKey
+      );
+      await withdrawBond(attester.publicKey, attester);
+      const balanceAfter = await context.provider.connection.getBalance(
+        attester.publicKey
+      );
+      expect(balanceAfter - balanceBefore).to.be.greaterThan(BOND - 10_000);
+
+      const vault = await context.provider.connection.getAccountInfo(
+        bondAccount
+      );
+      expect(vault).to.be.null;
+    });
+
+    it("Should hold the bond for the unbonding period", async () => {
+      await setBondConfig(BOND, 3600);
+      const attester = await createFundedKeypair(context.provider);
+      await registerWithBond(attester);
+      await beginUnbonding(attester.publicKey, attester);
+
+      try {
+        await withdrawBond(attester.publicKey, attester);
+        expect.fail("Should have rejected withdrawal during unbonding");
+      } catch (error: any) {
+        expect(error.message).to.include("BondNotWithdrawable");
+      }
+    });
+
+    it("Should let the owner force out a bonded attester", async () => {
+      await setBondConfig(BOND, 0);
+      const attester = await createFundedKeypair(context.provider);
+      await registerWithBond(attester);
+      const { attesterAccount } = bondedAccounts(attester.publicKey);
+      const { scopeAccount } = findAttesterSideAccounts(
+        attester.publicKey,
+        context.program.programId
+      );
+      await context.program.methods
+        .setAttesterScope(attester.publicKey, {
+          programs: [Keypair.generate().publicKey],
+          policyPrefixes: [],
+        })
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount,
+          scopeAccount,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+
+      await beginUnbonding(attester.publicKey, context.authority.keypair);
+
+      // Exiting closes the scope so a later self-registration starts denied
+      const scope = await context.provider.connection.getAccountInfo(
+        scopeAccount
+      );
+      expect(scope).to.be.null;
+
+      // The owner cannot redirect the bond to itself
+      try {
+        await withdrawBond(
+          attester.publicKey,
+          context.authority.keypair,
+          context.authority.keypair.publicKey
+        );
+        expect.fail("Should have rejected the wrong recipient");
+      } catch (error: any) {
+        expect(error.message).to.include("BondRecipientMismatch");
+      }
+
+      const balanceBefore = await context.provider.connection.getBalance(
+        attester.publicKey
+      );
+      await withdrawBond(attester.publicKey, context.authority.keypair);
+      const balanceAfter = await context.provider.connection.getBalance(
+        attester.publicKey
+      );
+      expect(balanceAfter - balanceBefore).to.be.greaterThan(BOND);
+    });
+
+    it("Should reject a force-out by anyone but the owner", async () => {
+      await setBondConfig(BOND, 0);
+      const attester = await createFundedKeypair(context.provider);
+      const stranger = await createFundedKeypair(context.provider);
+      await registerWithBond(attester);
+
+      try {
+        await beginUnbonding(attester.publicKey, stranger);
+        expect.fail("Should have rejected the stranger");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should reject collateral posted by a third party", async () => {
+      const attester = Keypair.generate().publicKey;
+      const stranger = await createFundedKeypair(context.provider);
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        attester,
+        context.registry.registryPda
+      );
+
+      try {
+        await postCollateral(attester, stranger, BOND);
+        expect.fail("Should have rejected the stranger's collateral");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should return an Ethereum attester's collateral to the authority", async () => {
+      const ethAddress = Keypair.generate().publicKey.toBuffer().subarray(0, 20);
+      const attester = secp256k1AttesterKey(ethAddress);
+      const { attesterAccount, bondAccount } = bondedAccounts(attester);
+      await context.program.methods
+        .registerSecp256k1Attester(Array.from(ethAddress))
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          indexPage: await findAttesterIndexTailPDA(context.program),
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+      await postCollateral(attester, context.authority.keypair, BOND);
+
+      // The key cannot sign, so the owner drives its exit
+      await beginUnbonding(attester, context.authority.keypair);
+      const authority = context.authority.keypair.publicKey;
+      const balanceBefore = await context.provider.connection.getBalance(
+        authority
+      );
+      await withdrawBond(attester, context.authority.keypair, authority);
+      const balanceAfter = await context.provider.connection.getBalance(
+        authority
+      );
+      expect(balanceAfter - balanceBefore).to.be.greaterThan(BOND - 10_000);
+
+      expect(
+        await context.provider.connection.getAccountInfo(attesterAccount)
+      ).to.be.null;
+      expect(await context.provider.connection.getAccountInfo(bondAccount)).to
+        .be.null;
+    });
+  });
+
+  describe("Attester Index", () => {
+    async function fetchIndexLen(): Promise<number> {
+      const registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      return registry.attesterIndexLen.toNumber();
+    }
+
+    function indexPagePDA(page: number) {
+      return findAttesterIndexPDA(page, context.program.programId)[0];
+    }
+
+    async function listIndex(): Promise<anchor.web3.PublicKey[]> {
+      const len = await fetchIndexLen();
+      const attesters: anchor.web3.PublicKey[] = [];
+      for (let page = 0; page * ATTESTER_INDEX_PAGE_SIZE < len; page++) {
+        const entries = await context.program.methods
+          .getRegisteredAttesters(page)
+          .accounts({
+            registry: context.registry.registryPda,
+            indexPage: indexPagePDA(page),
+          } as any)
+          .view();
+        attesters.push(...entries);
+      }
+      return attesters;
+    }
+
+    async function register(attester: anchor.web3.PublicKey) {
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        attester,
+        context.registry.registryPda
+      );
+      return findAttesterPDA(attester, context.program.programId)[0];
+    }
+
+    async function deregister(
+      attester: anchor.web3.PublicKey,
+      pages: {
+        indexPage: anchor.web3.PublicKey;
+        tailPage: anchor.web3.PublicKey | null;
+      }
+    ) {
+      await context.program.methods
+        .deregisterAttester(attester)
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: findAttesterPDA(
+            attester,
+            context.program.programId
+          )[0],
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(attester, context.program.programId),
+          ...pages,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    it("Should list every registered attester", async () => {
+      const attester = Keypair.generate().publicKey;
+      const lenBefore = await fetchIndexLen();
+
+      const attesterPda = await register(attester);
+
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterPda
+      );
+      expect(account.indexed).to.be.true;
+      expect(await fetchIndexLen()).to.equal(lenBefore + 1);
+
+      const listed = await listIndex();
+      expect(listed.filter((key) => key.equals(attester))).to.have.length(1);
+    });
+
+    it("Should keep the index dense when an attester is deregistered", async () => {
+      const first = Keypair.generate().publicKey;
+      const second = Keypair.generate().publicKey;
+      await register(first);
+      await register(second);
+      const lenBefore = await fetchIndexLen();
+
+      await deregister(
+        first,
+        await findAttesterIndexPages(context.program, first)
+      );
+
+      expect(await fetchIndexLen()).to.equal(lenBefore - 1);
+      const listed = await listIndex();
+      expect(listed).to.have.length(lenBefore - 1);
+      expect(listed.some((key) => key.equals(first))).to.be.false;
+      expect(listed.some((key) => key.equals(second))).to.be.true;
+    });
+
+    it("Should reject index pages that do not list the attester", async () => {
+      // Make sure the attester lands on a later page than page 0
+      while ((await fetchIndexLen()) < ATTESTER_INDEX_PAGE_SIZE) {
+        await register(Keypair.generate().publicKey);
+      }
+      const attester = Keypair.generate().publicKey;
+      await register(attester);
+      const { indexPage } = await findAttesterIndexPages(
+        context.program,
+        attester
+      );
+
+      try {
+        await deregister(attester, {
+          indexPage: indexPagePDA(0),
+          tailPage: indexPage,
+        });
+        expect.fail("Should have rejected the wrong index page");
+      } catch (error: any) {
+        expect(error.message).to.include("AttesterIndexMismatch");
+      }
+
+      await deregister(attester, { indexPage, tailPage: null });
+      expect((await listIndex()).some((key) => key.equals(attester))).to.be
+        .false;
+    });
+
+    it("Should restrict index backfill to the registry authority", async () => {
+      const attester = Keypair.generate().publicKey;
+      const attesterPda = await register(attester);
+      const stranger = await createFundedKeypair(context.provider);
+
+      const backfill = async (authority: Keypair) =>
+        context.program.methods
+          .indexAttester(attester)
+          .accounts({
+            registry: context.registry.registryPda,
+            attesterAccount: attesterPda,
+            indexPage: await findAttesterIndexTailPDA(context.program),
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .signers([authority])
+          .rpc();
+
+      try {
+        await backfill(stranger);
+        expect.fail("Should have rejected a stranger");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+
+      // Registration already listed the attester
+      try {
+        await backfill(context.authority.keypair);
+        expect.fail("Should have rejected listing the attester twice");
+      } catch (error: any) {
+        expect(error.message).to.include("AttesterAlreadyIndexed");
+      }
+    });
+  });
+
+  describe("Batch Registration", () => {
+    function attesterMetas(attesters: anchor.web3.PublicKey[]) {
+      return attesters.map((attester) => ({
+        pubkey: findAttesterPDA(attester, context.program.programId)[0],
+        isSigner: false,
+        isWritable: true,
+      }));
+    }
+
+    async function fetchIndexLen(): Promise<number> {
+      const registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      return registry.attesterIndexLen.toNumber();
+    }
+
+    function indexPagePDA(page: number) {
+      return findAttesterIndexPDA(page, context.program.programId)[0];
+    }
+
+    async function registerBatch(
+      attesters: anchor.web3.PublicKey[],
+      { withNextPage }: { withNextPage?: boolean } = {}
+    ) {
+      const len = await fetchIndexLen();
+      const tailPage = Math.floor(len / ATTESTER_INDEX_PAGE_SIZE);
+      const spills =
+        (len % ATTESTER_INDEX_PAGE_SIZE) + attesters.length >
+        ATTESTER_INDEX_PAGE_SIZE;
+      await context.program.methods
+        .registerAttesters(attesters)
+        .accounts({
+          registry: context.registry.registryPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          indexPage: indexPagePDA(tailPage),
+          nextIndexPage:
+            (withNextPage ?? spills) ? indexPagePDA(tailPage + 1) : null,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .remainingAccounts(attesterMetas(attesters))
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    async function deregisterBatch(attesters: anchor.web3.PublicKey[]) {
+      await context.program.methods
+        .deregisterAttesters(attesters)
+        .accounts({
+          registry: context.registry.registryPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+        } as any)
+        .remainingAccounts([
+          ...attesters.flatMap((attester) =>
+            [
+              findAttesterPDA(attester, context.program.programId)[0],
+              findAttesterMetadataPDA(attester, context.program.programId)[0],
+              // Batch-registered attesters are their own identity
+              findAttesterScopePDA(attester, context.program.programId)[0],
+            ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+          ),
+          ...(await indexPageMetas()),
+        ])
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    // Every page of the index, which covers the pages listing the batch and
+    // the index's last page
+    async function indexPageMetas() {
+      const len = await fetchIndexLen();
+      const pages = Math.ceil(len / ATTESTER_INDEX_PAGE_SIZE);
+      return Array.from({ length: pages }, (_, page) => ({
+        pubkey: indexPagePDA(page),
+        isSigner: false,
+        isWritable: true,
+      }));
+    }
+
+    async function fetchTotalAttesters(): Promise<number> {
+      const registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      return registry.totalAttesters.toNumber();
+    }
+
+    it("Should register and deregister a batch of attesters", async () => {
+      const attesters = [0, 1, 2].map(() => Keypair.generate().publicKey);
+      const totalBefore = await fetchTotalAttesters();
+
+      await registerBatch(attesters);
+
+      expect(await fetchTotalAttesters()).to.equal(totalBefore + 3);
+      for (const attester of attesters) {
+        const account = await context.program.account.attesterAccount.fetch(
+          findAttesterPDA(attester, context.program.programId)[0]
+        );
+        expect(account.attester.toString()).to.equal(attester.toString());
+        expect(account.isRegistered).to.be.true;
+        expect(account.scoped).to.be.false;
+      }
+
+      await deregisterBatch(attesters);
+
+      expect(await fetchTotalAttesters()).to.equal(totalBefore);
+      for (const attester of attesters) {
+        const info = await context.provider.connection.getAccountInfo(
+          findAttesterPDA(attester, context.program.programId)[0]
+        );
+        expect(info).to.be.null;
+      }
+    });
+
+    it("Should register nothing if any attester in the batch is already registered", async () => {
+      const existing = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        existing,
+        context.registry.registryPda
+      );
+      const fresh = Keypair.generate().publicKey;
+      const totalBefore = await fetchTotalAttesters();
+
+      try {
+        await registerBatch([fresh, existing]);
+        expect.fail("Should have rejected the batch");
+      } catch (error: any) {
+        expect(error.message).to.include("AttesterAlreadyRegistered");
+      }
+
+      expect(await fetchTotalAttesters()).to.equal(totalBefore);
+      const info = await context.provider.connection.getAccountInfo(
+        findAttesterPDA(fresh, context.program.programId)[0]
+      );
+      expect(info).to.be.null;
+    });
+
+    it("Should reject batches with duplicate attesters", async () => {
+      const attester = Keypair.generate().publicKey;
+
+      try {
+        await registerBatch([attester, attester]);
+        expect.fail("Should have rejected the batch");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidAttesterBatch");
+      }
+    });
+
+    it("Should close metadata and scope accounts with a batch", async () => {
+      const attesters = [0, 1].map(() => Keypair.generate().publicKey);
+      await registerBatch(attesters);
+      const [attester] = attesters;
+      const [attesterPda] = findAttesterPDA(
+        attester,
+        context.program.programId
+      );
+      const [metadataPda] = findAttesterMetadataPDA(
+        attester,
+        context.program.programId
+      );
+      const [scopePda] = findAttesterScopePDA(
+        attester,
+        context.program.programId
+      );
+      await context.program.methods
+        .updateAttesterMetadata(attester, {
+          name: "Batch Attester",
+          url: null,
+          contact: null,
+          description: null,
+        })
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          metadataAccount: metadataPda,
+          signer: context.authority.keypair.publicKey,
+          roleAccount: null,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+      await context.program.methods
+        .setAttesterScope(attester, {
+          programs: [Keypair.generate().publicKey],
+          policyPrefixes: [],
+        })
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          scopeAccount: scopePda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+
+      // The second attester has neither, and its PDAs are passed all the same
+      await deregisterBatch(attesters);
+
+      for (const pda of [metadataPda, scopePda]) {
+        const info = await context.provider.connection.getAccountInfo(pda);
+        expect(info).to.be.null;
+      }
+    });
+
+    it("Should let a batch-registered attester be deregistered on its own", async () => {
+      const attesters = [0, 1, 2].map(() => Keypair.generate().publicKey);
+      await registerBatch(attesters);
+      const [, attester] = attesters;
+      const [attesterPda] = findAttesterPDA(
+        attester,
+        context.program.programId
+      );
+      const totalBefore = await fetchTotalAttesters();
+      const lenBefore = await fetchIndexLen();
+
+      await context.program.methods
+        .deregisterAttester(attester)
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(attester, context.program.programId),
+          ...(await findAttesterIndexPages(context.program, attester)),
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+
+      expect(await fetchTotalAttesters()).to.equal(totalBefore - 1);
+      expect(await fetchIndexLen()).to.equal(lenBefore - 1);
+      const info = await context.provider.connection.getAccountInfo(
+        attesterPda
+      );
+      expect(info).to.be.null;
+
+      // The rest of the batch can still leave together
+      await deregisterBatch([attesters[0], attesters[2]]);
+      expect(await fetchTotalAttesters()).to.equal(totalBefore - 3);
+    });
+
+    it("Should spill a batch onto the next index page when the tail fills up", async () => {
+      const tailLen = (await fetchIndexLen()) % ATTESTER_INDEX_PAGE_SIZE;
+      const attesters = Array.from(
+        { length: ATTESTER_INDEX_PAGE_SIZE - tailLen + 1 },
+        () => Keypair.generate().publicKey
+      );
+
+      try {
+        await registerBatch(attesters, { withNextPage: false });
+        expect.fail("Should have required the next index page");
+      } catch (error: any) {
+        expect(error.message).to.include("AttesterIndexMismatch");
+      }
+
+      const lenBefore = await fetchIndexLen();
+      await registerBatch(attesters);
+
+      expect(await fetchIndexLen()).to.equal(lenBefore + attesters.length);
+      for (const attester of attesters) {
+        const account = await context.program.account.attesterAccount.fetch(
+          findAttesterPDA(attester, context.program.programId)[0]
+        );
+        expect(account.indexed).to.be.true;
+      }
+      const lastPage = await context.program.account.attesterIndexPage.fetch(
+        indexPagePDA(Math.floor(lenBefore / ATTESTER_INDEX_PAGE_SIZE) + 1)
+      );
+      expect(lastPage.attesters[0].equals(attesters[attesters.length - 1])).to
+        .be.true;
+
+      await deregisterBatch(attesters);
+      expect(await fetchIndexLen()).to.equal(lenBefore);
+    });
+  });
 });
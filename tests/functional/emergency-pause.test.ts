@@ -0,0 +1,341 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Counter } from "../../target/types/counter";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  Ed25519Program,
+  SystemProgram,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import * as crypto from "crypto";
+import {
+  setupSharedTestContext,
+  SharedTestContext,
+} from "../helpers/shared-setup";
+import {
+  createTestAccount,
+  findAttesterPDA,
+  findPolicyPDA,
+  findUsedUuidPDA,
+  registerAttesterIfNotExists,
+  setPolicyIdOrUpdate,
+  setPolicyPaused,
+  getFutureTimestamp,
+  createMessageHash,
+  createAttestationSignature,
+} from "../helpers/test-utils";
+
+describe("Emergency Pause", () => {
+  let context: SharedTestContext;
+  let attester: Keypair;
+  let client: Keypair;
+  let guardian: Keypair;
+  let targetProgramId: PublicKey;
+  let attesterPda: PublicKey;
+  let policyPda: PublicKey;
+
+  const testPolicy = "test-policy-v1";
+
+  before(async () => {
+    context = await setupSharedTestContext();
+
+    const counterProgram = anchor.workspace.Counter as Program<Counter>;
+    targetProgramId = counterProgram.programId;
+
+    attester = (await createTestAccount(context.provider)).keypair;
+    client = (await createTestAccount(context.provider)).keypair;
+    guardian = (await createTestAccount(context.provider)).keypair;
+
+    [attesterPda] = findAttesterPDA(
+      attester.publicKey,
+      context.program.programId
+    );
+    [policyPda] = findPolicyPDA(targetProgramId, context.program.programId);
+
+    await registerAttesterIfNotExists(
+      context.program,
+      context.authority.keypair,
+      attester.publicKey,
+      context.registry.registryPda
+    );
+    await setPolicyIdOrUpdate(
+      context.program,
+      targetProgramId,
+      context.authority.keypair,
+      testPolicy,
+      context.registry.registryPda
+    );
+  });
+
+  afterEach(async () => {
+    // Never leave the shared registry paused for later suites
+    const registry = await context.program.account.predicateRegistry.fetch(
+      context.registry.registryPda
+    );
+    if (registry.paused) {
+      await unpause(context.authority.keypair);
+    }
+  });
+
+  after(async () => {
+    await setGuardian(null);
+  });
+
+  async function pause(signer: Keypair) {
+    await context.program.methods
+      .pause()
+      .accounts({
+        registry: context.registry.registryPda,
+        signer: signer.publicKey,
+        roleAccount: null,
+      } as any)
+      .signers([signer])
+      .rpc();
+  }
+
+  async function unpause(authority: Keypair) {
+    await context.program.methods
+      .unpause()
+      .accounts({
+        registry: context.registry.registryPda,
+        authority: authority.publicKey,
+      } as any)
+      .signers([authority])
+      .rpc();
+  }
+
+  async function setGuardian(newGuardian: PublicKey | null) {
+    await context.program.methods
+      .setGuardian(newGuardian)
+      .accounts({
+        registry: context.registry.registryPda,
+        authority: context.authority.keypair.publicKey,
+      } as any)
+      .signers([context.authority.keypair])
+      .rpc();
+  }
+
+  async function validate() {
+    const uuid = crypto.randomBytes(16);
+    const expiration = getFutureTimestamp(3600);
+    const statement = {
+      uuid: Array.from(uuid),
+      msgSender: client.publicKey,
+      target: targetProgramId,
+      msgValue: new anchor.BN(0),
+      encodedSigAndArgs: Buffer.from("test-encoded-data"),
+      policyId: testPolicy,
+      expiration: new anchor.BN(expiration),
+      issuedAt: new anchor.BN(Math.min(expiration, Math.floor(Date.now() / 1000))),
+    };
+    const signature = createAttestationSignature(statement, attester);
+    const attestation = {
+      uuid: Array.from(uuid),
+      attester: attester.publicKey,
+      signature: Array.from(signature),
+      expiration: new anchor.BN(expiration),
+      issuedAt: statement.issuedAt,
+    };
+
+    const ed25519Instruction = Ed25519Program.createInstructionWithPublicKey({
+      publicKey: attester.publicKey.toBytes(),
+      message: createMessageHash(statement),
+      signature: signature,
+    });
+
+    const [usedUuidPda] = findUsedUuidPDA(
+      Array.from(uuid),
+      context.program.programId
+    );
+
+    const validateInstruction = await context.program.methods
+      .validateAttestation(
+        statement.target,
+        statement.msgValue,
+        statement.encodedSigAndArgs,
+        attestation
+      )
+      .accounts({
+        registry: context.registry.registryPda,
+        attesterAccount: attesterPda,
+        attesterScopeAccount: null,
+        policyAccount: policyPda,
+        usedUuidAccount: usedUuidPda,
+        signer: client.publicKey,
+        systemProgram: SystemProgram.programId,
+        instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      } as any)
+      .instruction();
+
+    const transaction = new Transaction()
+      .add(ed25519Instruction)
+      .add(validateInstruction);
+    return context.provider.sendAndConfirm(transaction, [client]);
+  }
+
+  it("Should reject validation while paused and accept after unpause", async () => {
+    await pause(context.authority.keypair);
+
+    try {
+      await validate();
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("Registry paused");
+    }
+
+    await unpause(context.authority.keypair);
+    const result = await validate();
+    expect(result).to.be.a("string");
+  });
+
+  it("Should emit RegistryPaused and RegistryUnpaused events", async () => {
+    let pausedBy: PublicKey | null = null;
+    let unpausedBy: PublicKey | null = null;
+    const pausedListener = context.program.addEventListener(
+      "registryPaused",
+      (event: any) => {
+        pausedBy = event.pausedBy;
+      }
+    );
+    const unpausedListener = context.program.addEventListener(
+      "registryUnpaused",
+      (event: any) => {
+        unpausedBy = event.authority;
+      }
+    );
+
+    try {
+      await pause(context.authority.keypair);
+      await unpause(context.authority.keypair);
+      await new Promise((resolve) => setTimeout(resolve, 100));
+
+      expect(pausedBy?.toString()).to.equal(
+        context.authority.keypair.publicKey.toString()
+      );
+      expect(unpausedBy?.toString()).to.equal(
+        context.authority.keypair.publicKey.toString()
+      );
+    } finally {
+      await context.program.removeEventListener(pausedListener);
+      await context.program.removeEventListener(unpausedListener);
+    }
+  });
+
+  it("Should reject pausing twice", async () => {
+    await pause(context.authority.keypair);
+    try {
+      await pause(context.authority.keypair);
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("Registry already paused");
+    }
+  });
+
+  it("Should reject unpausing when not paused", async () => {
+    try {
+      await unpause(context.authority.keypair);
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("Registry not paused");
+    }
+  });
+
+  it("Should reject pause from an unauthorized key", async () => {
+    try {
+      await pause(Keypair.generate());
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("Unauthorized");
+    }
+  });
+
+  it("Should reject validation for a paused target program", async () => {
+    await setPolicyPaused(
+      context.program,
+      targetProgramId,
+      context.authority.keypair,
+      true,
+      context.registry.registryPda
+    );
+
+    try {
+      await validate();
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("Policy paused");
+    } finally {
+      await setPolicyPaused(
+        context.program,
+        targetProgramId,
+        context.authority.keypair,
+        false,
+        context.registry.registryPda
+      );
+    }
+
+    const result = await validate();
+    expect(result).to.be.a("string");
+  });
+
+  describe("Guardian", () => {
+    before(async () => {
+      await setGuardian(guardian.publicKey);
+    });
+
+    it("Should store the guardian on the registry", async () => {
+      const registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      expect(registry.guardian?.toString()).to.equal(
+        guardian.publicKey.toString()
+      );
+    });
+
+    it("Should allow the guardian to pause", async () => {
+      await pause(guardian);
+
+      const registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      expect(registry.paused).to.be.true;
+    });
+
+    it("Should not allow the guardian to unpause", async () => {
+      await pause(guardian);
+      try {
+        await unpause(guardian);
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should not allow the guardian to change the guardian", async () => {
+      try {
+        await context.program.methods
+          .setGuardian(guardian.publicKey)
+          .accounts({
+            registry: context.registry.registryPda,
+            authority: guardian.publicKey,
+          } as any)
+          .signers([guardian])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should revoke pause rights when the guardian is cleared", async () => {
+      await setGuardian(null);
+      try {
+        await pause(guardian);
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+  });
+});
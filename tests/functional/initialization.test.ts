@@ -1,9 +1,19 @@
 import { expect } from "chai";
-import { SystemProgram } from "@solana/web3.js";
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
 import {
   setupSharedTestContext,
   SharedTestContext,
 } from "../helpers/shared-setup";
+import {
+  createTestAccount,
+  DEFAULT_REGISTRY_ID,
+  findAttesterPDA,
+  findProgramDataPDA,
+  findRegistryPDA,
+  initializeRegistry,
+  registerAttester,
+} from "../helpers/test-utils";
 
 describe("Registry Initialization", () => {
   let context: SharedTestContext;
@@ -54,9 +64,10 @@ describe("Registry Initialization", () => {
       // Try to initialize again - this should fail
       try {
         await context.program.methods
-          .initialize()
+          .initialize(new anchor.BN(DEFAULT_REGISTRY_ID))
           .accounts({
             registry: context.registry.registryPda,
+            programData: findProgramDataPDA(context.program.programId),
             authority: freshAuthority.publicKey,
             systemProgram: SystemProgram.programId,
           } as any)
@@ -72,5 +83,188 @@ describe("Registry Initialization", () => {
         expect(error.message).to.include("already in use");
       }
     });
+
+    it("Should reject a signer that is not the program upgrade authority", async () => {
+      const attacker = await createTestAccount(context.provider);
+      // Use an unclaimed namespace so only the upgrade authority check can fail
+      const registryId = new anchor.BN(Date.now());
+      const { registryPda } = findRegistryPDA(
+        context.program.programId,
+        registryId
+      );
+
+      try {
+        await context.program.methods
+          .initialize(registryId)
+          .accounts({
+            registry: registryPda,
+            programData: findProgramDataPDA(context.program.programId),
+            authority: attacker.keypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .signers([attacker.keypair])
+          .rpc();
+
+        expect.fail("Expected initialization by non-upgrade-authority to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should reject a program data account for a different program", async () => {
+      const otherProgramData = findProgramDataPDA(SystemProgram.programId);
+      const registryId = new anchor.BN(Date.now());
+      const { registryPda } = findRegistryPDA(
+        context.program.programId,
+        registryId
+      );
+
+      try {
+        await context.program.methods
+          .initialize(registryId)
+          .accounts({
+            registry: registryPda,
+            programData: otherProgramData,
+            authority: context.authority.keypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .signers([context.authority.keypair])
+          .rpc();
+
+        expect.fail("Expected initialization with foreign program data to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("ConstraintSeeds");
+      }
+    });
+  });
+
+  describe("Namespaced Registries", () => {
+    it("Should keep registry 0 and its accounts on the original addresses", async () => {
+      const [legacyRegistryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("predicate_registry")],
+        context.program.programId
+      );
+      expect(context.registry.registryPda.toString()).to.equal(
+        legacyRegistryPda.toString()
+      );
+
+      const attester = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        attester,
+        context.registry.registryPda
+      );
+
+      const [legacyAttesterPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("attester"), attester.toBuffer()],
+        context.program.programId
+      );
+      const legacyAttester =
+        await context.program.account.attesterAccount.fetch(legacyAttesterPda);
+      expect(legacyAttester.attester.toString()).to.equal(attester.toString());
+    });
+
+    it("Should host independent registries on one program", async () => {
+      const registryId = new anchor.BN(Date.now());
+      const { registryPda } = findRegistryPDA(
+        context.program.programId,
+        registryId
+      );
+      expect(registryPda.toString()).to.not.equal(
+        context.registry.registryPda.toString()
+      );
+
+      await initializeRegistry(
+        context.program,
+        context.authority.keypair,
+        registryPda,
+        registryId
+      );
+
+      const registryAccount =
+        await context.program.account.predicateRegistry.fetch(registryPda);
+      expect(registryAccount.registryId.toString()).to.equal(
+        registryId.toString()
+      );
+      expect(registryAccount.totalAttesters.toNumber()).to.equal(0);
+
+      // The same attester key gets a separate account in each registry
+      const attester = Keypair.generate().publicKey;
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        attester,
+        registryPda
+      );
+
+      const [scopedAttesterPda] = findAttesterPDA(
+        attester,
+        context.program.programId,
+        registryPda
+      );
+      const [defaultAttesterPda] = findAttesterPDA(
+        attester,
+        context.program.programId
+      );
+      const scopedAttester =
+        await context.program.account.attesterAccount.fetch(scopedAttesterPda);
+      expect(scopedAttester.isRegistered).to.be.true;
+
+      const defaultAttester = await context.provider.connection.getAccountInfo(
+        defaultAttesterPda
+      );
+      expect(defaultAttester).to.be.null;
+    });
+  });
+
+  describe("Account Versioning", () => {
+    const CURRENT_ACCOUNT_VERSION = 2;
+
+    it("Should create accounts with the current layout version", async () => {
+      const registryAccount =
+        await context.program.account.predicateRegistry.fetch(
+          context.registry.registryPda
+        );
+      expect(registryAccount.version).to.equal(CURRENT_ACCOUNT_VERSION);
+      expect(registryAccount.reserved.every((b: number) => b === 0)).to.be
+        .true;
+    });
+
+    it("Should reject migrating an account that is already current", async () => {
+      const payer = (await createTestAccount(context.provider)).keypair;
+      try {
+        await context.program.methods
+          .migrateAccount()
+          .accounts({
+            account: context.registry.registryPda,
+            payer: payer.publicKey,
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .signers([payer])
+          .rpc();
+        expect.fail("Should have rejected an already migrated account");
+      } catch (error: any) {
+        expect(error.message).to.include("AccountAlreadyMigrated");
+      }
+    });
+
+    it("Should reject migrating an account owned by another program", async () => {
+      const payer = (await createTestAccount(context.provider)).keypair;
+      try {
+        await context.program.methods
+          .migrateAccount()
+          .accounts({
+            account: payer.publicKey,
+            payer: payer.publicKey,
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .signers([payer])
+          .rpc();
+        expect.fail("Should have rejected a foreign account");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidAccountData");
+      }
+    });
   });
 });
@@ -14,6 +14,10 @@ import {
   registerAttester,
   setPolicyId,
   setPolicyIdOrUpdate,
+  transferAuthority,
+  findAttesterIndexPages,
+  findAttesterIndexTailPDA,
+  findAttesterSideAccounts,
 } from "../helpers/test-utils";
 
 describe("Integration Tests", () => {
@@ -76,14 +80,12 @@ describe("Integration Tests", () => {
             return;
           }
 
-          await context.program.methods
-            .transferAuthority(originalAuthority.publicKey)
-            .accounts({
-              registry: context.registry.registryPda,
-              authority: currentAuthorityKeypair.publicKey,
-            } as any)
-            .signers([currentAuthorityKeypair])
-            .rpc();
+          await transferAuthority(
+            context.program,
+            currentAuthorityKeypair,
+            originalAuthority,
+            context.registry.registryPda
+          );
         }
       } catch (error) {
         console.warn("Failed to restore original authority:", error);
@@ -161,14 +163,12 @@ describe("Integration Tests", () => {
       }
 
       // 5. Transfer authority
-      await context.program.methods
-        .transferAuthority(newAuthority.publicKey)
-        .accounts({
-          registry: context.registry.registryPda,
-          authority: context.authority.keypair.publicKey,
-        } as any)
-        .signers([context.authority.keypair])
-        .rpc();
+      await transferAuthority(
+        context.program,
+        context.authority.keypair,
+        newAuthority,
+        context.registry.registryPda
+      );
 
       registryAccount = await context.program.account.predicateRegistry.fetch(
         context.registry.registryPda
@@ -207,14 +207,12 @@ describe("Integration Tests", () => {
       let currentAuthority = context.authority.keypair;
 
       for (const nextAuthority of authorities) {
-        await context.program.methods
-          .transferAuthority(nextAuthority.publicKey)
-          .accounts({
-            registry: context.registry.registryPda,
-            authority: currentAuthority.publicKey,
-          } as any)
-          .signers([currentAuthority])
-          .rpc();
+        await transferAuthority(
+          context.program,
+          currentAuthority,
+          nextAuthority,
+          context.registry.registryPda
+        );
 
         currentAuthority = nextAuthority;
       }
@@ -292,14 +290,12 @@ describe("Integration Tests", () => {
             return;
           }
 
-          await context.program.methods
-            .transferAuthority(originalAuthority.publicKey)
-            .accounts({
-              registry: context.registry.registryPda,
-              authority: currentAuthorityKeypair.publicKey,
-            } as any)
-            .signers([currentAuthorityKeypair])
-            .rpc();
+          await transferAuthority(
+            context.program,
+            currentAuthorityKeypair,
+            originalAuthority,
+            context.registry.registryPda
+          );
         }
       } catch (error) {
         console.warn("Failed to restore original authority:", error);
@@ -314,14 +310,12 @@ describe("Integration Tests", () => {
       const totalAttestersBefore = registryBefore.totalAttesters.toNumber();
 
       // Transfer authority
-      await context.program.methods
-        .transferAuthority(newAuthority.publicKey)
-        .accounts({
-          registry: context.registry.registryPda,
-          authority: context.authority.keypair.publicKey,
-        } as any)
-        .signers([context.authority.keypair])
-        .rpc();
+      await transferAuthority(
+        context.program,
+        context.authority.keypair,
+        newAuthority,
+        context.registry.registryPda
+      );
 
       // Try to register with old authority (should fail)
       const newAttester = Keypair.generate();
@@ -332,11 +326,14 @@ describe("Integration Tests", () => {
 
       try {
         await context.program.methods
-          .registerAttester(newAttester.publicKey)
+          .registerAttester(newAttester.publicKey, null)
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: newAttesterPda,
             authority: context.authority.keypair.publicKey, // Old authority
+            roleAccount: null,
+            metadataAccount: null,
+            indexPage: await findAttesterIndexTailPDA(context.program),
             systemProgram: SystemProgram.programId,
           })
           .signers([context.authority.keypair])
@@ -479,14 +476,12 @@ describe("Integration Tests", () => {
             return;
           }
 
-          await context.program.methods
-            .transferAuthority(originalAuthority.publicKey)
-            .accounts({
-              registry: context.registry.registryPda,
-              authority: currentAuthorityKeypair.publicKey,
-            } as any)
-            .signers([currentAuthorityKeypair])
-            .rpc();
+          await transferAuthority(
+            context.program,
+            currentAuthorityKeypair,
+            originalAuthority,
+            context.registry.registryPda
+          );
         }
       } catch (error) {
         console.warn("Failed to restore original authority:", error);
@@ -664,6 +659,15 @@ describe("Integration Tests", () => {
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
           authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            attester3.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            attester3.publicKey
+          )),
         })
         .signers([context.authority.keypair])
         .rpc();
@@ -678,14 +682,12 @@ describe("Integration Tests", () => {
       // Transfer authority (should update timestamp but not counts)
       const updatedAtBeforeTransfer = registryAccount.updatedAt.toNumber();
 
-      await context.program.methods
-        .transferAuthority(newAuthority.publicKey)
-        .accounts({
-          registry: context.registry.registryPda,
-          authority: context.authority.keypair.publicKey,
-        } as any)
-        .signers([context.authority.keypair])
-        .rpc();
+      await transferAuthority(
+        context.program,
+        context.authority.keypair,
+        newAuthority,
+        context.registry.registryPda
+      );
 
       registryAccount = await context.program.account.predicateRegistry.fetch(
         context.registry.registryPda
@@ -723,14 +725,12 @@ describe("Integration Tests", () => {
             return;
           }
 
-          await context.program.methods
-            .transferAuthority(originalAuthority.publicKey)
-            .accounts({
-              registry: context.registry.registryPda,
-              authority: currentAuthorityKeypair.publicKey,
-            } as any)
-            .signers([currentAuthorityKeypair])
-            .rpc();
+          await transferAuthority(
+            context.program,
+            currentAuthorityKeypair,
+            originalAuthority,
+            context.registry.registryPda
+          );
         }
       } catch (error) {
         console.warn("Failed to restore original authority:", error);
@@ -763,11 +763,14 @@ describe("Integration Tests", () => {
 
       try {
         await context.program.methods
-          .registerAttester(newAttester.publicKey)
+          .registerAttester(newAttester.publicKey, null)
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
             authority: context.authority.keypair.publicKey,
+            roleAccount: null,
+            metadataAccount: null,
+            indexPage: await findAttesterIndexTailPDA(context.program),
             systemProgram: SystemProgram.programId,
           })
           .signers([context.authority.keypair])
@@ -867,6 +870,15 @@ describe("Integration Tests", () => {
           registry: context.registry.registryPda,
           attesterAccount: freshAttesterPda,
           authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(
+            freshAttester.publicKey,
+            context.program.programId
+          ),
+          ...(await findAttesterIndexPages(
+            context.program,
+            freshAttester.publicKey
+          )),
         })
         .signers([context.authority.keypair])
         .rpc();
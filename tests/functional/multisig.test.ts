@@ -0,0 +1,469 @@
+import { expect } from "chai";
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  createTestAccount,
+  findAttesterPDA,
+  findProposalPDA,
+  findRolePDA,
+  registerAttester,
+  findAttesterIndexTailPDA,
+  findAttesterIndexPages,
+  findAttesterSideAccounts,
+} from "../helpers/test-utils";
+import {
+  setupSharedTestContext,
+  SharedTestContext,
+  verifyAuthorityState,
+} from "../helpers/shared-setup";
+
+describe("Multisig Administration", () => {
+  let context: SharedTestContext;
+  let signer1: Keypair;
+  let signer2: Keypair;
+  let signer3: Keypair;
+  let outsider: Keypair;
+
+  before(async () => {
+    context = await setupSharedTestContext();
+    signer1 = (await createTestAccount(context.provider)).keypair;
+    signer2 = (await createTestAccount(context.provider)).keypair;
+    signer3 = (await createTestAccount(context.provider)).keypair;
+    outsider = (await createTestAccount(context.provider)).keypair;
+  });
+
+  async function nextProposalPda(): Promise<[PublicKey, anchor.BN]> {
+    const registry = await context.program.account.predicateRegistry.fetch(
+      context.registry.registryPda
+    );
+    const [proposalPda] = findProposalPDA(
+      registry.proposalCount,
+      context.program.programId
+    );
+    return [proposalPda, registry.proposalCount];
+  }
+
+  async function createProposal(
+    proposer: Keypair,
+    action: any
+  ): Promise<PublicKey> {
+    const [proposalPda] = await nextProposalPda();
+    await context.program.methods
+      .createProposal(action)
+      .accounts({
+        registry: context.registry.registryPda,
+        proposal: proposalPda,
+        proposer: proposer.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([proposer])
+      .rpc();
+    return proposalPda;
+  }
+
+  async function approveProposal(proposalPda: PublicKey, approver: Keypair) {
+    await context.program.methods
+      .approveProposal()
+      .accounts({
+        registry: context.registry.registryPda,
+        proposal: proposalPda,
+        approver: approver.publicKey,
+      } as any)
+      .signers([approver])
+      .rpc();
+  }
+
+  async function executeProposal(
+    proposalPda: PublicKey,
+    proposer: PublicKey,
+    extraAccounts: {
+      newAttesterAccount?: PublicKey;
+      attesterAccount?: PublicKey;
+      newRoleAccount?: PublicKey;
+      roleAccount?: PublicKey;
+      indexTailPage?: PublicKey;
+      indexPage?: PublicKey;
+      indexLastPage?: PublicKey | null;
+      metadataAccount?: PublicKey;
+      scopeAccount?: PublicKey;
+    } = {}
+  ) {
+    await context.program.methods
+      .executeProposal()
+      .accounts({
+        registry: context.registry.registryPda,
+        proposal: proposalPda,
+        proposer,
+        executor: context.authority.keypair.publicKey,
+        newAttesterAccount: extraAccounts.newAttesterAccount ?? null,
+        attesterAccount: extraAccounts.attesterAccount ?? null,
+        newRoleAccount: extraAccounts.newRoleAccount ?? null,
+        roleAccount: extraAccounts.roleAccount ?? null,
+        indexTailPage: extraAccounts.indexTailPage ?? null,
+        indexPage: extraAccounts.indexPage ?? null,
+        indexLastPage: extraAccounts.indexLastPage ?? null,
+        metadataAccount: extraAccounts.metadataAccount ?? null,
+        scopeAccount: extraAccounts.scopeAccount ?? null,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([context.authority.keypair])
+      .rpc();
+  }
+
+  async function enableMultisig() {
+    await context.program.methods
+      .configureMultisig(
+        [signer1.publicKey, signer2.publicKey, signer3.publicKey],
+        2
+      )
+      .accounts({
+        registry: context.registry.registryPda,
+        authority: context.authority.keypair.publicKey,
+      } as any)
+      .signers([context.authority.keypair])
+      .rpc();
+  }
+
+  async function disableMultisig() {
+    const proposalPda = await createProposal(signer1, {
+      configureMultisig: { signers: [], threshold: 0 },
+    });
+    await approveProposal(proposalPda, signer2);
+    await executeProposal(proposalPda, signer1.publicKey);
+  }
+
+  describe("Configuration", () => {
+    it("Should reject configuration by non-authority", async () => {
+      try {
+        await context.program.methods
+          .configureMultisig([signer1.publicKey], 1)
+          .accounts({
+            registry: context.registry.registryPda,
+            authority: outsider.publicKey,
+          } as any)
+          .signers([outsider])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should reject threshold above signer count", async () => {
+      try {
+        await context.program.methods
+          .configureMultisig([signer1.publicKey], 2)
+          .accounts({
+            registry: context.registry.registryPda,
+            authority: context.authority.keypair.publicKey,
+          } as any)
+          .signers([context.authority.keypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Invalid multisig config");
+      }
+    });
+
+    it("Should reject duplicate signers", async () => {
+      try {
+        await context.program.methods
+          .configureMultisig([signer1.publicKey, signer1.publicKey], 1)
+          .accounts({
+            registry: context.registry.registryPda,
+            authority: context.authority.keypair.publicKey,
+          } as any)
+          .signers([context.authority.keypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Invalid multisig config");
+      }
+    });
+  });
+
+  describe("Proposals", () => {
+    before(async () => {
+      await enableMultisig();
+    });
+
+    after(async () => {
+      await disableMultisig();
+    });
+
+    it("Should store signer set and threshold on the registry", async () => {
+      const registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      expect(registry.multisigThreshold).to.equal(2);
+      expect(registry.multisigSigners.map((k: PublicKey) => k.toString())).to
+        .have.members([
+          signer1.publicKey.toString(),
+          signer2.publicKey.toString(),
+          signer3.publicKey.toString(),
+        ]);
+    });
+
+    it("Should reject single-authority admin instructions", async () => {
+      const attester = Keypair.generate();
+      try {
+        await registerAttester(
+          context.program,
+          context.authority.keypair,
+          attester.publicKey,
+          context.registry.registryPda
+        );
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Multisig enabled");
+      }
+    });
+
+    it("Should register an attester once the threshold is met", async () => {
+      const attester = Keypair.generate();
+      const [attesterPda] = findAttesterPDA(
+        attester.publicKey,
+        context.program.programId
+      );
+      const registryBefore =
+        await context.program.account.predicateRegistry.fetch(
+          context.registry.registryPda
+        );
+
+      const proposalPda = await createProposal(signer1, {
+        registerAttester: { attester: attester.publicKey },
+      });
+
+      // One approval (the proposer) is below the threshold of 2
+      try {
+        await executeProposal(proposalPda, signer1.publicKey, {
+          newAttesterAccount: attesterPda,
+          indexTailPage: await findAttesterIndexTailPDA(context.program),
+        });
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Threshold not met");
+      }
+
+      await approveProposal(proposalPda, signer2);
+      await executeProposal(proposalPda, signer1.publicKey, {
+        newAttesterAccount: attesterPda,
+        indexTailPage: await findAttesterIndexTailPDA(context.program),
+      });
+
+      const attesterAccount =
+        await context.program.account.attesterAccount.fetch(attesterPda);
+      expect(attesterAccount.isRegistered).to.be.true;
+
+      const registryAfter =
+        await context.program.account.predicateRegistry.fetch(
+          context.registry.registryPda
+        );
+      expect(registryAfter.totalAttesters.toNumber()).to.equal(
+        registryBefore.totalAttesters.toNumber() + 1
+      );
+
+      // Executed proposals are closed
+      const proposalInfo = await context.provider.connection.getAccountInfo(
+        proposalPda
+      );
+      expect(proposalInfo).to.be.null;
+    });
+
+    it("Should deregister an attester through a proposal", async () => {
+      const attester = Keypair.generate();
+      const [attesterPda] = findAttesterPDA(
+        attester.publicKey,
+        context.program.programId
+      );
+
+      const registerPda = await createProposal(signer2, {
+        registerAttester: { attester: attester.publicKey },
+      });
+      await approveProposal(registerPda, signer3);
+      await executeProposal(registerPda, signer2.publicKey, {
+        newAttesterAccount: attesterPda,
+        indexTailPage: await findAttesterIndexTailPDA(context.program),
+      });
+
+      // Proposal registrations are listed in the attester index too
+      const attesterAccount =
+        await context.program.account.attesterAccount.fetch(attesterPda);
+      expect(attesterAccount.indexed).to.be.true;
+      const { indexPage, tailPage } = await findAttesterIndexPages(
+        context.program,
+        attester.publicKey
+      );
+
+      const deregisterPda = await createProposal(signer3, {
+        deregisterAttester: { attester: attester.publicKey },
+      });
+      await approveProposal(deregisterPda, signer1);
+      const sideAccounts = findAttesterSideAccounts(
+        attester.publicKey,
+        context.program.programId
+      );
+
+      // The index page is required so the index cannot fall out of step
+      try {
+        await executeProposal(deregisterPda, signer3.publicKey, {
+          attesterAccount: attesterPda,
+          ...sideAccounts,
+        });
+        expect.fail("Should have required the index page");
+      } catch (error: any) {
+        expect(error.message).to.include("Proposal account mismatch");
+      }
+
+      // So are the metadata and scope PDAs, which the deregistration closes
+      try {
+        await executeProposal(deregisterPda, signer3.publicKey, {
+          attesterAccount: attesterPda,
+          indexPage,
+          indexLastPage: tailPage,
+        });
+        expect.fail("Should have required the metadata and scope PDAs");
+      } catch (error: any) {
+        expect(error.message).to.include("Proposal account mismatch");
+      }
+
+      await executeProposal(deregisterPda, signer3.publicKey, {
+        attesterAccount: attesterPda,
+        ...sideAccounts,
+        indexPage,
+        indexLastPage: tailPage,
+      });
+
+      const attesterInfo = await context.provider.connection.getAccountInfo(
+        attesterPda
+      );
+      expect(attesterInfo).to.be.null;
+      const page = await context.program.account.attesterIndexPage.fetch(
+        indexPage
+      );
+      expect(page.attesters.some((key) => key.equals(attester.publicKey))).to.be
+        .false;
+    });
+
+    it("Should let an attester manager granted by proposal act directly", async () => {
+      const manager = (await createTestAccount(context.provider)).keypair;
+      const [rolePda] = findRolePDA(
+        "attesterManager",
+        manager.publicKey,
+        context.program.programId
+      );
+
+      const grantPda = await createProposal(signer1, {
+        grantRole: { role: { attesterManager: {} }, holder: manager.publicKey },
+      });
+      await approveProposal(grantPda, signer2);
+      await executeProposal(grantPda, signer1.publicKey, {
+        newRoleAccount: rolePda,
+      });
+
+      // The role holder bypasses the proposal flow for day-to-day operations
+      const attester = Keypair.generate();
+      const [attesterPda] = findAttesterPDA(
+        attester.publicKey,
+        context.program.programId
+      );
+      await context.program.methods
+        .registerAttester(attester.publicKey, null)
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          authority: manager.publicKey,
+          roleAccount: rolePda,
+          metadataAccount: null,
+          indexPage: await findAttesterIndexTailPDA(context.program),
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([manager])
+        .rpc();
+
+      const attesterAccount =
+        await context.program.account.attesterAccount.fetch(attesterPda);
+      expect(attesterAccount.isRegistered).to.be.true;
+
+      const revokePda = await createProposal(signer2, {
+        revokeRole: { role: { attesterManager: {} }, holder: manager.publicKey },
+      });
+      await approveProposal(revokePda, signer3);
+      await executeProposal(revokePda, signer2.publicKey, {
+        roleAccount: rolePda,
+      });
+
+      const roleInfo = await context.provider.connection.getAccountInfo(rolePda);
+      expect(roleInfo).to.be.null;
+    });
+
+    it("Should emit ProposalApproved events", async () => {
+      const attester = Keypair.generate();
+      const approvals: number[] = [];
+
+      const listener = context.program.addEventListener(
+        "proposalApproved",
+        (event: any) => {
+          expect(event.threshold).to.equal(2);
+          approvals.push(event.approvals);
+        }
+      );
+
+      try {
+        const proposalPda = await createProposal(signer1, {
+          registerAttester: { attester: attester.publicKey },
+        });
+        await approveProposal(proposalPda, signer3);
+
+        await new Promise((resolve) => setTimeout(resolve, 100));
+        expect(approvals).to.deep.equal([1, 2]);
+      } finally {
+        await context.program.removeEventListener(listener);
+      }
+    });
+
+    it("Should reject proposals from non-signers", async () => {
+      try {
+        await createProposal(outsider, {
+          registerAttester: { attester: Keypair.generate().publicKey },
+        });
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Not a multisig signer");
+      }
+    });
+
+    it("Should reject duplicate approvals", async () => {
+      const proposalPda = await createProposal(signer1, {
+        registerAttester: { attester: Keypair.generate().publicKey },
+      });
+
+      try {
+        await approveProposal(proposalPda, signer1);
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Already approved");
+      }
+    });
+
+    it("Should reject approvals from non-signers", async () => {
+      const proposalPda = await createProposal(signer1, {
+        registerAttester: { attester: Keypair.generate().publicKey },
+      });
+
+      try {
+        await approveProposal(proposalPda, outsider);
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Not a multisig signer");
+      }
+    });
+  });
+
+  after(async () => {
+    await verifyAuthorityState(context, {
+      when: "after",
+      suiteName: "multisig test suite",
+    });
+  });
+});
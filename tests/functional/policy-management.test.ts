@@ -8,6 +8,7 @@ import {
   setPolicyId,
   setPolicyIdOrUpdate,
   updatePolicyId,
+  setPolicyPaused,
   createFundedKeypair,
 } from "../helpers/test-utils";
 import {
@@ -514,4 +515,117 @@ describe("Policy Management", () => {
       expect(policyAccount.policyId).to.equal(testPolicyId);
     });
   });
+
+  describe("Policy Pause", () => {
+    let policyPda: PublicKey;
+
+    before(async () => {
+      [policyPda] = findPolicyPDA(counterProgramId, context.program.programId);
+      await setPolicyIdOrUpdate(
+        context.program,
+        counterProgramId,
+        context.authority.keypair,
+        mediumPolicyId,
+        context.registry.registryPda
+      );
+    });
+
+    afterEach(async () => {
+      // Never leave the Counter policy paused for later tests
+      const policyAccount = await context.program.account.policyAccount.fetch(
+        policyPda
+      );
+      if (policyAccount.paused) {
+        await setPolicyPaused(
+          context.program,
+          counterProgramId,
+          context.authority.keypair,
+          false,
+          context.registry.registryPda
+        );
+      }
+    });
+
+    it("Should pause and unpause a policy as the upgrade authority", async () => {
+      await setPolicyPaused(
+        context.program,
+        counterProgramId,
+        context.authority.keypair,
+        true,
+        context.registry.registryPda
+      );
+      let policyAccount = await context.program.account.policyAccount.fetch(
+        policyPda
+      );
+      expect(policyAccount.paused).to.be.true;
+      expect(policyAccount.policyId).to.equal(mediumPolicyId);
+
+      await setPolicyPaused(
+        context.program,
+        counterProgramId,
+        context.authority.keypair,
+        false,
+        context.registry.registryPda
+      );
+      policyAccount = await context.program.account.policyAccount.fetch(
+        policyPda
+      );
+      expect(policyAccount.paused).to.be.false;
+    });
+
+    it("Should reject pausing an already paused policy", async () => {
+      await setPolicyPaused(
+        context.program,
+        counterProgramId,
+        context.authority.keypair,
+        true,
+        context.registry.registryPda
+      );
+
+      try {
+        await setPolicyPaused(
+          context.program,
+          counterProgramId,
+          context.authority.keypair,
+          true,
+          context.registry.registryPda
+        );
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Policy already paused");
+      }
+    });
+
+    it("Should reject unpausing a policy that is not paused", async () => {
+      try {
+        await setPolicyPaused(
+          context.program,
+          counterProgramId,
+          context.authority.keypair,
+          false,
+          context.registry.registryPda
+        );
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Policy not paused");
+      }
+    });
+
+    it("Should reject pause from non-upgrade-authority", async () => {
+      const unauthorizedUser = await createFundedKeypair(context.provider);
+
+      try {
+        await setPolicyPaused(
+          context.program,
+          counterProgramId,
+          unauthorizedUser,
+          true,
+          context.registry.registryPda
+        );
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+  });
 });
@@ -0,0 +1,335 @@
+import { expect } from "chai";
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  createTestAccount,
+  findAttesterPDA,
+  findOperationPDA,
+  findRolePDA,
+  grantRole,
+  registerAttester,
+  revokeRole,
+  sleep,
+  findAttesterIndexTailPDA,
+} from "../helpers/test-utils";
+import {
+  setupSharedTestContext,
+  SharedTestContext,
+  verifyAuthorityState,
+} from "../helpers/shared-setup";
+
+describe("Timelocked Admin Operations", () => {
+  let context: SharedTestContext;
+  let executor: Keypair;
+  let manager: Keypair;
+
+  // Short delay so tests can wait it out
+  const DELAY_SECONDS = 2;
+
+  before(async () => {
+    context = await setupSharedTestContext();
+    executor = (await createTestAccount(context.provider)).keypair;
+    manager = (await createTestAccount(context.provider)).keypair;
+  });
+
+  async function queueOperation(action: any): Promise<PublicKey> {
+    const registry = await context.program.account.predicateRegistry.fetch(
+      context.registry.registryPda
+    );
+    const [operationPda] = findOperationPDA(
+      registry.operationCount,
+      context.program.programId
+    );
+    await context.program.methods
+      .queueOperation(action)
+      .accounts({
+        registry: context.registry.registryPda,
+        operation: operationPda,
+        authority: context.authority.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([context.authority.keypair])
+      .rpc();
+    return operationPda;
+  }
+
+  async function executeOperation(
+    operationPda: PublicKey,
+    extraAccounts: {
+      newAttesterAccount?: PublicKey;
+      attesterAccount?: PublicKey;
+      newRoleAccount?: PublicKey;
+      roleAccount?: PublicKey;
+      indexTailPage?: PublicKey;
+      indexPage?: PublicKey;
+      indexLastPage?: PublicKey | null;
+      metadataAccount?: PublicKey;
+      scopeAccount?: PublicKey;
+    } = {}
+  ) {
+    await context.program.methods
+      .executeOperation()
+      .accounts({
+        registry: context.registry.registryPda,
+        operation: operationPda,
+        queuedBy: context.authority.keypair.publicKey,
+        executor: executor.publicKey,
+        newAttesterAccount: extraAccounts.newAttesterAccount ?? null,
+        attesterAccount: extraAccounts.attesterAccount ?? null,
+        newRoleAccount: extraAccounts.newRoleAccount ?? null,
+        roleAccount: extraAccounts.roleAccount ?? null,
+        indexTailPage: extraAccounts.indexTailPage ?? null,
+        indexPage: extraAccounts.indexPage ?? null,
+        indexLastPage: extraAccounts.indexLastPage ?? null,
+        metadataAccount: extraAccounts.metadataAccount ?? null,
+        scopeAccount: extraAccounts.scopeAccount ?? null,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([executor])
+      .rpc();
+  }
+
+  async function cancelOperation(operationPda: PublicKey, authority: Keypair) {
+    await context.program.methods
+      .cancelOperation()
+      .accounts({
+        registry: context.registry.registryPda,
+        operation: operationPda,
+        queuedBy: context.authority.keypair.publicKey,
+        authority: authority.publicKey,
+      } as any)
+      .signers([authority])
+      .rpc();
+  }
+
+  before(async () => {
+    // Granted before the timelock is enabled, to check roles don't bypass it
+    await grantRole(
+      context.program,
+      context.authority.keypair,
+      "attesterManager",
+      manager.publicKey,
+      context.registry.registryPda
+    );
+
+    await context.program.methods
+      .setTimelockDelay(new anchor.BN(DELAY_SECONDS))
+      .accounts({
+        registry: context.registry.registryPda,
+        authority: context.authority.keypair.publicKey,
+      } as any)
+      .signers([context.authority.keypair])
+      .rpc();
+  });
+
+  after(async () => {
+    // Disabling the timelock is itself timelocked
+    const operationPda = await queueOperation({
+      setTimelockDelay: { delay: new anchor.BN(0) },
+    });
+    await sleep((DELAY_SECONDS + 1) * 1000);
+    await executeOperation(operationPda);
+
+    const registry = await context.program.account.predicateRegistry.fetch(
+      context.registry.registryPda
+    );
+    expect(registry.timelockDelay.toNumber()).to.equal(0);
+
+    await revokeRole(
+      context.program,
+      context.authority.keypair,
+      "attesterManager",
+      manager.publicKey,
+      context.registry.registryPda
+    );
+
+    await verifyAuthorityState(context, {
+      when: "after",
+      suiteName: "timelock test suite",
+    });
+  });
+
+  it("Should store the delay on the registry", async () => {
+    const registry = await context.program.account.predicateRegistry.fetch(
+      context.registry.registryPda
+    );
+    expect(registry.timelockDelay.toNumber()).to.equal(DELAY_SECONDS);
+  });
+
+  it("Should reject direct admin instructions while enabled", async () => {
+    try {
+      await registerAttester(
+        context.program,
+        context.authority.keypair,
+        Keypair.generate().publicKey,
+        context.registry.registryPda
+      );
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("Timelock enabled");
+    }
+  });
+
+  it("Should reject role holders acting directly while enabled", async () => {
+    const attester = Keypair.generate().publicKey;
+    const [attesterPda] = findAttesterPDA(attester, context.program.programId);
+    const [rolePda] = findRolePDA(
+      "attesterManager",
+      manager.publicKey,
+      context.program.programId
+    );
+
+    try {
+      await context.program.methods
+        .registerAttester(attester, null)
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          authority: manager.publicKey,
+          roleAccount: rolePda,
+          metadataAccount: null,
+          indexPage: await findAttesterIndexTailPDA(context.program),
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([manager])
+        .rpc();
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("Timelock enabled");
+    }
+  });
+
+  it("Should execute a queued registration only after the eta", async () => {
+    const attester = Keypair.generate();
+    const [attesterPda] = findAttesterPDA(
+      attester.publicKey,
+      context.program.programId
+    );
+
+    const operationPda = await queueOperation({
+      registerAttester: { attester: attester.publicKey },
+    });
+
+    const operation = await context.program.account.queuedOperation.fetch(
+      operationPda
+    );
+    expect(operation.eta.toNumber() - operation.queuedAt.toNumber()).to.equal(
+      DELAY_SECONDS
+    );
+
+    try {
+      await executeOperation(operationPda, {
+        newAttesterAccount: attesterPda,
+        indexTailPage: await findAttesterIndexTailPDA(context.program),
+      });
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("Timelock not expired");
+    }
+
+    await sleep((DELAY_SECONDS + 1) * 1000);
+    await executeOperation(operationPda, {
+      newAttesterAccount: attesterPda,
+      indexTailPage: await findAttesterIndexTailPDA(context.program),
+    });
+
+    const attesterAccount = await context.program.account.attesterAccount.fetch(
+      attesterPda
+    );
+    expect(attesterAccount.isRegistered).to.be.true;
+
+    const operationInfo = await context.provider.connection.getAccountInfo(
+      operationPda
+    );
+    expect(operationInfo).to.be.null;
+  });
+
+  it("Should emit OperationQueued with the eta", async () => {
+    let eventReceived = false;
+    const listener = context.program.addEventListener(
+      "operationQueued",
+      (event: any) => {
+        expect(event.eta.toNumber() - event.timestamp.toNumber()).to.equal(
+          DELAY_SECONDS
+        );
+        eventReceived = true;
+      }
+    );
+
+    try {
+      const operationPda = await queueOperation({
+        registerAttester: { attester: Keypair.generate().publicKey },
+      });
+      await new Promise((resolve) => setTimeout(resolve, 100));
+      expect(eventReceived).to.be.true;
+
+      await cancelOperation(operationPda, context.authority.keypair);
+    } finally {
+      await context.program.removeEventListener(listener);
+    }
+  });
+
+  it("Should allow the authority to cancel a queued operation", async () => {
+    const attester = Keypair.generate();
+    const [attesterPda] = findAttesterPDA(
+      attester.publicKey,
+      context.program.programId
+    );
+    const operationPda = await queueOperation({
+      registerAttester: { attester: attester.publicKey },
+    });
+
+    await cancelOperation(operationPda, context.authority.keypair);
+
+    await sleep((DELAY_SECONDS + 1) * 1000);
+    try {
+      await executeOperation(operationPda, {
+        newAttesterAccount: attesterPda,
+        indexTailPage: await findAttesterIndexTailPDA(context.program),
+      });
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("AccountNotInitialized");
+    }
+  });
+
+  it("Should reject cancellation by non-authority", async () => {
+    const operationPda = await queueOperation({
+      registerAttester: { attester: Keypair.generate().publicKey },
+    });
+
+    try {
+      await cancelOperation(operationPda, executor);
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("Unauthorized");
+    } finally {
+      await cancelOperation(operationPda, context.authority.keypair);
+    }
+  });
+
+  it("Should reject attester accounts that don't match the action", async () => {
+    const attester = Keypair.generate();
+    const [attesterPda] = findAttesterPDA(
+      attester.publicKey,
+      context.program.programId
+    );
+    const operationPda = await queueOperation({
+      registerAttester: { attester: attester.publicKey },
+    });
+    await sleep((DELAY_SECONDS + 1) * 1000);
+
+    try {
+      // Missing the attester account required by RegisterAttester
+      await executeOperation(operationPda);
+      expect.fail("Should have thrown an error");
+    } catch (error: any) {
+      expect(error.message).to.include("Proposal account mismatch");
+    } finally {
+      await executeOperation(operationPda, {
+        newAttesterAccount: attesterPda,
+        indexTailPage: await findAttesterIndexTailPDA(context.program),
+      });
+    }
+  });
+});
@@ -16,16 +16,36 @@ import {
 } from "../helpers/shared-setup";
 import {
   createTestAccount,
+  createFundedKeypair,
   findAttesterPDA,
+  findAttesterScopePDA,
+  findAttesterBondPDA,
   findPolicyPDA,
   registerAttesterIfNotExists,
   setPolicyIdOrUpdate,
   getFutureTimestamp,
   getPastTimestamp,
+  getCurrentTimestamp,
   expectError,
   findUsedUuidPDA,
   createMessageHash,
   createAttestationSignature,
+  createMultiEd25519Instruction,
+  setAttesterQuorum,
+  createSecp256k1Attestation,
+  ethAddressFromPrivateKey,
+  secp256k1AttesterKey,
+  generateP256Keypair,
+  secp256r1AttesterKey,
+  createSecp256r1Signature,
+  createSecp256r1Instruction,
+  createEvmMessageHash,
+  createIssuedAtMessageHash,
+  setDigestScheme,
+  findAttesterIndexTailPDA,
+  rotateAttesterKey,
+  findAttesterIndexPages,
+  findAttesterSideAccounts,
 } from "../helpers/test-utils";
 
 describe("Validate Attestation", () => {
@@ -101,6 +121,7 @@ describe("Validate Attestation", () => {
       encodedSigAndArgs: Buffer.from("test-encoded-data"),
       policyId: testPolicy,
       expiration: new anchor.BN(expiration),
+      issuedAt: new anchor.BN(Math.min(expiration, getCurrentTimestamp())),
     };
   }
 
@@ -111,16 +132,85 @@ describe("Validate Attestation", () => {
     uuid: Uint8Array,
     attesterKeypair: Keypair,
     expiration: number,
-    signature: Uint8Array
+    signature: Uint8Array,
+    issuedAt: anchor.BN
   ) {
     return {
       uuid: Array.from(uuid),
       attester: attesterKeypair.publicKey,
       signature: Array.from(signature),
       expiration: new anchor.BN(expiration),
+      issuedAt,
     };
   }
 
+  /**
+   * Helper function to sign, verify and validate a fresh attestation
+   */
+  async function sendValidation(
+    expiration: number,
+    attesterScopeAccount: PublicKey | null = null,
+    uuid: Buffer = crypto.randomBytes(16),
+    issuedAt: number | null = null,
+    attesterKeypair: Keypair = attester,
+    hashStatement: (statement: any) => Buffer = createMessageHash
+  ) {
+    const statement = createStatement(uuid, expiration);
+    if (issuedAt !== null) {
+      statement.issuedAt = new anchor.BN(issuedAt);
+    }
+    const signature = createAttestationSignature(
+      statement,
+      attesterKeypair,
+      hashStatement
+    );
+    const attestation = createAttestation(
+      uuid,
+      attesterKeypair,
+      expiration,
+      signature,
+      statement.issuedAt
+    );
+
+    const ed25519Instruction = Ed25519Program.createInstructionWithPublicKey({
+      publicKey: attesterKeypair.publicKey.toBytes(),
+      message: hashStatement(statement),
+      signature: signature,
+    });
+
+    const [usedUuidPda] = findUsedUuidPDA(
+      Array.from(uuid),
+      context.program.programId
+    );
+
+    const validateInstruction = await context.program.methods
+      .validateAttestation(
+        statement.target,
+        statement.msgValue,
+        statement.encodedSigAndArgs,
+        attestation
+      )
+      .accounts({
+        registry: context.registry.registryPda,
+        attesterAccount: findAttesterPDA(
+          attesterKeypair.publicKey,
+          context.program.programId
+        )[0],
+        attesterScopeAccount,
+        policyAccount: policyPda,
+        usedUuidAccount: usedUuidPda,
+        signer: client.publicKey,
+        systemProgram: SystemProgram.programId,
+        instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      } as any)
+      .instruction();
+
+    const transaction = new Transaction();
+    transaction.add(ed25519Instruction);
+    transaction.add(validateInstruction);
+    return context.provider.sendAndConfirm(transaction, [client]);
+  }
+
   describe("Successful Validation", () => {
     it("should validate a correct attestation", async () => {
       const uuid = crypto.randomBytes(16);
@@ -133,7 +223,8 @@ describe("Validate Attestation", () => {
         uuid,
         attester,
         expiration,
-        signature
+        signature,
+        statement.issuedAt
       );
 
       // Create message hash for Ed25519 verification instruction
@@ -162,6 +253,7 @@ describe("Validate Attestation", () => {
         .accounts({
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
+          attesterScopeAccount: null,
           policyAccount: policyPda,
           usedUuidAccount: usedUuidPda,
           signer: client.publicKey,
@@ -195,7 +287,8 @@ describe("Validate Attestation", () => {
         uuid,
         attester,
         expiration,
-        signature
+        signature,
+        statement.issuedAt
       );
 
       const [usedUuidPda] = findUsedUuidPDA(
@@ -214,6 +307,7 @@ describe("Validate Attestation", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -240,7 +334,8 @@ describe("Validate Attestation", () => {
         attestationUuid,
         attester,
         expiration,
-        signature
+        signature,
+        statement.issuedAt
       );
 
       const [usedUuidPda] = findUsedUuidPDA(
@@ -259,6 +354,7 @@ describe("Validate Attestation", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -285,7 +381,8 @@ describe("Validate Attestation", () => {
         uuid,
         attester,
         expiration,
-        invalidSignature
+        invalidSignature,
+        statement.issuedAt
       );
 
       const [usedUuidPda] = findUsedUuidPDA(
@@ -304,6 +401,7 @@ describe("Validate Attestation", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -337,7 +435,8 @@ describe("Validate Attestation", () => {
         uuid,
         attester,
         expiration,
-        signature
+        signature,
+        statement.issuedAt
       );
 
       const [usedUuidPda] = findUsedUuidPDA(
@@ -356,6 +455,7 @@ describe("Validate Attestation", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -395,7 +495,8 @@ describe("Validate Attestation", () => {
         uuid,
         unregisteredAttester,
         expiration,
-        signature
+        signature,
+        statement.issuedAt
       );
 
       const [unregisteredAttesterPda] = findAttesterPDA(
@@ -419,6 +520,7 @@ describe("Validate Attestation", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: unregisteredAttesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -446,7 +548,8 @@ describe("Validate Attestation", () => {
         uuid,
         attester,
         attestationExpiration,
-        signature
+        signature,
+        statement.issuedAt
       );
 
       const [usedUuidPda] = findUsedUuidPDA(
@@ -465,6 +568,7 @@ describe("Validate Attestation", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -480,4 +584,1522 @@ describe("Validate Attestation", () => {
       }
     });
   });
+
+  describe("Attestation Config", () => {
+    const DEFAULT_DRIFT_BUFFER = 30;
+    const DEFAULT_MAX_LIFETIME = 7 * 24 * 60 * 60;
+
+    async function setAttestationConfig(driftBuffer: number, maxLifetime: number) {
+      await context.program.methods
+        .setAttestationConfig(new anchor.BN(driftBuffer), new anchor.BN(maxLifetime))
+        .accounts({
+          registry: context.registry.registryPda,
+          authority: context.authority.keypair.publicKey,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    after(async () => {
+      await setAttestationConfig(DEFAULT_DRIFT_BUFFER, DEFAULT_MAX_LIFETIME);
+    });
+
+    it("should start with the default drift buffer and maximum lifetime", async () => {
+      const registry = await context.program.account.predicateRegistry.fetch(
+        context.registry.registryPda
+      );
+      expect(registry.clockDriftBuffer.toNumber()).to.equal(DEFAULT_DRIFT_BUFFER);
+      expect(registry.maxAttestationLifetime.toNumber()).to.equal(DEFAULT_MAX_LIFETIME);
+    });
+
+    it("should reject an expiration beyond the maximum lifetime", async () => {
+      try {
+        await sendValidation(getFutureTimestamp(DEFAULT_MAX_LIFETIME + 3600));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttestationLifetimeExceeded");
+      }
+    });
+
+    it("should apply an updated maximum lifetime", async () => {
+      await setAttestationConfig(DEFAULT_DRIFT_BUFFER, 600);
+
+      try {
+        await sendValidation(getFutureTimestamp(3600));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttestationLifetimeExceeded");
+      }
+
+      const result = await sendValidation(getFutureTimestamp(300));
+      expect(result).to.be.a("string");
+    });
+
+    it("should accept a recently expired attestation within the drift buffer", async () => {
+      try {
+        await sendValidation(getPastTimestamp(300));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "StatementExpired");
+      }
+
+      await setAttestationConfig(600, DEFAULT_MAX_LIFETIME);
+
+      const result = await sendValidation(getPastTimestamp(300));
+      expect(result).to.be.a("string");
+    });
+
+    it("should reject an out-of-range config", async () => {
+      for (const [driftBuffer, maxLifetime] of [
+        [-1, DEFAULT_MAX_LIFETIME],
+        [601, DEFAULT_MAX_LIFETIME],
+        [DEFAULT_DRIFT_BUFFER, 0],
+        [DEFAULT_DRIFT_BUFFER, 365 * 24 * 60 * 60 + 1],
+      ]) {
+        try {
+          await setAttestationConfig(driftBuffer, maxLifetime);
+          expect.fail("Expected transaction to fail");
+        } catch (error) {
+          expectError(error, "InvalidAttestationConfig");
+        }
+      }
+    });
+
+    it("should reject a config change from a non-authority", async () => {
+      const attacker = (await createTestAccount(context.provider)).keypair;
+      try {
+        await context.program.methods
+          .setAttestationConfig(new anchor.BN(0), new anchor.BN(60))
+          .accounts({
+            registry: context.registry.registryPda,
+            authority: attacker.publicKey,
+          } as any)
+          .signers([attacker])
+          .rpc();
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "Unauthorized");
+      }
+    });
+  });
+
+  describe("Attester Validity Window", () => {
+    async function setAttesterValidity(validFrom: number, validUntil: number) {
+      await context.program.methods
+        .setAttesterValidity(
+          attester.publicKey,
+          new anchor.BN(validFrom),
+          new anchor.BN(validUntil)
+        )
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    after(async () => {
+      await setAttesterValidity(0, 0);
+    });
+
+    it("should reject attestations before the window starts", async () => {
+      await setAttesterValidity(getFutureTimestamp(3600), 0);
+
+      try {
+        await sendValidation(getFutureTimestamp(600));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttesterNotYetValid");
+      }
+    });
+
+    it("should reject attestations after the window ends", async () => {
+      await setAttesterValidity(0, getPastTimestamp(60));
+
+      try {
+        await sendValidation(getFutureTimestamp(600));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttesterValidityEnded");
+      }
+    });
+
+    it("should accept attestations inside the window", async () => {
+      await setAttesterValidity(getPastTimestamp(60), getFutureTimestamp(3600));
+
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterPda
+      );
+      expect(account.validFrom.toNumber()).to.be.greaterThan(0);
+      expect(account.validUntil.toNumber()).to.be.greaterThan(
+        account.validFrom.toNumber()
+      );
+
+      const result = await sendValidation(getFutureTimestamp(600));
+      expect(result).to.be.a("string");
+    });
+
+    it("should reject an inverted window", async () => {
+      try {
+        await setAttesterValidity(getFutureTimestamp(3600), getPastTimestamp(60));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidAttesterValidity");
+      }
+    });
+  });
+
+  describe("Attester Suspension", () => {
+    async function setSuspended(suspended: boolean) {
+      const method = suspended
+        ? context.program.methods.suspendAttester(attester.publicKey)
+        : context.program.methods.reinstateAttester(attester.publicKey);
+      await method
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    it("should reject attestations from a suspended attester", async () => {
+      await setSuspended(true);
+
+      try {
+        await sendValidation(getFutureTimestamp(600));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttesterSuspended");
+      } finally {
+        await setSuspended(false);
+      }
+    });
+
+    it("should accept attestations again once reinstated", async () => {
+      const result = await sendValidation(getFutureTimestamp(600));
+      expect(result).to.be.a("string");
+    });
+  });
+
+  describe("Compromise Revocation", () => {
+    let compromised: Keypair;
+    let cutoff: number;
+
+    before(async () => {
+      compromised = Keypair.generate();
+      await registerAttesterIfNotExists(
+        context.program,
+        context.authority.keypair,
+        compromised.publicKey,
+        context.registry.registryPda
+      );
+      cutoff = getPastTimestamp(3600);
+      await setScheme("sha256WithIssuedAt");
+    });
+
+    after(async () => {
+      await setScheme("sha256");
+    });
+
+    async function setScheme(digestScheme: "sha256" | "sha256WithIssuedAt") {
+      await setDigestScheme(
+        context.program,
+        targetProgramId,
+        context.authority.keypair,
+        digestScheme,
+        context.registry.registryPda
+      );
+    }
+
+    async function revokeAfter(timestamp: number) {
+      await context.program.methods
+        .revokeAttesterAfter(compromised.publicKey, new anchor.BN(timestamp))
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: findAttesterPDA(
+            compromised.publicKey,
+            context.program.programId
+          )[0],
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    it("should reject an issue time in the future", async () => {
+      try {
+        await sendValidation(
+          getFutureTimestamp(7200),
+          null,
+          crypto.randomBytes(16),
+          getFutureTimestamp(3600),
+          compromised,
+          createIssuedAtMessageHash
+        );
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidIssuedAt");
+      }
+    });
+
+    it("should reject attestations issued after the cutoff", async () => {
+      await revokeAfter(cutoff);
+
+      const account = await context.program.account.attesterAccount.fetch(
+        findAttesterPDA(compromised.publicKey, context.program.programId)[0]
+      );
+      expect(account.revokedAfter.toNumber()).to.equal(cutoff);
+
+      try {
+        await sendValidation(
+          getFutureTimestamp(600),
+          null,
+          crypto.randomBytes(16),
+          null,
+          compromised,
+          createIssuedAtMessageHash
+        );
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttestationRevoked");
+      }
+    });
+
+    it("should keep accepting attestations issued before the cutoff", async () => {
+      const result = await sendValidation(
+        getFutureTimestamp(600),
+        null,
+        crypto.randomBytes(16),
+        cutoff - 60,
+        compromised,
+        createIssuedAtMessageHash
+      );
+      expect(result).to.be.a("string");
+    });
+
+    it("should refuse a revoked attester under a digest without the issue time", async () => {
+      await setScheme("sha256");
+
+      // Unsigned, the issue time could be rewritten to before the cutoff
+      try {
+        await sendValidation(
+          getFutureTimestamp(600),
+          null,
+          crypto.randomBytes(16),
+          cutoff - 60,
+          compromised
+        );
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "IssuedAtNotSigned");
+      } finally {
+        await setScheme("sha256WithIssuedAt");
+      }
+    });
+
+    it("should not let the cutoff be lifted", async () => {
+      try {
+        await revokeAfter(cutoff + 60);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidRevocationCutoff");
+      }
+    });
+  });
+
+  describe("Attester Scope", () => {
+    let scopePda: PublicKey;
+
+    before(() => {
+      [scopePda] = findAttesterScopePDA(
+        attester.publicKey,
+        context.program.programId
+      );
+    });
+
+    async function setAttesterScope(
+      programs: PublicKey[],
+      policyPrefixes: string[],
+      key: PublicKey = attester.publicKey
+    ) {
+      await context.program.methods
+        .setAttesterScope(key, { programs, policyPrefixes })
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: findAttesterPDA(key, context.program.programId)[0],
+          scopeAccount: findAttesterScopePDA(key, context.program.programId)[0],
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    after(async () => {
+      await setAttesterScope([], []);
+    });
+
+    it("should accept attestations from an unscoped attester without a scope account", async () => {
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterPda
+      );
+      expect(account.scoped).to.be.false;
+
+      const result = await sendValidation(getFutureTimestamp(600));
+      expect(result).to.be.a("string");
+    });
+
+    async function registerWithBond(bonded: Keypair) {
+      const setBond = (bond: number) =>
+        context.program.methods
+          .setBondConfig(new anchor.BN(bond), new anchor.BN(0), new anchor.BN(0))
+          .accounts({
+            registry: context.registry.registryPda,
+            authority: context.authority.keypair.publicKey,
+            roleAccount: null,
+          } as any)
+          .signers([context.authority.keypair])
+          .rpc();
+
+      await setBond(anchor.web3.LAMPORTS_PER_SOL / 100);
+      try {
+        await context.program.methods
+          .registerWithBond()
+          .accounts({
+            registry: context.registry.registryPda,
+            attesterAccount: findAttesterPDA(
+              bonded.publicKey,
+              context.program.programId
+            )[0],
+            bondAccount: findAttesterBondPDA(
+              bonded.publicKey,
+              context.program.programId
+            )[0],
+            scopeAccount: findAttesterScopePDA(
+              bonded.publicKey,
+              context.program.programId
+            )[0],
+            attester: bonded.publicKey,
+            indexPage: await findAttesterIndexTailPDA(context.program),
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .signers([bonded])
+          .rpc();
+      } finally {
+        await setBond(0);
+      }
+    }
+
+    async function deregister(key: PublicKey, scopeAccount: PublicKey) {
+      await context.program.methods
+        .deregisterAttester(key)
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: findAttesterPDA(key, context.program.programId)[0],
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          ...findAttesterSideAccounts(key, context.program.programId),
+          scopeAccount,
+          ...(await findAttesterIndexPages(context.program, key)),
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    it("should reject a self-registered attester until it is scoped", async () => {
+      const bonded = await createFundedKeypair(context.provider);
+      await registerWithBond(bonded);
+
+      const account = await context.program.account.attesterAccount.fetch(
+        findAttesterPDA(bonded.publicKey, context.program.programId)[0]
+      );
+      expect(account.scoped).to.be.true;
+
+      try {
+        await sendValidation(
+          getFutureTimestamp(600),
+          null,
+          crypto.randomBytes(16),
+          null,
+          bonded
+        );
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttesterScopeRequired");
+      }
+
+      await setAttesterScope([targetProgramId], [], bonded.publicKey);
+      const result = await sendValidation(
+        getFutureTimestamp(600),
+        findAttesterScopePDA(bonded.publicKey, context.program.programId)[0],
+        crypto.randomBytes(16),
+        null,
+        bonded
+      );
+      expect(result).to.be.a("string");
+    });
+
+    it("should not let a re-registered attester inherit its old scope", async () => {
+      const reused = await createFundedKeypair(context.provider);
+      const [reusedScopePda] = findAttesterScopePDA(
+        reused.publicKey,
+        context.program.programId
+      );
+      await registerAttesterIfNotExists(
+        context.program,
+        context.authority.keypair,
+        reused.publicKey,
+        context.registry.registryPda
+      );
+      await setAttesterScope([targetProgramId], [], reused.publicKey);
+
+      // The scope PDA cannot be left out, so it is always closed
+      try {
+        await deregister(reused.publicKey, Keypair.generate().publicKey);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "ConstraintSeeds");
+      }
+      await deregister(reused.publicKey, reusedScopePda);
+      const info = await context.provider.connection.getAccountInfo(
+        reusedScopePda
+      );
+      expect(info).to.be.null;
+
+      // Self-registering the same key starts denied, not with the old scope
+      await registerWithBond(reused);
+      const account = await context.program.account.attesterAccount.fetch(
+        findAttesterPDA(reused.publicKey, context.program.programId)[0]
+      );
+      expect(account.scoped).to.be.true;
+
+      try {
+        await sendValidation(
+          getFutureTimestamp(600),
+          null,
+          crypto.randomBytes(16),
+          null,
+          reused
+        );
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttesterScopeRequired");
+      }
+    });
+
+    it("should accept attestations for a program in scope", async () => {
+      await setAttesterScope([targetProgramId], []);
+
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterPda
+      );
+      expect(account.scoped).to.be.true;
+
+      const result = await sendValidation(getFutureTimestamp(600), scopePda);
+      expect(result).to.be.a("string");
+    });
+
+    it("should accept attestations for a policy prefix in scope", async () => {
+      await setAttesterScope([], ["test-policy"]);
+
+      const result = await sendValidation(getFutureTimestamp(600), scopePda);
+      expect(result).to.be.a("string");
+    });
+
+    it("should reject attestations outside the scope", async () => {
+      await setAttesterScope([Keypair.generate().publicKey], ["other-"]);
+
+      try {
+        await sendValidation(getFutureTimestamp(600), scopePda);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttesterOutOfScope");
+      }
+    });
+
+    it("should require the scope account for a scoped attester", async () => {
+      await setAttesterScope([targetProgramId], []);
+
+      try {
+        await sendValidation(getFutureTimestamp(600));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttesterScopeRequired");
+      }
+    });
+
+    it("should lift the restriction with an empty scope", async () => {
+      await setAttesterScope([], []);
+
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterPda
+      );
+      expect(account.scoped).to.be.false;
+
+      const result = await sendValidation(getFutureTimestamp(600));
+      expect(result).to.be.a("string");
+    });
+
+    it("should reject an empty policy prefix", async () => {
+      try {
+        await setAttesterScope([], [""]);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidAttesterScope");
+      }
+    });
+  });
+
+  describe("Threshold Attestation", () => {
+    let coAttester: Keypair;
+
+    before(async () => {
+      coAttester = Keypair.generate();
+      await registerAttesterIfNotExists(
+        context.program,
+        context.authority.keypair,
+        coAttester.publicKey,
+        context.registry.registryPda
+      );
+      await setAttesterQuorum(
+        context.program,
+        targetProgramId,
+        context.authority.keypair,
+        2,
+        context.registry.registryPda
+      );
+    });
+
+    after(async () => {
+      await setAttesterQuorum(
+        context.program,
+        targetProgramId,
+        context.authority.keypair,
+        0,
+        context.registry.registryPda
+      );
+    });
+
+    /**
+     * Helper function to sign a fresh statement with each attester and
+     * validate it in one threshold validation
+     */
+    async function sendThresholdValidation(
+      signers: Keypair[],
+      ed25519Signers: Keypair[] = signers,
+      uuid: Buffer = crypto.randomBytes(16)
+    ) {
+      const expiration = getFutureTimestamp(600);
+      const statement = createStatement(uuid, expiration);
+      const message = createMessageHash(statement);
+      const signatureOf = (signer: Keypair) => ({
+        publicKey: signer.publicKey,
+        signature: createAttestationSignature(statement, signer),
+      });
+
+      const ed25519Instruction = createMultiEd25519Instruction(
+        ed25519Signers.map(signatureOf),
+        message
+      );
+
+      const [usedUuidPda] = findUsedUuidPDA(
+        Array.from(uuid),
+        context.program.programId
+      );
+
+      const validateInstruction = await context.program.methods
+        .validateThresholdAttestation(
+          statement.target,
+          statement.msgValue,
+          statement.encodedSigAndArgs,
+          {
+            uuid: Array.from(uuid),
+            expiration: statement.expiration,
+            issuedAt: statement.issuedAt,
+            signatures: signers.map((signer) => {
+              const { signature } = signatureOf(signer);
+              return {
+                attester: signer.publicKey,
+                signature: Array.from(signature),
+              };
+            }),
+          }
+        )
+        .accounts({
+          registry: context.registry.registryPda,
+          policyAccount: policyPda,
+          usedUuidAccount: usedUuidPda,
+          signer: client.publicKey,
+          systemProgram: SystemProgram.programId,
+          instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        } as any)
+        .remainingAccounts(
+          signers.map((signer) => ({
+            pubkey: findAttesterPDA(signer.publicKey, context.program.programId)[0],
+            isSigner: false,
+            isWritable: false,
+          }))
+        )
+        .instruction();
+
+      const transaction = new Transaction();
+      transaction.add(ed25519Instruction);
+      transaction.add(validateInstruction);
+      return context.provider.sendAndConfirm(transaction, [client]);
+    }
+
+    it("should store the quorum on the policy", async () => {
+      const policy = await context.program.account.policyAccount.fetch(
+        policyPda
+      );
+      expect(policy.attesterQuorum).to.equal(2);
+    });
+
+    it("should validate a statement signed by a quorum of attesters", async () => {
+      const uuid = crypto.randomBytes(16);
+      const result = await sendThresholdValidation(
+        [attester, coAttester],
+        [attester, coAttester],
+        uuid
+      );
+      expect(result).to.be.a("string");
+
+      // Every signer is recorded, not just the first
+      const used = await context.program.account.usedUuidAccount.fetch(
+        findUsedUuidPDA(Array.from(uuid), context.program.programId)[0]
+      );
+      expect(used.attestation.attester.toString()).to.equal(
+        attester.publicKey.toString()
+      );
+      expect(used.coSigners.map((key) => key.toString())).to.deep.equal([
+        coAttester.publicKey.toString(),
+      ]);
+    });
+
+    it("should accept a co-signed attestation as slashing evidence", async () => {
+      const uuid = crypto.randomBytes(16);
+      await sendThresholdValidation([attester, coAttester], undefined, uuid);
+
+      const [coAttesterPda] = findAttesterPDA(
+        coAttester.publicKey,
+        context.program.programId
+      );
+      const [bondPda] = findAttesterBondPDA(
+        coAttester.publicKey,
+        context.program.programId
+      );
+      await context.program.methods
+        .postCollateral(coAttester.publicKey, new anchor.BN(2))
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: coAttesterPda,
+          bondAccount: bondPda,
+          depositor: context.authority.keypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+
+      // The co-signer is not the recorded attester, but signed all the same
+      await context.program.methods
+        .slashAttester(coAttester.publicKey, Array.from(uuid), new anchor.BN(1))
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: coAttesterPda,
+          bondAccount: bondPda,
+          usedUuidAccount: findUsedUuidPDA(
+            Array.from(uuid),
+            context.program.programId
+          )[0],
+          recipient: context.authority.keypair.publicKey,
+          authority: context.authority.keypair.publicKey,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+
+      const account = await context.program.account.attesterAccount.fetch(
+        coAttesterPda
+      );
+      expect(account.bond.toNumber()).to.equal(1);
+    });
+
+    it("should reject a single attestation for a quorum policy", async () => {
+      try {
+        await sendValidation(getFutureTimestamp(600));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttesterQuorumRequired");
+      }
+    });
+
+    it("should reject fewer signatures than the quorum", async () => {
+      try {
+        await sendThresholdValidation([attester]);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "AttesterQuorumNotMet");
+      }
+    });
+
+    it("should reject the same attester signing twice", async () => {
+      try {
+        await sendThresholdValidation([attester, attester]);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "DuplicateAttester");
+      }
+    });
+
+    it("should reject an Ed25519 instruction that does not match the signatures", async () => {
+      try {
+        await sendThresholdValidation(
+          [attester, coAttester],
+          [coAttester, attester]
+        );
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidSignature");
+      }
+    });
+
+    it("should reject a quorum above the maximum", async () => {
+      try {
+        await setAttesterQuorum(
+          context.program,
+          targetProgramId,
+          context.authority.keypair,
+          5,
+          context.registry.registryPda
+        );
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidAttesterQuorum");
+      }
+    });
+  });
+
+  describe("Collateral and Slashing", () => {
+    const COLLATERAL = anchor.web3.LAMPORTS_PER_SOL / 10;
+    const INCINERATOR = new PublicKey(
+      "1nc1nerator11111111111111111111111111111111"
+    );
+    let bondPda: PublicKey;
+
+    before(() => {
+      [bondPda] = findAttesterBondPDA(
+        attester.publicKey,
+        context.program.programId
+      );
+    });
+
+    async function setMinCollateral(minCollateral: number) {
+      await context.program.methods
+        .setBondConfig(new anchor.BN(0), new anchor.BN(0), new anchor.BN(minCollateral))
+        .accounts({
+          registry: context.registry.registryPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    async function postCollateral(
+      amount: number,
+      key: PublicKey = attester.publicKey
+    ) {
+      await context.program.methods
+        .postCollateral(key, new anchor.BN(amount))
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: findAttesterPDA(key, context.program.programId)[0],
+          bondAccount: findAttesterBondPDA(key, context.program.programId)[0],
+          depositor: context.authority.keypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    async function slashAttester(
+      uuid: Buffer,
+      amount: number,
+      recipient: PublicKey,
+      authority: Keypair = context.authority.keypair
+    ) {
+      const [usedUuidPda] = findUsedUuidPDA(
+        Array.from(uuid),
+        context.program.programId
+      );
+      await context.program.methods
+        .slashAttester(
+          attester.publicKey,
+          Array.from(uuid),
+          new anchor.BN(amount)
+        )
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: attesterPda,
+          bondAccount: bondPda,
+          usedUuidAccount: usedUuidPda,
+          recipient,
+          authority: authority.publicKey,
+        } as any)
+        .signers([authority])
+        .rpc();
+    }
+
+    after(async () => {
+      await setMinCollateral(0);
+    });
+
+    it("should reject attestations from an attester below the minimum collateral", async () => {
+      await setMinCollateral(COLLATERAL);
+
+      try {
+        await sendValidation(getFutureTimestamp(600));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InsufficientCollateral");
+      }
+    });
+
+    it("should accept attestations once collateral is posted", async () => {
+      await postCollateral(COLLATERAL);
+
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterPda
+      );
+      expect(account.bond.toNumber()).to.equal(COLLATERAL);
+
+      const result = await sendValidation(getFutureTimestamp(600));
+      expect(result).to.be.a("string");
+    });
+
+    it("should slash the bond to a recipient using the used UUID as evidence", async () => {
+      const uuid = crypto.randomBytes(16);
+      await sendValidation(getFutureTimestamp(600), null, uuid);
+
+      const recipient = Keypair.generate().publicKey;
+      await slashAttester(uuid, COLLATERAL / 2, recipient);
+
+      const vault = await context.program.account.attesterBondAccount.fetch(
+        bondPda
+      );
+      expect(vault.amount.toNumber()).to.equal(COLLATERAL / 2);
+      expect(
+        await context.provider.connection.getBalance(recipient)
+      ).to.equal(COLLATERAL / 2);
+
+      // The attester now falls below the minimum collateral
+      try {
+        await sendValidation(getFutureTimestamp(600));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InsufficientCollateral");
+      }
+    });
+
+    it("should burn slashed lamports sent to the incinerator", async () => {
+      await postCollateral(COLLATERAL / 2);
+      const uuid = crypto.randomBytes(16);
+      await sendValidation(getFutureTimestamp(600), null, uuid);
+
+      await slashAttester(uuid, COLLATERAL / 4, INCINERATOR);
+
+      const account = await context.program.account.attesterAccount.fetch(
+        attesterPda
+      );
+      expect(account.bond.toNumber()).to.equal(COLLATERAL - COLLATERAL / 4);
+    });
+
+    it("should reject slashing more than the bond", async () => {
+      const uuid = crypto.randomBytes(16);
+      await postCollateral(COLLATERAL / 4);
+      await sendValidation(getFutureTimestamp(600), null, uuid);
+
+      try {
+        await slashAttester(uuid, 2 * COLLATERAL, INCINERATOR);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidSlashAmount");
+      }
+    });
+
+    it("should reject slashing by anyone but the authority", async () => {
+      const stranger = await createFundedKeypair(context.provider);
+      const uuid = crypto.randomBytes(16);
+      await sendValidation(getFutureTimestamp(600), null, uuid);
+
+      try {
+        await slashAttester(uuid, 1, INCINERATOR, stranger);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "Unauthorized");
+      }
+    });
+
+    it("should reject evidence signed by a different attester", async () => {
+      const uuid = crypto.randomBytes(16);
+      await sendValidation(getFutureTimestamp(600), null, uuid);
+
+      const other = Keypair.generate().publicKey;
+      await registerAttesterIfNotExists(
+        context.program,
+        context.authority.keypair,
+        other,
+        context.registry.registryPda
+      );
+      await postCollateral(COLLATERAL, other);
+
+      try {
+        await context.program.methods
+          .slashAttester(other, Array.from(uuid), new anchor.BN(1))
+          .accounts({
+            registry: context.registry.registryPda,
+            attesterAccount: findAttesterPDA(
+              other,
+              context.program.programId
+            )[0],
+            bondAccount: findAttesterBondPDA(
+              other,
+              context.program.programId
+            )[0],
+            usedUuidAccount: findUsedUuidPDA(
+              Array.from(uuid),
+              context.program.programId
+            )[0],
+            recipient: INCINERATOR,
+            authority: context.authority.keypair.publicKey,
+          } as any)
+          .signers([context.authority.keypair])
+          .rpc();
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "SlashEvidenceMismatch");
+      }
+    });
+
+    it("should move the bond to a rotated key", async () => {
+      const oldKey = Keypair.generate();
+      const newKey = Keypair.generate();
+      await registerAttesterIfNotExists(
+        context.program,
+        context.authority.keypair,
+        oldKey.publicKey,
+        context.registry.registryPda
+      );
+      await postCollateral(COLLATERAL, oldKey.publicKey);
+
+      const [oldPda] = findAttesterPDA(
+        oldKey.publicKey,
+        context.program.programId
+      );
+      const [newPda] = findAttesterPDA(
+        newKey.publicKey,
+        context.program.programId
+      );
+      await rotateAttesterKey(
+        context.program,
+        context.authority.keypair,
+        oldKey.publicKey,
+        newKey.publicKey,
+        0
+      );
+
+      const oldAccount = await context.program.account.attesterAccount.fetch(
+        oldPda
+      );
+      const newAccount = await context.program.account.attesterAccount.fetch(
+        newPda
+      );
+      expect(oldAccount.bond.toNumber()).to.equal(0);
+      expect(newAccount.bond.toNumber()).to.equal(COLLATERAL);
+
+      // The vault stays keyed by the identity, and the new key meets the minimum
+      const vault = await context.program.account.attesterBondAccount.fetch(
+        findAttesterBondPDA(oldKey.publicKey, context.program.programId)[0]
+      );
+      expect(vault.identity.toString()).to.equal(oldKey.publicKey.toString());
+      expect(vault.amount.toNumber()).to.equal(COLLATERAL);
+
+      const result = await sendValidation(
+        getFutureTimestamp(600),
+        null,
+        crypto.randomBytes(16),
+        null,
+        newKey
+      );
+      expect(result).to.be.a("string");
+    });
+  });
+
+  describe("Ethereum Attesters", () => {
+    const ethPrivateKey = crypto.randomBytes(32);
+    let ethAddress: Buffer;
+    let ethAttesterKey: PublicKey;
+    let ethAttesterPda: PublicKey;
+
+    before(async () => {
+      ethAddress = ethAddressFromPrivateKey(ethPrivateKey);
+      ethAttesterKey = secp256k1AttesterKey(ethAddress);
+      [ethAttesterPda] = findAttesterPDA(
+        ethAttesterKey,
+        context.program.programId
+      );
+
+      await context.program.methods
+        .registerSecp256k1Attester(Array.from(ethAddress))
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: ethAttesterPda,
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          indexPage: await findAttesterIndexTailPDA(context.program),
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    });
+
+    /**
+     * Helper function to validate a fresh statement signed by the Ethereum
+     * attester (or another Ethereum key), optionally replacing the secp256k1
+     * instruction with an Ed25519 one
+     */
+    async function sendEthValidation(
+      useEd25519: boolean = false,
+      privateKey: Buffer = ethPrivateKey
+    ) {
+      const uuid = crypto.randomBytes(16);
+      const expiration = getFutureTimestamp(600);
+      const statement = createStatement(uuid, expiration);
+      const { instruction, signature } = createSecp256k1Attestation(
+        statement,
+        privateKey
+      );
+      const attesterKey = secp256k1AttesterKey(
+        ethAddressFromPrivateKey(privateKey)
+      );
+
+      const verifyInstruction = useEd25519
+        ? Ed25519Program.createInstructionWithPublicKey({
+            publicKey: attester.publicKey.toBytes(),
+            message: createMessageHash(statement),
+            signature: createAttestationSignature(statement, attester),
+          })
+        : instruction;
+
+      const [usedUuidPda] = findUsedUuidPDA(
+        Array.from(uuid),
+        context.program.programId
+      );
+
+      const validateInstruction = await context.program.methods
+        .validateAttestation(
+          statement.target,
+          statement.msgValue,
+          statement.encodedSigAndArgs,
+          {
+            uuid: Array.from(uuid),
+            attester: attesterKey,
+            signature: Array.from(signature),
+            expiration: statement.expiration,
+            issuedAt: statement.issuedAt,
+          }
+        )
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: findAttesterPDA(
+            attesterKey,
+            context.program.programId
+          )[0],
+          attesterScopeAccount: null,
+          policyAccount: policyPda,
+          usedUuidAccount: usedUuidPda,
+          signer: client.publicKey,
+          systemProgram: SystemProgram.programId,
+          instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        } as any)
+        .instruction();
+
+      const transaction = new Transaction();
+      transaction.add(verifyInstruction);
+      transaction.add(validateInstruction);
+      return context.provider.sendAndConfirm(transaction, [client]);
+    }
+
+    it("should register the attester under its padded Ethereum address", async () => {
+      const account = await context.program.account.attesterAccount.fetch(
+        ethAttesterPda
+      );
+      expect(account.attester.toBuffer().subarray(0, 12)).to.deep.equal(
+        Buffer.alloc(12)
+      );
+      expect(account.attester.toBuffer().subarray(12)).to.deep.equal(
+        ethAddress
+      );
+      expect(account.keyType).to.deep.equal({ secp256k1: {} });
+      expect(account.isRegistered).to.be.true;
+    });
+
+    it("should validate an attestation verified by the secp256k1 program", async () => {
+      const result = await sendEthValidation();
+      expect(result).to.be.a("string");
+    });
+
+    it("should reject an Ed25519 instruction for an Ethereum attester", async () => {
+      try {
+        await sendEthValidation(true);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidSignature");
+      }
+    });
+
+    it("should reject registering the zero address", async () => {
+      const zeroAddress = Buffer.alloc(20);
+      try {
+        await context.program.methods
+          .registerSecp256k1Attester(Array.from(zeroAddress))
+          .accounts({
+            registry: context.registry.registryPda,
+            attesterAccount: findAttesterPDA(
+              secp256k1AttesterKey(zeroAddress),
+              context.program.programId
+            )[0],
+            authority: context.authority.keypair.publicKey,
+            roleAccount: null,
+            indexPage: await findAttesterIndexTailPDA(context.program),
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .signers([context.authority.keypair])
+          .rpc();
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidAuthority");
+      }
+    });
+
+    it("should keep the Ethereum key type across a key rotation", async () => {
+      const newPrivateKey = crypto.randomBytes(32);
+      const newAttesterKey = secp256k1AttesterKey(
+        ethAddressFromPrivateKey(newPrivateKey)
+      );
+
+      // The new key must be an Ethereum address as well
+      try {
+        await rotateAttesterKey(
+          context.program,
+          context.authority.keypair,
+          ethAttesterKey,
+          Keypair.generate().publicKey,
+          3600
+        );
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidRotationKey");
+      }
+
+      await rotateAttesterKey(
+        context.program,
+        context.authority.keypair,
+        ethAttesterKey,
+        newAttesterKey,
+        3600
+      );
+
+      const account = await context.program.account.attesterAccount.fetch(
+        findAttesterPDA(newAttesterKey, context.program.programId)[0]
+      );
+      expect(account.keyType).to.deep.equal({ secp256k1: {} });
+      expect(account.identity.toString()).to.equal(ethAttesterKey.toString());
+
+      const result = await sendEthValidation(false, newPrivateKey);
+      expect(result).to.be.a("string");
+    });
+  });
+
+  describe("P-256 Attesters", () => {
+    const p256 = generateP256Keypair();
+    let p256AttesterKey: PublicKey;
+    let p256AttesterPda: PublicKey;
+
+    /**
+     * Helper function to register a P-256 attester by its compressed public key
+     */
+    async function registerP256Attester(publicKey: Buffer) {
+      return context.program.methods
+        .registerSecp256r1Attester(Array.from(publicKey))
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: findAttesterPDA(
+            secp256r1AttesterKey(publicKey),
+            context.program.programId
+          )[0],
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+          indexPage: await findAttesterIndexTailPDA(context.program),
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+    }
+
+    before(async () => {
+      p256AttesterKey = secp256r1AttesterKey(p256.publicKey);
+      [p256AttesterPda] = findAttesterPDA(
+        p256AttesterKey,
+        context.program.programId
+      );
+      await registerP256Attester(p256.publicKey);
+    });
+
+    /**
+     * Helper function to validate a fresh statement signed by the P-256
+     * attester (or another attester key), optionally verified in the
+     * secp256r1 instruction under a different key pair
+     */
+    async function sendP256Validation(
+      verifier: { privateKey: any; publicKey: Buffer } = p256,
+      attesterKey: PublicKey = p256AttesterKey
+    ) {
+      const uuid = crypto.randomBytes(16);
+      const expiration = getFutureTimestamp(600);
+      const statement = createStatement(uuid, expiration);
+      const signature = createSecp256r1Signature(statement, verifier.privateKey);
+
+      const secp256r1Instruction = createSecp256r1Instruction(
+        [{ publicKey: verifier.publicKey, signature }],
+        createMessageHash(statement)
+      );
+
+      const [usedUuidPda] = findUsedUuidPDA(
+        Array.from(uuid),
+        context.program.programId
+      );
+
+      const validateInstruction = await context.program.methods
+        .validateAttestation(
+          statement.target,
+          statement.msgValue,
+          statement.encodedSigAndArgs,
+          {
+            uuid: Array.from(uuid),
+            attester: attesterKey,
+            signature: Array.from(signature),
+            expiration: statement.expiration,
+            issuedAt: statement.issuedAt,
+          }
+        )
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: findAttesterPDA(
+            attesterKey,
+            context.program.programId
+          )[0],
+          attesterScopeAccount: null,
+          policyAccount: policyPda,
+          usedUuidAccount: usedUuidPda,
+          signer: client.publicKey,
+          systemProgram: SystemProgram.programId,
+          instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        } as any)
+        .instruction();
+
+      const transaction = new Transaction();
+      transaction.add(secp256r1Instruction);
+      transaction.add(validateInstruction);
+      return context.provider.sendAndConfirm(transaction, [client]);
+    }
+
+    it("should register the attester under its x-coordinate", async () => {
+      const account = await context.program.account.attesterAccount.fetch(
+        p256AttesterPda
+      );
+      expect(account.attester.toBuffer()).to.deep.equal(
+        p256.publicKey.subarray(1)
+      );
+      expect(account.keyType).to.deep.equal({ secp256r1: {} });
+      expect(account.isRegistered).to.be.true;
+    });
+
+    it("should validate an attestation verified by the secp256r1 program", async () => {
+      const result = await sendP256Validation();
+      expect(result).to.be.a("string");
+    });
+
+    it("should reject a secp256r1 instruction for a different key", async () => {
+      try {
+        await sendP256Validation(generateP256Keypair());
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidSignature");
+      }
+    });
+
+    it("should reject a public key that is not compressed", async () => {
+      const uncompressed = Buffer.from(generateP256Keypair().publicKey);
+      uncompressed[0] = 0x04;
+      try {
+        await registerP256Attester(uncompressed);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidAuthority");
+      }
+    });
+
+    it("should reject an x-coordinate outside the P-256 field", async () => {
+      const beyondField = Buffer.concat([
+        Buffer.from([0x02]),
+        Buffer.from(
+          "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+          "hex"
+        ),
+      ]);
+      try {
+        await registerP256Attester(beyondField);
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidAuthority");
+      }
+    });
+
+    it("should keep the P-256 key type across a key rotation", async () => {
+      const rotated = generateP256Keypair();
+      const rotatedAttesterKey = secp256r1AttesterKey(rotated.publicKey);
+
+      await rotateAttesterKey(
+        context.program,
+        context.authority.keypair,
+        p256AttesterKey,
+        rotatedAttesterKey,
+        3600
+      );
+
+      const account = await context.program.account.attesterAccount.fetch(
+        findAttesterPDA(rotatedAttesterKey, context.program.programId)[0]
+      );
+      expect(account.keyType).to.deep.equal({ secp256r1: {} });
+      expect(account.identity.toString()).to.equal(p256AttesterKey.toString());
+
+      const result = await sendP256Validation(rotated, rotatedAttesterKey);
+      expect(result).to.be.a("string");
+    });
+  });
+
+  describe("Keccak Digest", () => {
+    before(async () => {
+      await setDigestScheme(
+        context.program,
+        targetProgramId,
+        context.authority.keypair,
+        "keccak256",
+        context.registry.registryPda
+      );
+    });
+
+    after(async () => {
+      await setDigestScheme(
+        context.program,
+        targetProgramId,
+        context.authority.keypair,
+        "sha256",
+        context.registry.registryPda
+      );
+    });
+
+    it("should match the reference EVM digest vector", () => {
+      const statement = {
+        uuid: Array.from({ length: 16 }, (_, i) => i),
+        msgSender: new PublicKey(Buffer.alloc(32, 1)),
+        target: new PublicKey(Buffer.alloc(32, 2)),
+        msgValue: new anchor.BN(1_000_000),
+        encodedSigAndArgs: Buffer.from("test-encoded-data"),
+        policyId: "test-policy-v1",
+        expiration: new anchor.BN(1_700_000_600),
+        issuedAt: new anchor.BN(1_700_000_000),
+      };
+      expect(createEvmMessageHash(statement).toString("hex")).to.equal(
+        "0c0103440890a8d5a9f84306cc83f15a905a29ff93a2574356248cea2ab36b81"
+      );
+    });
+
+    it("should store the digest scheme on the policy", async () => {
+      const policy = await context.program.account.policyAccount.fetch(
+        policyPda
+      );
+      expect(policy.digestScheme).to.deep.equal({ keccak256: {} });
+    });
+
+    it("should validate an attestation signed over the keccak digest", async () => {
+      const result = await sendValidation(
+        getFutureTimestamp(600),
+        null,
+        crypto.randomBytes(16),
+        null,
+        attester,
+        createEvmMessageHash
+      );
+      expect(result).to.be.a("string");
+    });
+
+    it("should reject an attestation signed over the SHA-256 digest", async () => {
+      try {
+        await sendValidation(getFutureTimestamp(600));
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "InvalidSignature");
+      }
+    });
+
+    it("should refuse an attester with a revocation cutoff", async () => {
+      const revoked = Keypair.generate();
+      await registerAttesterIfNotExists(
+        context.program,
+        context.authority.keypair,
+        revoked.publicKey,
+        context.registry.registryPda
+      );
+      await context.program.methods
+        .revokeAttesterAfter(
+          revoked.publicKey,
+          new anchor.BN(getPastTimestamp(3600))
+        )
+        .accounts({
+          registry: context.registry.registryPda,
+          attesterAccount: findAttesterPDA(
+            revoked.publicKey,
+            context.program.programId
+          )[0],
+          authority: context.authority.keypair.publicKey,
+          roleAccount: null,
+        } as any)
+        .signers([context.authority.keypair])
+        .rpc();
+
+      // The keccak digest does not sign issuedAt, so the cutoff cannot hold
+      try {
+        await sendValidation(
+          getFutureTimestamp(600),
+          null,
+          crypto.randomBytes(16),
+          null,
+          revoked,
+          createEvmMessageHash
+        );
+        expect.fail("Expected transaction to fail");
+      } catch (error) {
+        expectError(error, "IssuedAtNotSigned");
+      }
+    });
+  });
 });
@@ -1,11 +1,19 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { PredicateRegistry } from "../../target/types/predicate_registry";
-import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  Ed25519Program,
+  Keypair,
+  PublicKey,
+  Secp256k1Program,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
 import * as fs from "fs";
 import * as path from "path";
 import * as crypto from "crypto";
 import nacl from "tweetnacl";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 // BPF Loader Upgradeable Program ID (well-known constant)
 const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
@@ -110,11 +118,23 @@ export async function createTestAccount(
 }
 
 /**
- * Finds program-derived addresses for the registry
+ * Registry namespace used by the shared test registry
  */
-export function findRegistryPDA(programId: PublicKey): TestRegistryPDA {
+export const DEFAULT_REGISTRY_ID = 0;
+
+/**
+ * Finds program-derived addresses for the registry with the given ID
+ */
+export function findRegistryPDA(
+  programId: PublicKey,
+  registryId: number | anchor.BN = DEFAULT_REGISTRY_ID
+): TestRegistryPDA {
+  const id = new anchor.BN(registryId);
+  // Registry 0 keeps the original `[b"predicate_registry"]` address
   const [registryPda, registryBump] = PublicKey.findProgramAddressSync(
-    [Buffer.from("predicate_registry")],
+    id.isZero()
+      ? [Buffer.from("predicate_registry")]
+      : [Buffer.from("predicate_registry"), id.toArrayLike(Buffer, "le", 8)],
     programId
   );
 
@@ -124,29 +144,268 @@ export function findRegistryPDA(programId: PublicKey): TestRegistryPDA {
   };
 }
 
+/**
+ * Seed scoping child PDAs to a registry: empty for registry 0, whose
+ * children keep their original un-namespaced addresses, and the registry
+ * address otherwise
+ */
+export function registryNamespace(
+  registryPda: PublicKey,
+  programId: PublicKey
+): Buffer {
+  return registryPda.equals(findRegistryPDA(programId).registryPda)
+    ? Buffer.alloc(0)
+    : registryPda.toBuffer();
+}
+
 /**
  * Finds attester PDA for a given attester public key
  */
 export function findAttesterPDA(
   attester: PublicKey,
-  programId: PublicKey
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("attester"),
+      registryNamespace(registryPda, programId),
+      attester.toBuffer(),
+    ],
+    programId
+  );
+}
+
+/**
+ * Returns the registry key of an Ethereum-key attester: its 20-byte address
+ * left-padded with zeros to 32 bytes
+ */
+export function secp256k1AttesterKey(ethAddress: Buffer): PublicKey {
+  return new PublicKey(Buffer.concat([Buffer.alloc(12), ethAddress]));
+}
+
+/**
+ * Finds the metadata PDA for a given attester
+ */
+export function findAttesterMetadataPDA(
+  attester: PublicKey,
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("attester_metadata"),
+      registryNamespace(registryPda, programId),
+      attester.toBuffer(),
+    ],
+    programId
+  );
+}
+
+/**
+ * Finds the scope PDA for a given attester identity
+ */
+export function findAttesterScopePDA(
+  identity: PublicKey,
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("attester_scope"),
+      registryNamespace(registryPda, programId),
+      identity.toBuffer(),
+    ],
+    programId
+  );
+}
+
+/**
+ * Finds the metadata and scope PDAs that every deregistration path closes
+ * (required even if they were never created; the scope PDA is keyed by the
+ * attester's identity, its first key)
+ */
+export function findAttesterSideAccounts(
+  attester: PublicKey,
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda,
+  identity: PublicKey = attester
+): { metadataAccount: PublicKey; scopeAccount: PublicKey } {
+  return {
+    metadataAccount: findAttesterMetadataPDA(
+      attester,
+      programId,
+      registryPda
+    )[0],
+    scopeAccount: findAttesterScopePDA(identity, programId, registryPda)[0],
+  };
+}
+
+/**
+ * Finds the bond vault PDA for an attester identity (its first key)
+ */
+export function findAttesterBondPDA(
+  identity: PublicKey,
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("attester_bond"),
+      registryNamespace(registryPda, programId),
+      identity.toBuffer(),
+    ],
+    programId
+  );
+}
+
+/**
+ * Attester keys per attester index page (mirrors `ATTESTER_INDEX_PAGE_SIZE`)
+ */
+export const ATTESTER_INDEX_PAGE_SIZE = 30;
+
+/**
+ * Finds the attester index page PDA for a given page number
+ */
+export function findAttesterIndexPDA(
+  page: number,
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("attester"), attester.toBuffer()],
+    [
+      Buffer.from("attester_index"),
+      registryNamespace(registryPda, programId),
+      new anchor.BN(page).toArrayLike(Buffer, "le", 4),
+    ],
     programId
   );
 }
 
+/**
+ * Finds the attester index tail page PDA, which lists the next attester
+ * registered (every registration path requires it)
+ */
+export async function findAttesterIndexTailPDA(
+  program: Program<PredicateRegistry>,
+  registryPda: PublicKey = findRegistryPDA(program.programId).registryPda
+): Promise<PublicKey> {
+  const registry = await program.account.predicateRegistry.fetch(registryPda);
+  const page = Math.floor(
+    registry.attesterIndexLen.toNumber() / ATTESTER_INDEX_PAGE_SIZE
+  );
+  return findAttesterIndexPDA(page, program.programId, registryPda)[0];
+}
+
+/**
+ * Finds the attester index page listing an attester, plus the index's last
+ * page unless they coincide (every deregistration path requires them)
+ */
+export async function findAttesterIndexPages(
+  program: Program<PredicateRegistry>,
+  attester: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(program.programId).registryPda
+): Promise<{ indexPage: PublicKey; tailPage: PublicKey | null }> {
+  const registry = await program.account.predicateRegistry.fetch(registryPda);
+  const len = registry.attesterIndexLen.toNumber();
+  const lastPage = Math.floor(Math.max(len - 1, 0) / ATTESTER_INDEX_PAGE_SIZE);
+  for (let page = 0; page <= lastPage; page++) {
+    const [indexPage] = findAttesterIndexPDA(
+      page,
+      program.programId,
+      registryPda
+    );
+    const account = await program.account.attesterIndexPage.fetch(indexPage);
+    if (account.attesters.some((key) => key.equals(attester))) {
+      return {
+        indexPage,
+        tailPage:
+          page === lastPage
+            ? null
+            : findAttesterIndexPDA(lastPage, program.programId, registryPda)[0],
+      };
+    }
+  }
+  throw new Error(`Attester ${attester.toBase58()} is not indexed`);
+}
+
 /**
  * Finds policy PDA for a given client program (not user)
  * CRITICAL: Policy is now tied to the PROGRAM, not the user
  */
 export function findPolicyPDA(
   clientProgram: PublicKey,
-  programId: PublicKey
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("policy"),
+      registryNamespace(registryPda, programId),
+      clientProgram.toBuffer(),
+    ],
+    programId
+  );
+}
+
+/**
+ * Finds admin proposal PDA for a given proposal ID
+ */
+export function findProposalPDA(
+  proposalId: number | anchor.BN,
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("admin_proposal"),
+      registryNamespace(registryPda, programId),
+      new anchor.BN(proposalId).toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+}
+
+/**
+ * Finds queued operation PDA for a given operation ID
+ */
+export function findOperationPDA(
+  operationId: number | anchor.BN,
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("policy"), clientProgram.toBuffer()],
+    [
+      Buffer.from("queued_operation"),
+      registryNamespace(registryPda, programId),
+      new anchor.BN(operationId).toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+}
+
+/**
+ * Registry roles, in on-chain enum order (the index is the PDA seed byte)
+ */
+export const ROLES = ["attesterManager", "pauser", "feeManager"] as const;
+export type RoleName = (typeof ROLES)[number];
+
+/**
+ * Finds role PDA for a given role and holder
+ */
+export function findRolePDA(
+  role: RoleName,
+  holder: PublicKey,
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("role"),
+      registryNamespace(registryPda, programId),
+      Buffer.from([ROLES.indexOf(role)]),
+      holder.toBuffer(),
+    ],
     programId
   );
 }
@@ -156,21 +415,39 @@ export function findPolicyPDA(
  */
 export function findUsedUuidPDA(
   uuid: number[],
-  programId: PublicKey
+  programId: PublicKey,
+  registryPda: PublicKey = findRegistryPDA(programId).registryPda
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("used_uuid"), Buffer.from(uuid)],
+    [
+      Buffer.from("used_uuid"),
+      registryNamespace(registryPda, programId),
+      Buffer.from(uuid),
+    ],
     programId
   );
 }
 
+/**
+ * Finds the ProgramData PDA for an upgradeable program
+ */
+export function findProgramDataPDA(programId: PublicKey): PublicKey {
+  const [programDataPda] = PublicKey.findProgramAddressSync(
+    [programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  );
+  return programDataPda;
+}
+
 /**
  * Initializes the predicate registry with the given authority
+ * The authority must be the registry program's upgrade authority
  */
 export async function initializeRegistry(
   program: Program<PredicateRegistry>,
   authority: Keypair,
-  registryPda: PublicKey
+  registryPda: PublicKey,
+  registryId: number | anchor.BN = DEFAULT_REGISTRY_ID
 ): Promise<anchor.web3.TransactionSignature> {
   console.log(
     "Initializing registry with authority:",
@@ -181,9 +458,10 @@ export async function initializeRegistry(
   console.log("Program ID:", program.programId.toString());
 
   return await program.methods
-    .initialize()
+    .initialize(new anchor.BN(registryId))
     .accounts({
       registry: registryPda,
+      programData: findProgramDataPDA(program.programId),
       authority: authority.publicKey,
       systemProgram: SystemProgram.programId,
     } as any)
@@ -197,7 +475,8 @@ export async function initializeRegistry(
 export async function initializeRegistryIfNotExists(
   program: Program<PredicateRegistry>,
   authority: Keypair,
-  registryPda: PublicKey
+  registryPda: PublicKey,
+  registryId: number | anchor.BN = DEFAULT_REGISTRY_ID
 ): Promise<anchor.web3.TransactionSignature> {
   try {
     await program.account.predicateRegistry.fetch(registryPda);
@@ -206,7 +485,7 @@ export async function initializeRegistryIfNotExists(
   } catch (error) {
     console.log("Registry does not exist");
   }
-  return await initializeRegistry(program, authority, registryPda);
+  return await initializeRegistry(program, authority, registryPda, registryId);
 }
 
 /**
@@ -218,14 +497,21 @@ export async function registerAttester(
   attester: PublicKey,
   registryPda: PublicKey
 ): Promise<string> {
-  const [attesterPda] = findAttesterPDA(attester, program.programId);
+  const [attesterPda] = findAttesterPDA(
+    attester,
+    program.programId,
+    registryPda,
+  );
 
   return await program.methods
-    .registerAttester(attester)
+    .registerAttester(attester, null)
     .accounts({
       registry: registryPda,
       attesterAccount: attesterPda,
       authority: authority.publicKey,
+      roleAccount: null,
+      metadataAccount: null,
+      indexPage: await findAttesterIndexTailPDA(program, registryPda),
       systemProgram: SystemProgram.programId,
     })
     .signers([authority])
@@ -240,7 +526,7 @@ export async function registerAttesterIfNotExists(
 ): Promise<string> {
   try {
     await program.account.attesterAccount.fetch(
-      findAttesterPDA(attester, program.programId)[0]
+      findAttesterPDA(attester, program.programId, registryPda)[0]
     );
     console.log("Attester already exists");
     return "";
@@ -250,6 +536,137 @@ export async function registerAttesterIfNotExists(
   return await registerAttester(program, authority, attester, registryPda);
 }
 
+/**
+ * Rotates an attester to a new key of the same key type (owner or manager)
+ */
+export async function rotateAttesterKey(
+  program: Program<PredicateRegistry>,
+  authority: Keypair,
+  oldKey: PublicKey,
+  newKey: PublicKey,
+  overlap: number,
+  registryPda: PublicKey = findRegistryPDA(program.programId).registryPda
+): Promise<string> {
+  return await program.methods
+    .rotateAttesterKey(oldKey, newKey, new anchor.BN(overlap))
+    .accounts({
+      registry: registryPda,
+      attesterAccount: findAttesterPDA(
+        oldKey,
+        program.programId,
+        registryPda
+      )[0],
+      newAttesterAccount: findAttesterPDA(
+        newKey,
+        program.programId,
+        registryPda
+      )[0],
+      authority: authority.publicKey,
+      roleAccount: null,
+      indexPage: await findAttesterIndexTailPDA(program, registryPda),
+      systemProgram: SystemProgram.programId,
+    } as any)
+    .signers([authority])
+    .rpc();
+}
+
+/**
+ * Grants a role to a holder (owner only)
+ */
+export async function grantRole(
+  program: Program<PredicateRegistry>,
+  authority: Keypair,
+  role: RoleName,
+  holder: PublicKey,
+  registryPda: PublicKey
+): Promise<string> {
+  const [rolePda] = findRolePDA(role, holder, program.programId, registryPda);
+
+  return await program.methods
+    .grantRole({ [role]: {} } as any, holder)
+    .accounts({
+      registry: registryPda,
+      roleAccount: rolePda,
+      authority: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any)
+    .signers([authority])
+    .rpc();
+}
+
+/**
+ * Revokes a role from a holder (owner only)
+ */
+export async function revokeRole(
+  program: Program<PredicateRegistry>,
+  authority: Keypair,
+  role: RoleName,
+  holder: PublicKey,
+  registryPda: PublicKey
+): Promise<string> {
+  const [rolePda] = findRolePDA(role, holder, program.programId, registryPda);
+
+  return await program.methods
+    .revokeRole({ [role]: {} } as any, holder)
+    .accounts({
+      registry: registryPda,
+      roleAccount: rolePda,
+      authority: authority.publicKey,
+    } as any)
+    .signers([authority])
+    .rpc();
+}
+
+/**
+ * Proposes a new registry authority (first step of a two-step transfer)
+ */
+export async function proposeAuthority(
+  program: Program<PredicateRegistry>,
+  authority: Keypair,
+  newAuthority: PublicKey,
+  registryPda: PublicKey
+): Promise<string> {
+  return await program.methods
+    .proposeAuthority(newAuthority)
+    .accounts({
+      registry: registryPda,
+      authority: authority.publicKey,
+    } as any)
+    .signers([authority])
+    .rpc();
+}
+
+/**
+ * Accepts a pending registry authority transfer (second step)
+ */
+export async function acceptAuthority(
+  program: Program<PredicateRegistry>,
+  newAuthority: Keypair,
+  registryPda: PublicKey
+): Promise<string> {
+  return await program.methods
+    .acceptAuthority()
+    .accounts({
+      registry: registryPda,
+      newAuthority: newAuthority.publicKey,
+    } as any)
+    .signers([newAuthority])
+    .rpc();
+}
+
+/**
+ * Transfers registry authority by proposing and immediately accepting
+ */
+export async function transferAuthority(
+  program: Program<PredicateRegistry>,
+  authority: Keypair,
+  newAuthority: Keypair,
+  registryPda: PublicKey
+): Promise<string> {
+  await proposeAuthority(program, authority, newAuthority.publicKey, registryPda);
+  return await acceptAuthority(program, newAuthority, registryPda);
+}
+
 /**
  * Sets a policy ID for a client program
  * CRITICAL: Policy is now set for a PROGRAM, not a user
@@ -262,7 +679,11 @@ export async function setPolicyId(
   policyId: string,
   registryPda: PublicKey
 ): Promise<string> {
-  const [policyPda] = findPolicyPDA(clientProgram, program.programId);
+  const [policyPda] = findPolicyPDA(
+    clientProgram,
+    program.programId,
+    registryPda,
+  );
 
   // Derive program data PDA
   const [programDataPda] = PublicKey.findProgramAddressSync(
@@ -296,7 +717,11 @@ export async function updatePolicyId(
   policyId: string,
   registryPda: PublicKey
 ): Promise<string> {
-  const [policyPda] = findPolicyPDA(clientProgram, program.programId);
+  const [policyPda] = findPolicyPDA(
+    clientProgram,
+    program.programId,
+    registryPda,
+  );
 
   // Derive program data PDA
   const [programDataPda] = PublicKey.findProgramAddressSync(
@@ -317,6 +742,105 @@ export async function updatePolicyId(
     .rpc();
 }
 
+/**
+ * Pauses or unpauses attestation validation for a client program
+ * Only the program's upgrade authority can call this
+ */
+export async function setPolicyPaused(
+  program: Program<PredicateRegistry>,
+  clientProgram: PublicKey,
+  authority: Keypair,
+  paused: boolean,
+  registryPda: PublicKey
+): Promise<string> {
+  const [policyPda] = findPolicyPDA(
+    clientProgram,
+    program.programId,
+    registryPda,
+  );
+
+  // Derive program data PDA
+  const [programDataPda] = PublicKey.findProgramAddressSync(
+    [clientProgram.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  );
+
+  const method = paused
+    ? program.methods.pausePolicy()
+    : program.methods.unpausePolicy();
+
+  return await method
+    .accounts({
+      registry: registryPda,
+      policyAccount: policyPda,
+      clientProgram: clientProgram,
+      programData: programDataPda,
+      authority: authority.publicKey,
+    } as any)
+    .signers([authority])
+    .rpc();
+}
+
+/**
+ * Sets how many distinct attesters must sign each validation for a client program
+ * Only the program's upgrade authority can call this
+ */
+export async function setAttesterQuorum(
+  program: Program<PredicateRegistry>,
+  clientProgram: PublicKey,
+  authority: Keypair,
+  quorum: number,
+  registryPda: PublicKey
+): Promise<string> {
+  const [policyPda] = findPolicyPDA(
+    clientProgram,
+    program.programId,
+    registryPda,
+  );
+
+  return await program.methods
+    .setAttesterQuorum(quorum)
+    .accounts({
+      registry: registryPda,
+      policyAccount: policyPda,
+      clientProgram: clientProgram,
+      programData: findProgramDataPDA(clientProgram),
+      authority: authority.publicKey,
+    } as any)
+    .signers([authority])
+    .rpc();
+}
+
+/**
+ * Sets the digest over which attesters sign statements for a client program
+ * Only the program's upgrade authority can call this
+ */
+export async function setDigestScheme(
+  program: Program<PredicateRegistry>,
+  clientProgram: PublicKey,
+  authority: Keypair,
+  digestScheme: "sha256" | "keccak256" | "sha256WithIssuedAt",
+  registryPda: PublicKey
+): Promise<string> {
+  const [policyPda] = findPolicyPDA(
+    clientProgram,
+    program.programId,
+    registryPda,
+  );
+
+  return await program.methods
+    .setDigestScheme({ [digestScheme]: {} } as any)
+    .accounts({
+      registry: registryPda,
+      policyAccount: policyPda,
+      clientProgram: clientProgram,
+      programData: findProgramDataPDA(clientProgram),
+      authority: authority.publicKey,
+    } as any)
+    .signers([authority])
+    .rpc();
+}
+
 /**
  * Sets or updates a policy ID for a client program (idempotent)
  * Tries to set first, if account exists, updates instead
@@ -366,7 +890,8 @@ export function createTestStatement(
   msgValue: number,
   encodedSigAndArgs: Buffer,
   policyId: string,
-  expiration: number
+  expiration: number,
+  issuedAt: number = Math.min(expiration, getCurrentTimestamp())
 ): any {
   return {
     uuid: Array.from(uuid),
@@ -376,6 +901,7 @@ export function createTestStatement(
     encodedSigAndArgs: Array.from(encodedSigAndArgs),
     policyId: policyId,
     expiration: new anchor.BN(expiration),
+    issuedAt: new anchor.BN(issuedAt),
   };
 }
 
@@ -386,13 +912,15 @@ export function createTestAttestation(
   uuid: Buffer,
   attester: PublicKey,
   signature: Buffer,
-  expiration: number
+  expiration: number,
+  issuedAt: number
 ): any {
   return {
     uuid: Array.from(uuid),
     attester,
     signature: Array.from(signature),
     expiration: new anchor.BN(expiration),
+    issuedAt: new anchor.BN(issuedAt),
   };
 }
 
@@ -519,6 +1047,33 @@ export function getPolicyPDA(programId: PublicKey, clientProgram: PublicKey) {
  * @returns Buffer containing the 32-byte hash
  */
 export function createMessageHash(statement: any): Buffer {
+  return crypto
+    .createHash("sha256")
+    .update(statementPreimage(statement))
+    .digest();
+}
+
+/**
+ * Creates the statement digest that also signs the issue time (matching
+ * hash_statement_with_issued_at in Rust): the createMessageHash preimage
+ * followed by issuedAt as 8 little-endian bytes
+ *
+ * @param statement - The statement object with uuid, msgSender, target, msgValue, encodedSigAndArgs, policyId, expiration, issuedAt
+ * @returns Buffer containing the 32-byte hash
+ */
+export function createIssuedAtMessageHash(statement: any): Buffer {
+  const data = Buffer.concat([
+    statementPreimage(statement),
+    Buffer.from(statement.issuedAt.toTwos(64).toArrayLike(Buffer, "le", 8)),
+  ]);
+
+  return crypto.createHash("sha256").update(data).digest();
+}
+
+/**
+ * Builds the data hashed by createMessageHash (hash_statement_safe in Rust)
+ */
+function statementPreimage(statement: any): Buffer {
   // Domain separator to prevent signature reuse across contexts
   const domainSeparator = Buffer.from("predicate_solana_attestation");
 
@@ -533,7 +1088,7 @@ export function createMessageHash(statement: any): Buffer {
     .digest();
 
   // Concatenate domain separator with fixed-length fields and hashed variable-length fields
-  const data = Buffer.concat([
+  return Buffer.concat([
     domainSeparator,
     Buffer.from(statement.uuid),
     statement.msgSender.toBuffer(),
@@ -543,8 +1098,61 @@ export function createMessageHash(statement: any): Buffer {
     policyIdHash,
     Buffer.from(statement.expiration.toBuffer("le", 8)),
   ]);
+}
 
-  return crypto.createHash("sha256").update(data).digest();
+/**
+ * Creates the Keccak-256 statement digest (matching hash_statement_evm in Rust)
+ *
+ * keccak256(abi.encode(uuid, msgSender, target, msgValue, encodedSigAndArgs,
+ * policy, expiration)) with the types
+ * (string, address, address, uint256, bytes, string, uint256), mirroring
+ * hashStatementSafe in the EVM contracts. The UUID is its dashed string and
+ * Solana keys become the address of their low 20 bytes. issuedAt is not part
+ * of the digest.
+ *
+ * @param statement - The statement object with uuid, msgSender, target, msgValue, encodedSigAndArgs, policyId, expiration
+ * @returns Buffer containing the 32-byte hash
+ */
+export function createEvmMessageHash(statement: any): Buffer {
+  const HEAD_LEN = 7 * 32;
+  const word = (value: anchor.BN) => value.toTwos(256).toArrayLike(Buffer, "be", 32);
+
+  // Dynamic values follow the head as a length word and zero-padded bytes
+  const tails: Buffer[] = [];
+  let tailLen = 0;
+  const dynamic = (bytes: Buffer) => {
+    const offset = word(new anchor.BN(HEAD_LEN + tailLen));
+    const padded = Buffer.alloc(Math.ceil(bytes.length / 32) * 32);
+    bytes.copy(padded);
+    tails.push(word(new anchor.BN(bytes.length)), padded);
+    tailLen += 32 + padded.length;
+    return offset;
+  };
+
+  // An address is the key's low 20 bytes, right-aligned in its word
+  const address = (key: PublicKey) => {
+    const padded = Buffer.alloc(32);
+    key.toBuffer().copy(padded, 12, 12);
+    return padded;
+  };
+
+  const uuidHex = Buffer.from(statement.uuid).toString("hex");
+  const uuid = [8, 12, 16, 20].reduceRight(
+    (formatted, at) => formatted.slice(0, at) + "-" + formatted.slice(at),
+    uuidHex
+  );
+
+  const head = [
+    dynamic(Buffer.from(uuid, "utf8")),
+    address(statement.msgSender),
+    address(statement.target),
+    word(statement.msgValue),
+    dynamic(Buffer.from(statement.encodedSigAndArgs)),
+    dynamic(Buffer.from(statement.policyId, "utf8")),
+    word(statement.expiration),
+  ];
+
+  return Buffer.from(keccak_256(Buffer.concat([...head, ...tails])));
 }
 
 /**
@@ -552,12 +1160,206 @@ export function createMessageHash(statement: any): Buffer {
  *
  * @param statement - The statement object to sign
  * @param attesterKeypair - The keypair of the attester signing the statement
+ * @param hashStatement - The statement digest to sign (the policy's digest scheme)
  * @returns Uint8Array containing the 64-byte Ed25519 signature
  */
 export function createAttestationSignature(
   statement: any,
-  attesterKeypair: Keypair
+  attesterKeypair: Keypair,
+  hashStatement: (statement: any) => Buffer = createMessageHash
 ): Uint8Array {
-  const messageHash = createMessageHash(statement);
+  const messageHash = hashStatement(statement);
   return nacl.sign.detached(messageHash, attesterKeypair.secretKey);
 }
+
+/**
+ * Creates one Ed25519 verification instruction carrying several signatures
+ * over the same message, as expected by validate_threshold_attestation
+ */
+export function createMultiEd25519Instruction(
+  signers: { publicKey: PublicKey; signature: Uint8Array }[],
+  message: Buffer
+): TransactionInstruction {
+  const OFFSETS_LEN = 14;
+  const ENTRY_LEN = 32 + 64;
+  const CURRENT_INSTRUCTION = 0xffff;
+
+  const headerLen = 2 + OFFSETS_LEN * signers.length;
+  const messageOffset = headerLen + ENTRY_LEN * signers.length;
+  const data = Buffer.alloc(messageOffset + message.length);
+  data.writeUInt8(signers.length, 0);
+
+  signers.forEach(({ publicKey, signature }, i) => {
+    const pubkeyOffset = headerLen + ENTRY_LEN * i;
+    const signatureOffset = pubkeyOffset + 32;
+    const base = 2 + OFFSETS_LEN * i;
+    data.writeUInt16LE(signatureOffset, base);
+    data.writeUInt16LE(CURRENT_INSTRUCTION, base + 2);
+    data.writeUInt16LE(pubkeyOffset, base + 4);
+    data.writeUInt16LE(CURRENT_INSTRUCTION, base + 6);
+    data.writeUInt16LE(messageOffset, base + 8);
+    data.writeUInt16LE(message.length, base + 10);
+    data.writeUInt16LE(CURRENT_INSTRUCTION, base + 12);
+    publicKey.toBuffer().copy(data, pubkeyOffset);
+    Buffer.from(signature).copy(data, signatureOffset);
+  });
+  message.copy(data, messageOffset);
+
+  return new TransactionInstruction({
+    keys: [],
+    programId: Ed25519Program.programId,
+    data,
+  });
+}
+
+/**
+ * Creates a secp256k1 verification instruction for an Ethereum-key attester
+ * signing a statement
+ *
+ * The precompile hashes the 32-byte statement hash with keccak256 before
+ * recovering the signer, so no Ethereum message prefix is applied.
+ *
+ * @param statement - The statement object to sign
+ * @param privateKey - The 32-byte secp256k1 private key of the attester
+ * @param instructionIndex - Index of the returned instruction in its transaction
+ * @returns The instruction, the 20-byte Ethereum address and the 64-byte signature
+ */
+export function createSecp256k1Attestation(
+  statement: any,
+  privateKey: Buffer,
+  instructionIndex: number = 0
+): {
+  instruction: TransactionInstruction;
+  ethAddress: Buffer;
+  signature: Buffer;
+} {
+  const instruction = Secp256k1Program.createInstructionWithPrivateKey({
+    privateKey,
+    message: createMessageHash(statement),
+    instructionIndex,
+  });
+  return {
+    instruction,
+    ethAddress: Buffer.from(instruction.data.subarray(12, 32)),
+    signature: Buffer.from(instruction.data.subarray(32, 96)),
+  };
+}
+
+/**
+ * Derives the 20-byte Ethereum address of a secp256k1 private key
+ */
+export function ethAddressFromPrivateKey(privateKey: Buffer): Buffer {
+  const { data } = Secp256k1Program.createInstructionWithPrivateKey({
+    privateKey,
+    message: Buffer.alloc(32),
+  });
+  return Buffer.from(data.subarray(12, 32));
+}
+
+/**
+ * The secp256r1 (P-256) signature verification program
+ */
+export const SECP256R1_PROGRAM_ID = new PublicKey(
+  "Secp256r1SigVerify1111111111111111111111111"
+);
+
+/**
+ * Order of the P-256 curve, for normalizing signatures to low-S form
+ */
+const P256_ORDER = BigInt(
+  "0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+);
+
+/**
+ * Generates a P-256 key pair, as issued by an HSM or cloud KMS
+ *
+ * @returns The private key and the 33-byte SEC1 compressed public key
+ */
+export function generateP256Keypair(): {
+  privateKey: crypto.KeyObject;
+  publicKey: Buffer;
+} {
+  const { privateKey, publicKey } = crypto.generateKeyPairSync("ec", {
+    namedCurve: "prime256v1",
+  });
+  const jwk = publicKey.export({ format: "jwk" });
+  const x = Buffer.from(jwk.x as string, "base64url");
+  const y = Buffer.from(jwk.y as string, "base64url");
+  return {
+    privateKey,
+    publicKey: Buffer.concat([Buffer.from([0x02 | (y[31] & 1)]), x]),
+  };
+}
+
+/**
+ * Returns the registry key of a P-256 attester: the x-coordinate of its
+ * compressed public key
+ */
+export function secp256r1AttesterKey(publicKey: Buffer): PublicKey {
+  return new PublicKey(publicKey.subarray(1));
+}
+
+/**
+ * Creates a low-S ECDSA-SHA256 signature over a statement hash with a P-256 key
+ *
+ * @param statement - The statement object to sign
+ * @param privateKey - The attester's P-256 private key
+ * @returns Buffer containing the 64-byte `r || s` signature
+ */
+export function createSecp256r1Signature(
+  statement: any,
+  privateKey: crypto.KeyObject
+): Buffer {
+  const signature = crypto.sign("sha256", createMessageHash(statement), {
+    key: privateKey,
+    dsaEncoding: "ieee-p1363",
+  });
+  const s = BigInt("0x" + signature.subarray(32).toString("hex"));
+  if (s > P256_ORDER / BigInt(2)) {
+    Buffer.from(
+      (P256_ORDER - s).toString(16).padStart(64, "0"),
+      "hex"
+    ).copy(signature, 32);
+  }
+  return signature;
+}
+
+/**
+ * Creates one secp256r1 verification instruction carrying several P-256
+ * signatures over the same message
+ */
+export function createSecp256r1Instruction(
+  signers: { publicKey: Buffer; signature: Uint8Array }[],
+  message: Buffer
+): TransactionInstruction {
+  const OFFSETS_LEN = 14;
+  const ENTRY_LEN = 33 + 64;
+  const CURRENT_INSTRUCTION = 0xffff;
+
+  const headerLen = 2 + OFFSETS_LEN * signers.length;
+  const messageOffset = headerLen + ENTRY_LEN * signers.length;
+  const data = Buffer.alloc(messageOffset + message.length);
+  data.writeUInt8(signers.length, 0);
+
+  signers.forEach(({ publicKey, signature }, i) => {
+    const pubkeyOffset = headerLen + ENTRY_LEN * i;
+    const signatureOffset = pubkeyOffset + 33;
+    const base = 2 + OFFSETS_LEN * i;
+    data.writeUInt16LE(signatureOffset, base);
+    data.writeUInt16LE(CURRENT_INSTRUCTION, base + 2);
+    data.writeUInt16LE(pubkeyOffset, base + 4);
+    data.writeUInt16LE(CURRENT_INSTRUCTION, base + 6);
+    data.writeUInt16LE(messageOffset, base + 8);
+    data.writeUInt16LE(message.length, base + 10);
+    data.writeUInt16LE(CURRENT_INSTRUCTION, base + 12);
+    publicKey.copy(data, pubkeyOffset);
+    Buffer.from(signature).copy(data, signatureOffset);
+  });
+  message.copy(data, messageOffset);
+
+  return new TransactionInstruction({
+    keys: [],
+    programId: SECP256R1_PROGRAM_ID,
+    data,
+  });
+}
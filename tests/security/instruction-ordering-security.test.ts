@@ -35,6 +35,7 @@ import {
   setPolicyId,
   getFutureTimestamp,
   createMessageHash,
+  registryNamespace,
 } from "../helpers/test-utils";
 
 describe("Program Security Tests", () => {
@@ -116,6 +117,7 @@ describe("Program Security Tests", () => {
         encodedSigAndArgs: Buffer.from("test()"),
         policyId: testPolicy,
         expiration: new anchor.BN(expiration),
+        issuedAt: new anchor.BN(Math.min(expiration, Math.floor(Date.now() / 1000))),
       };
     }
 
@@ -139,6 +141,7 @@ describe("Program Security Tests", () => {
         attester: attesterKeypair.publicKey,
         signature: Array.from(signature),
         expiration: statement.expiration,
+        issuedAt: statement.issuedAt,
       };
     }
 
@@ -159,7 +162,14 @@ describe("Program Security Tests", () => {
       );
 
       const [usedUuidPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("used_uuid"), Buffer.from(uuid)],
+        [
+          Buffer.from("used_uuid"),
+          registryNamespace(
+            context.registry.registryPda,
+            context.program.programId
+          ),
+          Buffer.from(uuid),
+        ],
         context.program.programId
       );
 
@@ -194,6 +204,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: validator.publicKey,
@@ -232,7 +243,14 @@ describe("Program Security Tests", () => {
       );
 
       const [usedUuidPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("used_uuid"), Buffer.from(uuid)],
+        [
+          Buffer.from("used_uuid"),
+          registryNamespace(
+            context.registry.registryPda,
+            context.program.programId
+          ),
+          Buffer.from(uuid),
+        ],
         context.program.programId
       );
 
@@ -258,6 +276,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: validator.publicKey,
@@ -311,7 +330,14 @@ describe("Program Security Tests", () => {
       );
 
       const [usedUuidPda2] = PublicKey.findProgramAddressSync(
-        [Buffer.from("used_uuid"), Buffer.from(uuid2)],
+        [
+          Buffer.from("used_uuid"),
+          registryNamespace(
+            context.registry.registryPda,
+            context.program.programId
+          ),
+          Buffer.from(uuid2),
+        ],
         context.program.programId
       );
 
@@ -338,6 +364,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda2,
             signer: validator.publicKey,
@@ -14,6 +14,7 @@ import {
   findAttesterPDA,
   findPolicyPDA,
   createMessageHash,
+  registryNamespace,
 } from "../helpers/test-utils";
 import * as nacl from "tweetnacl";
 
@@ -94,6 +95,7 @@ describe("Program Security Tests", () => {
         encodedSigAndArgs: Buffer.from("test()"),
         policyId: testPolicy,
         expiration: new anchor.BN(Math.floor(Date.now() / 1000) + 3600), // 1 hour from now
+        issuedAt: new anchor.BN(Math.floor(Date.now() / 1000)),
       };
     }
 
@@ -115,6 +117,7 @@ describe("Program Security Tests", () => {
         attester: attesterKeypair.publicKey,
         uuid: Buffer.from(statement.uuid),
         expiration: statement.expiration,
+        issuedAt: statement.issuedAt,
         signature: Buffer.from(signature),
       };
     }
@@ -124,7 +127,11 @@ describe("Program Security Tests", () => {
      */
     function findUsedUuidPDA(uuid: number[]): PublicKey {
       const [pda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("used_uuid"), Buffer.from(uuid)],
+        [
+          Buffer.from("used_uuid"),
+          registryNamespace(context.registry.registryPda, program.programId),
+          Buffer.from(uuid),
+        ],
         program.programId
       );
       return pda;
@@ -170,6 +177,7 @@ describe("Program Security Tests", () => {
         .accounts({
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
+          attesterScopeAccount: null,
           policyAccount: policyAccount,
           usedUuidAccount: usedUuidPda,
           signer: validator.publicKey,
@@ -197,6 +205,7 @@ describe("Program Security Tests", () => {
         await program.methods
           .cleanupExpiredUuid()
           .accounts({
+            registry: context.registry.registryPda,
             usedUuidAccount: usedUuidPda,
             signerRecipient: attacker.publicKey, // ← Attacker's address!
           } as any)
@@ -275,6 +284,7 @@ describe("Program Security Tests", () => {
         .accounts({
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
+          attesterScopeAccount: null,
           policyAccount: policyAccount,
           usedUuidAccount: usedUuidPda,
           signer: validator.publicKey,
@@ -302,6 +312,7 @@ describe("Program Security Tests", () => {
         await program.methods
           .cleanupExpiredUuid()
           .accounts({
+            registry: context.registry.registryPda,
             usedUuidAccount: usedUuidPda,
             signerRecipient: validator.publicKey, // ← Correct address
           } as any)
@@ -369,6 +380,7 @@ describe("Program Security Tests", () => {
         .accounts({
           registry: context.registry.registryPda,
           attesterAccount: attesterPda,
+          attesterScopeAccount: null,
           policyAccount: policyAccount,
           usedUuidAccount: usedUuidPda,
           signer: validator.publicKey,
@@ -392,6 +404,7 @@ describe("Program Security Tests", () => {
         await program.methods
           .cleanupExpiredUuid()
           .accounts({
+            registry: context.registry.registryPda,
             usedUuidAccount: usedUuidPda,
             signerRecipient: validator.publicKey, // ← Validator's own address
           } as any)
@@ -19,6 +19,7 @@ import {
   findAttesterPDA,
   findPolicyPDA,
   createMessageHash,
+  registryNamespace,
 } from "../helpers/test-utils";
 import nacl from "tweetnacl";
 import * as crypto from "crypto";
@@ -90,6 +91,7 @@ describe("Program Security Tests", () => {
         encodedSigAndArgs: Buffer.from("test()"),
         policyId: "x-replay-test-policy",
         expiration: new BN(Math.floor(Date.now() / 1000) + 3600), // 1 hour from now
+        issuedAt: new BN(Math.floor(Date.now() / 1000)),
       };
     }
 
@@ -111,6 +113,7 @@ describe("Program Security Tests", () => {
         attester: attesterKeypair.publicKey,
         uuid: Buffer.from(statement.uuid),
         expiration: statement.expiration,
+        issuedAt: statement.issuedAt,
         signature: Buffer.from(signature),
       };
     }
@@ -120,7 +123,11 @@ describe("Program Security Tests", () => {
      */
     function findUsedUuidPDA(uuid: number[]): PublicKey {
       const [pda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("used_uuid"), Buffer.from(uuid)],
+        [
+          Buffer.from("used_uuid"),
+          registryNamespace(context.registry.registryPda, program.programId),
+          Buffer.from(uuid),
+        ],
         program.programId
       );
       return pda;
@@ -156,6 +163,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -215,6 +223,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -259,6 +268,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -287,6 +297,7 @@ describe("Program Security Tests", () => {
             .accounts({
               registry: context.registry.registryPda,
               attesterAccount: attesterPda,
+              attesterScopeAccount: null,
               policyAccount,
               usedUuidAccount: usedUuidPda,
               signer: client.publicKey,
@@ -343,6 +354,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount,
             usedUuidAccount: usedUuidPda1,
             signer: client.publicKey,
@@ -371,6 +383,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount,
             usedUuidAccount: usedUuidPda2,
             signer: client.publicKey,
@@ -417,6 +430,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -432,6 +446,7 @@ describe("Program Security Tests", () => {
           await program.methods
             .cleanupExpiredUuid()
             .accounts({
+              registry: context.registry.registryPda,
               usedUuidAccount: usedUuidPda,
               signerRecipient: client.publicKey,
             } as any)
@@ -451,6 +466,7 @@ describe("Program Security Tests", () => {
         const statement = createStatement(uuid);
         // Set expiration to 1 second ago
         statement.expiration = new BN(Math.floor(Date.now() / 1000) - 1);
+        statement.issuedAt = statement.expiration.subn(60);
 
         const attestation = createAttestation(
           statement,
@@ -482,6 +498,7 @@ describe("Program Security Tests", () => {
             .accounts({
               registry: context.registry.registryPda,
               attesterAccount: attesterPda,
+              attesterScopeAccount: null,
               policyAccount,
               usedUuidAccount: usedUuidPda,
               signer: client.publicKey,
@@ -538,6 +555,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -579,6 +597,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount,
             usedUuidAccount: usedUuidPda,
             signer: client.publicKey,
@@ -40,6 +40,7 @@ import {
   getFutureTimestamp,
   createMessageHash,
   createAttestationSignature,
+  registryNamespace,
 } from "../helpers/test-utils";
 
 describe("Program Security Tests", () => {
@@ -113,6 +114,7 @@ describe("Program Security Tests", () => {
         encodedSigAndArgs: Buffer.from("test()"),
         policyId: testPolicy,
         expiration: new anchor.BN(expiration),
+        issuedAt: new anchor.BN(Math.min(expiration, Math.floor(Date.now() / 1000))),
       };
     }
 
@@ -136,6 +138,7 @@ describe("Program Security Tests", () => {
         attester: attesterKeypair.publicKey,
         signature: Array.from(signature),
         expiration: statement.expiration,
+        issuedAt: statement.issuedAt,
       };
     }
 
@@ -243,7 +246,14 @@ describe("Program Security Tests", () => {
       );
 
       const [usedUuidPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("used_uuid"), Buffer.from(uuid)],
+        [
+          Buffer.from("used_uuid"),
+          registryNamespace(
+            context.registry.registryPda,
+            context.program.programId
+          ),
+          Buffer.from(uuid),
+        ],
         context.program.programId
       );
 
@@ -287,6 +297,7 @@ describe("Program Security Tests", () => {
             .accounts({
               registry: context.registry.registryPda,
               attesterAccount: attesterPda,
+              attesterScopeAccount: null,
               policyAccount: policyPda,
               usedUuidAccount: usedUuidPda,
               signer: validator.publicKey,
@@ -336,10 +347,18 @@ describe("Program Security Tests", () => {
         attester: attester.publicKey,
         signature: Array.from(differentSignature),
         expiration: statement.expiration,
+        issuedAt: statement.issuedAt,
       };
 
       const [usedUuidPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("used_uuid"), Buffer.from(uuid)],
+        [
+          Buffer.from("used_uuid"),
+          registryNamespace(
+            context.registry.registryPda,
+            context.program.programId
+          ),
+          Buffer.from(uuid),
+        ],
         context.program.programId
       );
 
@@ -361,6 +380,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: validator.publicKey,
@@ -404,10 +424,18 @@ describe("Program Security Tests", () => {
         attester: attester.publicKey, // Claim it's from registered attester
         signature: Array.from(signature), // But use wrong attester's signature
         expiration: statement.expiration,
+        issuedAt: statement.issuedAt,
       };
 
       const [usedUuidPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("used_uuid"), Buffer.from(uuid)],
+        [
+          Buffer.from("used_uuid"),
+          registryNamespace(
+            context.registry.registryPda,
+            context.program.programId
+          ),
+          Buffer.from(uuid),
+        ],
         context.program.programId
       );
 
@@ -428,6 +456,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: validator.publicKey,
@@ -471,10 +500,18 @@ describe("Program Security Tests", () => {
         attester: attester.publicKey,
         signature: Array.from(signature),
         expiration: statement.expiration,
+        issuedAt: statement.issuedAt,
       };
 
       const [usedUuidPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("used_uuid"), Buffer.from(uuid)],
+        [
+          Buffer.from("used_uuid"),
+          registryNamespace(
+            context.registry.registryPda,
+            context.program.programId
+          ),
+          Buffer.from(uuid),
+        ],
         context.program.programId
       );
 
@@ -496,6 +533,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: validator.publicKey,
@@ -536,10 +574,18 @@ describe("Program Security Tests", () => {
         attester: attester.publicKey,
         signature: Array.from(signature),
         expiration: statement.expiration,
+        issuedAt: statement.issuedAt,
       };
 
       const [usedUuidPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("used_uuid"), Buffer.from(uuid)],
+        [
+          Buffer.from("used_uuid"),
+          registryNamespace(
+            context.registry.registryPda,
+            context.program.programId
+          ),
+          Buffer.from(uuid),
+        ],
         context.program.programId
       );
 
@@ -588,6 +634,7 @@ describe("Program Security Tests", () => {
           .accounts({
             registry: context.registry.registryPda,
             attesterAccount: attesterPda,
+            attesterScopeAccount: null,
             policyAccount: policyPda,
             usedUuidAccount: usedUuidPda,
             signer: validator.publicKey,
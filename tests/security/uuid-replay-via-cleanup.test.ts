@@ -34,6 +34,7 @@ import {
   findAttesterPDA,
   findPolicyPDA,
   createMessageHash,
+  registryNamespace,
 } from "../helpers/test-utils";
 import nacl from "tweetnacl";
 import * as crypto from "crypto";
@@ -107,6 +108,7 @@ describe("UUID Replay Prevention via Cleanup", () => {
       ]),
       policyId: "test-policy-uuid-replay",
       expiration,
+      issuedAt: new BN(Math.min(expiration.toNumber(), Math.floor(Date.now() / 1000))),
     };
   }
 
@@ -142,7 +144,11 @@ describe("UUID Replay Prevention via Cleanup", () => {
    */
   function findUsedUuidPDA(uuid: number[]): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("used_uuid"), Buffer.from(uuid)],
+      [
+        Buffer.from("used_uuid"),
+        registryNamespace(context.registry.registryPda, program.programId),
+        Buffer.from(uuid),
+      ],
       program.programId
     );
     return pda;
@@ -168,6 +174,7 @@ describe("UUID Replay Prevention via Cleanup", () => {
       uuid: Array.from(uuid),
       attester: attester.publicKey,
       expiration,
+      issuedAt: statement.issuedAt,
       signature: Array.from(signature),
     };
 
@@ -190,6 +197,7 @@ describe("UUID Replay Prevention via Cleanup", () => {
       .accounts({
         registry: context.registry.registryPda,
         attesterAccount: attesterPda,
+        attesterScopeAccount: null,
         policyAccount,
         usedUuidAccount: usedUuidPda,
         signer: client.publicKey,
@@ -213,11 +221,12 @@ describe("UUID Replay Prevention via Cleanup", () => {
     expect(uuidAccount1.signer).to.deep.equal(client.publicKey);
 
     // Attempt cleanup - should fail because attestation is still within validation buffer
-    // Cleanup is only allowed after: current_timestamp > expires_at + CLOCK_DRIFT_BUFFER
+    // Cleanup is only allowed after: current_timestamp > expires_at + registry.clock_drift_buffer
     try {
       await program.methods
         .cleanupExpiredUuid()
         .accounts({
+          registry: context.registry.registryPda,
           usedUuidAccount: usedUuidPda,
           signerRecipient: client.publicKey,
         } as any)
@@ -267,6 +276,7 @@ describe("UUID Replay Prevention via Cleanup", () => {
       uuid: Array.from(uuid),
       attester: attester.publicKey,
       expiration,
+      issuedAt: statement.issuedAt,
       signature: Array.from(signature),
     };
 
@@ -289,6 +299,7 @@ describe("UUID Replay Prevention via Cleanup", () => {
       .accounts({
         registry: context.registry.registryPda,
         attesterAccount: attesterPda,
+        attesterScopeAccount: null,
         policyAccount,
         usedUuidAccount: usedUuidPda,
         signer: client.publicKey,
@@ -304,11 +315,12 @@ describe("UUID Replay Prevention via Cleanup", () => {
     expect(validateTx1.length).to.be.greaterThan(0);
 
     // Attempt cleanup - should fail (attestation still within validation buffer)
-    // Cleanup is only allowed after: current_timestamp > expires_at + CLOCK_DRIFT_BUFFER
+    // Cleanup is only allowed after: current_timestamp > expires_at + registry.clock_drift_buffer
     try {
       await program.methods
         .cleanupExpiredUuid()
         .accounts({
+          registry: context.registry.registryPda,
           usedUuidAccount: usedUuidPda,
           signerRecipient: client.publicKey,
         } as any)